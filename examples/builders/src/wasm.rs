@@ -0,0 +1,268 @@
+//! Executes the builder described by the incoming `Action`.
+
+use core::ops::Range;
+use example_io::{Action, CreateFailure, Gas, Options, Outcome, Payload, Reply, Report};
+use gstd_fluent::{
+    self as builder,
+    backend::WaitingProgram,
+    created::Created,
+    errors::{Error, InitFailure},
+    gstd::{self, msg, prelude::*, ReservationId},
+};
+
+const RESERVATION_DURATION: u32 = 100;
+
+fn reserve(amount: u64) -> ReservationId {
+    ReservationId::reserve(amount, RESERVATION_DURATION).expect("failed to reserve gas")
+}
+
+fn input(start: u32, end: u32) -> Range<usize> {
+    start as usize..end as usize
+}
+
+/// Replies with the failures of the init message that the tests expect, panics on the others.
+fn create_failed(program: gstd::ActorId, err: Error) -> Outcome {
+    match err {
+        Error::CreationRejected { program } => {
+            Outcome::CreateFailed(program, CreateFailure::Rejected)
+        }
+        Error::InitFailed {
+            program,
+            failure: InitFailure::OutOfGas,
+        } => Outcome::CreateFailed(program, CreateFailure::OutOfGas),
+        Error::InitFailed {
+            program,
+            failure: InitFailure::Trap(_),
+        } => Outcome::CreateFailed(program, CreateFailure::Trap),
+        Error::InitFailed {
+            program,
+            failure: InitFailure::ErrorReply(_),
+        } => Outcome::CreateFailed(program, CreateFailure::ErrorReply),
+        Error::Contract(_) => Outcome::CreateFailed(program, CreateFailure::Contract),
+        err => panic!("failed to get reply: {err}"),
+    }
+}
+
+/// Executes a builder that can wait for a reply.
+macro_rules! execute_for_reply {
+    ($message:expr, $options:ident) => {
+        match $options.reply {
+            Reply::None => {
+                $message.execute().expect("failed to execute");
+                Outcome::Sent
+            }
+            Reply::Bytes => Outcome::Reply(
+                $message
+                    .for_reply()
+                    .with_reply_deposit($options.reply_deposit)
+                    .execute()
+                    .expect("failed to execute")
+                    .await
+                    .expect("failed to get reply"),
+            ),
+            Reply::Report => Outcome::Report(
+                $message
+                    .for_reply_as::<Report>()
+                    .with_reply_deposit($options.reply_deposit)
+                    .execute()
+                    .expect("failed to execute")
+                    .await
+                    .expect("failed to get reply"),
+            ),
+        }
+    };
+}
+
+/// Executes a delayed builder, which can't wait for a reply.
+macro_rules! execute_delayed {
+    ($message:expr, $options:ident) => {{
+        assert_eq!(
+            $options.reply,
+            Reply::None,
+            "delayed messages can't wait for a reply"
+        );
+        $message.execute().expect("failed to execute");
+        Outcome::Sent
+    }};
+}
+
+/// Executes `builder::send*(...)` with the given options.
+macro_rules! execute_send {
+    ($message:expr, $options:ident, reservation = $reservation:tt) => {{
+        let message = $message.with_value($options.value);
+        match ($options.delay, $options.gas) {
+            (None, Gas::Auto) => execute_for_reply!(message, $options),
+            (None, Gas::Limit(gas_limit)) => {
+                execute_for_reply!(message.with_gas_limit(gas_limit), $options)
+            }
+            (Some(delay), Gas::Auto) => execute_delayed!(message.with_delay(delay), $options),
+            (Some(delay), Gas::Limit(gas_limit)) => {
+                execute_delayed!(message.with_delay(delay).with_gas_limit(gas_limit), $options)
+            }
+            (delay, Gas::Reservation(amount)) => {
+                execute_send!(@reservation $reservation, message, $options, delay, amount)
+            }
+        }
+    }};
+    (@reservation true, $message:ident, $options:ident, $delay:ident, $amount:ident) => {
+        match $delay {
+            None => execute_for_reply!($message.with_gas_from_reservation(reserve($amount)), $options),
+            Some(delay) => execute_delayed!(
+                $message.with_delay(delay).with_gas_from_reservation(reserve($amount)),
+                $options
+            ),
+        }
+    };
+    (@reservation false, $message:ident, $options:ident, $delay:ident, $amount:ident) => {{
+        let _ = ($message, $delay, $amount);
+        panic!("input payloads can't use gas from reservation")
+    }};
+}
+
+/// Executes `builder::reply*(...)` with the given options.
+macro_rules! execute_reply {
+    ($message:expr, $options:ident, reservation = $reservation:tt) => {{
+        let message = $message.with_value($options.value);
+        match $options.gas {
+            Gas::Auto => message.execute(),
+            Gas::Limit(gas_limit) => message.with_gas_limit(gas_limit).execute(),
+            Gas::Reservation(amount) => execute_reply!(@reservation $reservation, message, amount),
+        }
+        .expect("failed to reply");
+    }};
+    (@reservation true, $message:ident, $amount:ident) => {
+        $message.with_gas_from_reservation(reserve($amount)).execute()
+    };
+    (@reservation false, $message:ident, $amount:ident) => {{
+        let _ = ($message, $amount);
+        panic!("input payloads can't use gas from reservation")
+    }};
+}
+
+/// Executes `builder::create_program(...)` that waits for the init reply.
+macro_rules! execute_create_program_for_reply {
+    ($message:expr, $options:ident) => {
+        match $options.reply {
+            Reply::None => {
+                let (_, program_id) = $message.execute().expect("failed to execute");
+                Outcome::Created(program_id)
+            }
+            Reply::Bytes => {
+                let future = $message
+                    .for_reply()
+                    .with_reply_deposit($options.reply_deposit)
+                    .execute()
+                    .expect("failed to execute");
+                let program_id = future.program_id();
+                match future.await {
+                    Ok(Created { program, reply, .. }) => Outcome::CreatedWithReply(program, reply),
+                    Err(err) => create_failed(program_id, err),
+                }
+            }
+            Reply::Report => {
+                let future = $message
+                    .for_reply_as::<Report>()
+                    .with_reply_deposit($options.reply_deposit)
+                    .execute()
+                    .expect("failed to execute");
+                let program_id = future.program_id();
+                match future.await {
+                    Ok(Created { program, reply, .. }) => {
+                        Outcome::CreatedWithReport(program, reply)
+                    }
+                    Err(err) => create_failed(program_id, err),
+                }
+            }
+        }
+    };
+}
+
+/// Executes a delayed `builder::create_program(...)`.
+macro_rules! execute_create_program_delayed {
+    ($message:expr, $options:ident) => {{
+        assert_eq!(
+            $options.reply,
+            Reply::None,
+            "delayed messages can't wait for a reply"
+        );
+        let (_, program_id) = $message.execute().expect("failed to execute");
+        Outcome::Created(program_id)
+    }};
+}
+
+async fn send(to: gstd::ActorId, payload: Payload, options: Options) -> Outcome {
+    match payload {
+        Payload::Bytes(payload) => {
+            execute_send!(
+                builder::send_bytes(to, payload),
+                options,
+                reservation = true
+            )
+        }
+        Payload::Encodable(payload) => {
+            execute_send!(builder::send(to, payload), options, reservation = true)
+        }
+        Payload::Input(start, end) => {
+            execute_send!(
+                builder::send_input(to, input(start, end)),
+                options,
+                reservation = false
+            )
+        }
+    }
+}
+
+fn reply(payload: Payload, options: Options) {
+    match payload {
+        Payload::Bytes(payload) => {
+            execute_reply!(builder::reply_bytes(payload), options, reservation = true)
+        }
+        Payload::Encodable(payload) => {
+            execute_reply!(builder::reply(payload), options, reservation = true)
+        }
+        Payload::Input(start, end) => {
+            execute_reply!(
+                builder::reply_input(input(start, end)),
+                options,
+                reservation = false
+            )
+        }
+    }
+}
+
+async fn create_program(code_id: gstd::CodeId, payload: Vec<u8>, options: Options) -> Outcome {
+    let message = builder::create_program(code_id, payload).with_value(options.value);
+    match (options.delay, options.gas) {
+        (None, Gas::Auto) => execute_create_program_for_reply!(message, options),
+        (None, Gas::Limit(gas_limit)) => {
+            execute_create_program_for_reply!(message.with_gas_limit(gas_limit), options)
+        }
+        (Some(delay), Gas::Auto) => {
+            execute_create_program_delayed!(message.with_delay(delay), options)
+        }
+        (Some(delay), Gas::Limit(gas_limit)) => execute_create_program_delayed!(
+            message.with_delay(delay).with_gas_limit(gas_limit),
+            options
+        ),
+        (_, Gas::Reservation(_)) => panic!("programs can't be created with gas from reservation"),
+    }
+}
+
+#[gstd::async_main]
+async fn main() {
+    let outcome = match msg::load::<Action>().expect("failed to load action") {
+        Action::Send {
+            to,
+            payload,
+            options,
+        } => send(to, payload, options).await,
+        Action::Reply { payload, options } => return reply(payload, options),
+        Action::CreateProgram {
+            code_id,
+            payload,
+            options,
+        } => create_program(code_id, payload, options).await,
+    };
+
+    builder::reply(outcome).execute().expect("failed to reply");
+}
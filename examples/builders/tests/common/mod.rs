@@ -0,0 +1,86 @@
+#![allow(dead_code)]
+
+use example_io::{Outcome, Report};
+use gstd_fluent::gstd::{ActorId, CodeId, Decode};
+use gtest::{Program, RunResult, System};
+
+pub const USER: u64 = 42;
+pub const BALANCE: u128 = 1_000_000_000_000;
+pub const VALUE: u128 = 10_000;
+pub const GAS_LIMIT: u64 = 5_000_000_000;
+pub const DELAY: u32 = 3;
+
+const BUILDERS_WASM: &str = "../../target/wasm32-unknown-unknown/release/example_builders.opt.wasm";
+const PROBE_WASM: &str = "../../target/wasm32-unknown-unknown/release/example_probe.opt.wasm";
+
+pub fn system() -> System {
+    let system = System::new();
+    system.init_logger();
+    system.mint_to(USER, BALANCE);
+    system
+}
+
+/// Uploads the program that executes the builder described by `Action`.
+pub fn builders(system: &System) -> Program<'_> {
+    let program = Program::from_file(system, BUILDERS_WASM);
+    assert!(!program.send_bytes(USER, []).main_failed());
+    program
+}
+
+/// Uploads the program that reports every received message to `USER`.
+pub fn probe(system: &System) -> Program<'_> {
+    let program = Program::from_file(system, PROBE_WASM);
+    assert!(!program.send(USER, ActorId::from(USER)).main_failed());
+    program
+}
+
+pub fn probe_code_id(system: &System) -> CodeId {
+    CodeId::from(system.submit_code(PROBE_WASM).into_bytes())
+}
+
+pub fn actor_id(program: &Program) -> ActorId {
+    ActorId::from(program.id().into_bytes())
+}
+
+/// Returns the reports sent to `USER` by `probe`.
+pub fn reports<'a>(
+    results: impl IntoIterator<Item = &'a RunResult>,
+    probe: ActorId,
+) -> Vec<Report> {
+    results
+        .into_iter()
+        .flat_map(|result| result.log())
+        .filter(|log| {
+            ActorId::from(log.source().into_bytes()) == probe
+                && ActorId::from(log.destination().into_bytes()) == ActorId::from(USER)
+        })
+        .map(|log| Report::decode(&mut log.payload()).expect("failed to decode report"))
+        .collect()
+}
+
+/// Returns the only report sent to `USER` by `probe`.
+pub fn report<'a>(results: impl IntoIterator<Item = &'a RunResult>, probe: ActorId) -> Report {
+    let mut reports = reports(results, probe);
+    assert_eq!(reports.len(), 1, "expected exactly one report");
+    reports.remove(0)
+}
+
+/// Returns the payloads sent to `USER` by `builders`.
+pub fn replies(result: &RunResult, builders: ActorId) -> Vec<Vec<u8>> {
+    result
+        .log()
+        .iter()
+        .filter(|log| {
+            ActorId::from(log.source().into_bytes()) == builders
+                && ActorId::from(log.destination().into_bytes()) == ActorId::from(USER)
+        })
+        .map(|log| log.payload().to_vec())
+        .collect()
+}
+
+/// Returns the `Outcome` replied to `USER` by `builders`.
+pub fn outcome(result: &RunResult, builders: ActorId) -> Outcome {
+    let replies = replies(result, builders);
+    assert_eq!(replies.len(), 1, "expected exactly one reply");
+    Outcome::decode(&mut replies[0].as_ref()).expect("failed to decode outcome")
+}
@@ -0,0 +1,168 @@
+//! Covers every `execute()` of `CreateProgramBuilder` and `CreateProgramBuilderForReply` in
+//! `generated/create_program.rs`, and the errors of `CreatedFuture` when the init message fails.
+
+mod common;
+
+use common::*;
+use example_io::{
+    Action, CreateFailure, Gas, Options, Outcome, Reply, INIT_EMPTY_REPLY, INIT_WAIT,
+};
+use gstd_fluent::gstd::{prelude::*, ActorId, CodeId, Encode};
+
+/// Not enough to initialize the probe.
+const INIT_GAS_LIMIT: u64 = 1_000_000;
+
+/// Enough for the waitlist to charge the gas the probe keeps before waiting.
+const WAIT_BLOCKS: u32 = 20_000;
+
+#[test]
+fn create_program() {
+    for delay in [None, Some(DELAY)] {
+        for gas in [Gas::Auto, Gas::Limit(GAS_LIMIT)] {
+            for reply in [Reply::None, Reply::Bytes, Reply::Report] {
+                if delay.is_some() && reply != Reply::None {
+                    continue;
+                }
+
+                let system = system();
+                let builders = builders(&system);
+
+                // The probe reads the reporter from the beginning of the init payload
+                let mut payload = ActorId::from(USER).encode();
+                payload.extend_from_slice(b"init");
+
+                let options = Options {
+                    value: VALUE,
+                    delay,
+                    gas,
+                    reply,
+                    ..Default::default()
+                };
+                let action = Action::CreateProgram {
+                    code_id: probe_code_id(&system),
+                    payload: payload.clone(),
+                    options,
+                };
+                let result = builders.send_with_value(USER, action.clone(), VALUE);
+                assert!(!result.main_failed(), "{action:?}");
+
+                let outcome = outcome(&result, actor_id(&builders));
+                let program_id = match outcome {
+                    Outcome::Created(program_id)
+                    | Outcome::CreatedWithReply(program_id, _)
+                    | Outcome::CreatedWithReport(program_id, _) => program_id,
+                    _ => panic!("unexpected outcome {outcome:?}"),
+                };
+
+                let report = match delay {
+                    Some(delay) => {
+                        assert!(reports([&result], program_id).is_empty(), "{action:?}");
+                        report(&system.spend_blocks(delay), program_id)
+                    }
+                    None => report([&result], program_id),
+                };
+                assert_eq!(report.source, actor_id(&builders), "{action:?}");
+                assert_eq!(report.payload, payload, "{action:?}");
+                assert_eq!(report.value, VALUE, "{action:?}");
+                if let Gas::Limit(gas_limit) = gas {
+                    assert!(report.gas_available <= gas_limit, "{action:?}");
+                }
+
+                let expected_outcome = match reply {
+                    Reply::None => Outcome::Created(program_id),
+                    Reply::Bytes => Outcome::CreatedWithReply(program_id, report.encode()),
+                    Reply::Report => Outcome::CreatedWithReport(program_id, report),
+                };
+                assert_eq!(outcome, expected_outcome, "{action:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn create_program_failures() {
+    let system = system();
+    let builders = builders(&system);
+    let code_id = probe_code_id(&system);
+
+    let init_payload = |tail: &[u8]| [ActorId::from(USER).encode(), tail.to_vec()].concat();
+    let payload = init_payload(b"init");
+
+    let cases = [
+        // The code was never submitted
+        (
+            CodeId::from([0xff; 32]),
+            payload.clone(),
+            Gas::Auto,
+            Reply::Bytes,
+            CreateFailure::Rejected,
+        ),
+        (
+            code_id,
+            payload,
+            Gas::Limit(INIT_GAS_LIMIT),
+            Reply::Bytes,
+            CreateFailure::OutOfGas,
+        ),
+        // The probe panics if the init payload doesn't start with the reporter
+        (
+            code_id,
+            vec![],
+            Gas::Auto,
+            Reply::Bytes,
+            CreateFailure::Trap,
+        ),
+        // The init message is removed from the waitlist
+        (
+            code_id,
+            init_payload(INIT_WAIT),
+            Gas::Auto,
+            Reply::Bytes,
+            CreateFailure::ErrorReply,
+        ),
+        // The empty init reply can't be decoded as a report
+        (
+            code_id,
+            init_payload(INIT_EMPTY_REPLY),
+            Gas::Auto,
+            Reply::Report,
+            CreateFailure::Contract,
+        ),
+    ];
+    for (code_id, payload, gas, reply, failure) in cases {
+        let options = Options {
+            gas,
+            reply,
+            ..Default::default()
+        };
+        let action = Action::CreateProgram {
+            code_id,
+            payload,
+            options,
+        };
+        let result = builders.send(USER, action.clone());
+        assert!(!result.main_failed(), "{action:?}");
+
+        let results = match failure {
+            CreateFailure::ErrorReply => {
+                assert!(
+                    replies(&result, actor_id(&builders)).is_empty(),
+                    "{action:?}"
+                );
+                system.spend_blocks(WAIT_BLOCKS)
+            }
+            _ => vec![result],
+        };
+        let result = results
+            .iter()
+            .find(|result| !replies(result, actor_id(&builders)).is_empty())
+            .unwrap_or_else(|| panic!("no reply to {action:?}"));
+
+        let outcome = outcome(result, actor_id(&builders));
+        let Outcome::CreateFailed(program_id, actual) = outcome else {
+            panic!("unexpected outcome {outcome:?} of {action:?}");
+        };
+        assert_eq!(actual, failure, "{action:?}");
+        assert!(reports(&results, program_id).is_empty(), "{action:?}");
+    }
+}
@@ -0,0 +1,55 @@
+//! Covers every `execute()` of `ReplyBuilder` in `generated/reply.rs`.
+
+mod common;
+
+use common::*;
+use example_io::{Action, Gas, Options, Payload};
+use gstd_fluent::gstd::{prelude::*, Encode};
+
+#[test]
+fn reply() {
+    let payloads = [
+        Payload::Bytes(b"bytes".to_vec()),
+        Payload::Encodable(String::from("encodable")),
+        Payload::Input(1, 9),
+    ];
+
+    for payload in payloads {
+        for gas in [
+            Gas::Auto,
+            Gas::Limit(GAS_LIMIT),
+            Gas::Reservation(GAS_LIMIT),
+        ] {
+            if matches!((&payload, gas), (Payload::Input(..), Gas::Reservation(_))) {
+                continue;
+            }
+
+            let system = system();
+            let builders = builders(&system);
+
+            let action = Action::Reply {
+                payload: payload.clone(),
+                options: Options {
+                    value: VALUE,
+                    gas,
+                    ..Default::default()
+                },
+            };
+            let result = builders.send_with_value(USER, action.clone(), VALUE);
+            assert!(!result.main_failed(), "{action:?}");
+
+            let expected_payload = match &payload {
+                Payload::Bytes(bytes) => bytes.clone(),
+                Payload::Encodable(string) => string.encode(),
+                Payload::Input(start, end) => {
+                    action.encode()[*start as usize..*end as usize].to_vec()
+                }
+            };
+            assert_eq!(
+                replies(&result, actor_id(&builders)),
+                [expected_payload],
+                "{action:?}"
+            );
+        }
+    }
+}
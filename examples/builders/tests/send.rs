@@ -0,0 +1,98 @@
+//! Covers every `execute()` of `SendBuilder` and `SendBuilderForReply` in `generated/send.rs`.
+
+mod common;
+
+use common::*;
+use example_io::{Action, Gas, Options, Outcome, Payload, Reply};
+use gstd_fluent::gstd::{prelude::*, Encode};
+
+fn payloads() -> [Payload; 3] {
+    [
+        Payload::Bytes(b"bytes".to_vec()),
+        Payload::Encodable(String::from("encodable")),
+        Payload::Input(1, 9),
+    ]
+}
+
+fn cases() -> Vec<(Payload, Options)> {
+    let mut cases = vec![];
+    for payload in payloads() {
+        for delay in [None, Some(DELAY)] {
+            for gas in [
+                Gas::Auto,
+                Gas::Limit(GAS_LIMIT),
+                Gas::Reservation(GAS_LIMIT),
+            ] {
+                for reply in [Reply::None, Reply::Bytes, Reply::Report] {
+                    let input = matches!(payload, Payload::Input(..));
+                    let reservation = matches!(gas, Gas::Reservation(_));
+                    if (input && reservation) || (delay.is_some() && reply != Reply::None) {
+                        continue;
+                    }
+
+                    let options = Options {
+                        value: VALUE,
+                        delay,
+                        gas,
+                        reply,
+                        ..Default::default()
+                    };
+                    cases.push((payload.clone(), options));
+                }
+            }
+        }
+    }
+
+    cases
+}
+
+#[test]
+fn send() {
+    for (payload, options) in cases() {
+        let system = system();
+        let builders = builders(&system);
+        let probe = probe(&system);
+
+        let action = Action::Send {
+            to: actor_id(&probe),
+            payload: payload.clone(),
+            options: options.clone(),
+        };
+        let result = builders.send_with_value(USER, action.clone(), options.value);
+        assert!(!result.main_failed(), "{action:?}");
+
+        let report = match options.delay {
+            Some(delay) => {
+                assert!(
+                    reports([&result], actor_id(&probe)).is_empty(),
+                    "{action:?}"
+                );
+                report(&system.spend_blocks(delay), actor_id(&probe))
+            }
+            None => report([&result], actor_id(&probe)),
+        };
+
+        let expected_payload = match payload {
+            Payload::Bytes(bytes) => bytes,
+            Payload::Encodable(string) => string.encode(),
+            Payload::Input(start, end) => action.encode()[start as usize..end as usize].to_vec(),
+        };
+        assert_eq!(report.source, actor_id(&builders), "{action:?}");
+        assert_eq!(report.payload, expected_payload, "{action:?}");
+        assert_eq!(report.value, options.value, "{action:?}");
+        if let Gas::Limit(gas_limit) | Gas::Reservation(gas_limit) = options.gas {
+            assert!(report.gas_available <= gas_limit, "{action:?}");
+        }
+
+        let expected_outcome = match options.reply {
+            Reply::None => Outcome::Sent,
+            Reply::Bytes => Outcome::Reply(report.encode()),
+            Reply::Report => Outcome::Report(report),
+        };
+        assert_eq!(
+            outcome(&result, actor_id(&builders)),
+            expected_outcome,
+            "{action:?}"
+        );
+    }
+}
@@ -0,0 +1,131 @@
+//! Types shared between the example programs and their tests.
+
+#![no_std]
+
+use gstd_fluent::gstd::{self, prelude::*, ActorId, CodeId, Decode, Encode};
+
+/// Follows the reporter in the init payload of the probe to make its init wait until it is
+/// removed from the waitlist.
+pub const INIT_WAIT: &[u8] = b"wait";
+
+/// Follows the reporter in the init payload of the probe to make its init reply with an empty
+/// payload instead of a report.
+pub const INIT_EMPTY_REPLY: &[u8] = b"empty";
+
+/// Sent by the probe program to the reporter and as a reply for every message it receives.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct Report {
+    pub source: ActorId,
+    pub payload: Vec<u8>,
+    pub value: u128,
+    pub gas_available: u64,
+}
+
+/// The payload of the executed builder.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub enum Payload {
+    /// `builder::send_bytes(...)`, `builder::reply_bytes(...)`
+    Bytes(Vec<u8>),
+    /// `builder::send(...)`, `builder::reply(...)`
+    Encodable(String),
+    /// `builder::send_input(...)`, `builder::reply_input(...)` with `start..end`
+    Input(u32, u32),
+}
+
+/// The source of gas of the executed builder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub enum Gas {
+    /// Gas limit is not specified.
+    Auto,
+    /// `.with_gas_limit(...)`
+    Limit(u64),
+    /// `.with_gas_from_reservation(...)` with the gas reserved right before the call.
+    Reservation(u64),
+}
+
+/// Whether the executed builder waits for a reply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub enum Reply {
+    /// `.execute()`
+    None,
+    /// `.for_reply().execute()`
+    Bytes,
+    /// `.for_reply_as::<Report>().execute()`
+    Report,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct Options {
+    pub value: u128,
+    pub delay: Option<u32>,
+    pub gas: Gas,
+    pub reply: Reply,
+    pub reply_deposit: u64,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            value: 0,
+            delay: None,
+            gas: Gas::Auto,
+            reply: Reply::None,
+            reply_deposit: 0,
+        }
+    }
+}
+
+/// Handled by the builders program.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub enum Action {
+    Send {
+        to: ActorId,
+        payload: Payload,
+        options: Options,
+    },
+    Reply {
+        payload: Payload,
+        options: Options,
+    },
+    CreateProgram {
+        code_id: CodeId,
+        payload: Vec<u8>,
+        options: Options,
+    },
+}
+
+/// Why the init message of a program created by `Action::CreateProgram` failed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub enum CreateFailure {
+    /// `Error::CreationRejected`, e.g. the code does not exist.
+    Rejected,
+    /// `Error::InitFailed` with `InitFailure::OutOfGas`.
+    OutOfGas,
+    /// `Error::InitFailed` with `InitFailure::Trap`.
+    Trap,
+    /// `Error::InitFailed` with `InitFailure::ErrorReply`, e.g. the init message was removed from
+    /// the waitlist.
+    ErrorReply,
+    /// `Error::Contract`, e.g. the init reply can't be decoded.
+    Contract,
+}
+
+/// Replied by the builders program to `Action::Send` and `Action::CreateProgram`.
+#[derive(Debug, Clone, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub enum Outcome {
+    Sent,
+    Reply(Vec<u8>),
+    Report(Report),
+    Created(ActorId),
+    CreatedWithReply(ActorId, Vec<u8>),
+    CreatedWithReport(ActorId, Report),
+    CreateFailed(ActorId, CreateFailure),
+}
@@ -0,0 +1,51 @@
+//! Reports every received message to the actor passed in the init payload.
+
+use example_io::{Report, INIT_EMPTY_REPLY, INIT_WAIT};
+use gstd_fluent::{
+    self as builder,
+    gstd::{exec, msg, prelude::*, ActorId},
+};
+
+static mut REPORTER: ActorId = ActorId::zero();
+
+fn report() -> Report {
+    let report = Report {
+        source: msg::source(),
+        payload: msg::load_bytes().expect("failed to load payload"),
+        value: msg::value(),
+        gas_available: exec::gas_available(),
+    };
+
+    builder::send(unsafe { REPORTER }, report.clone())
+        .execute()
+        .expect("failed to send report");
+
+    report
+}
+
+#[no_mangle]
+extern "C" fn init() {
+    unsafe { REPORTER = msg::load().expect("failed to load reporter") };
+
+    let payload = msg::load_bytes().expect("failed to load payload");
+    match payload.get(core::mem::size_of::<ActorId>()..) {
+        Some(INIT_WAIT) => wait_until_removed(),
+        Some(INIT_EMPTY_REPLY) => builder::reply_bytes(b"").execute(),
+        _ => builder::reply(report()).execute(),
+    }
+    .expect("failed to reply");
+}
+
+/// Burns the gas down to `KEEP` so the message is removed from the waitlist within a few thousand
+/// blocks instead of holding all its gas.
+fn wait_until_removed() -> ! {
+    const KEEP: u64 = 1_000_000;
+
+    while exec::gas_available() > KEEP {}
+    exec::wait()
+}
+
+#[no_mangle]
+extern "C" fn handle() {
+    builder::reply(report()).execute().expect("failed to reply");
+}
@@ -0,0 +1,65 @@
+//! Attribute macros re-exported by [`gstd-fluent`](https://docs.rs/gstd-fluent).
+
+use proc_macro::TokenStream;
+use quote::quote;
+use syn::{parse_macro_input, parse_quote, spanned::Spanned, ItemFn, Path, ReturnType};
+
+/// Turns a function returning `Result<Reply, Failure>` into a program entry point that replies
+/// with the result.
+///
+/// See `gstd_fluent::handle` for the details.
+#[proc_macro_attribute]
+pub fn handle(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut krate: Path = parse_quote!(::gstd_fluent);
+    let parser = syn::meta::parser(|meta| {
+        if meta.path.is_ident("crate") {
+            krate = meta.value()?.parse()?;
+            Ok(())
+        } else {
+            Err(meta.error("expected `crate = path`"))
+        }
+    });
+    parse_macro_input!(args with parser);
+
+    let function = parse_macro_input!(item as ItemFn);
+    if let Err(err) = check(&function) {
+        return err.to_compile_error().into();
+    }
+
+    let name = &function.sig.ident;
+    quote! {
+        #[no_mangle]
+        extern "C" fn #name() {
+            #function
+
+            #krate::reply_result(#name())
+                .execute()
+                .expect("Unable to reply");
+        }
+    }
+    .into()
+}
+
+fn check(function: &ItemFn) -> syn::Result<()> {
+    let signature = &function.sig;
+    if let Some(asyncness) = signature.asyncness {
+        return Err(syn::Error::new(
+            asyncness.span(),
+            "async handlers are not supported, use `builder::router()` with `.on_async(...)`",
+        ));
+    }
+    if !signature.inputs.is_empty() || !signature.generics.params.is_empty() {
+        return Err(syn::Error::new(
+            signature.span(),
+            "the handler must not have arguments or generics",
+        ));
+    }
+    if let ReturnType::Default = signature.output {
+        return Err(syn::Error::new(
+            signature.span(),
+            "the handler must return `Result<Reply, Failure>`",
+        ));
+    }
+
+    Ok(())
+}
@@ -0,0 +1,160 @@
+//! Role-based access control for the message being processed.
+//!
+//! An [`Access`] is kept in the program state and holds the owner of the program and the roles
+//! granted to other actors. The owner passes every role check.
+//!
+//! ```ignore
+//! #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Encode, Decode)]
+//! #[codec(crate = gstd::codec)]
+//! enum Role {
+//!     Admin,
+//!     Minter,
+//! }
+//!
+//! static mut ACCESS: Option<Access<Role>> = None;
+//!
+//! #[no_mangle]
+//! extern "C" fn handle() {
+//!     let access = unsafe { ACCESS.as_ref().expect("The program is not initialized") };
+//!     builder::router::<Action, u64, ErrorReply>()
+//!         .on(route!(Action::Mint(amount) => amount), only_role(access, Role::Minter, mint))
+//!         .on(route!(Action::Pause => ()), only_owner(access, pause))
+//!         .handle()
+//!         // ^ Replies with `Err(ErrorReply { code: Unauthorized::CODE, .. })`
+//!         //   if the sender is not allowed to call the handler
+//!         .expect("Unable to reply");
+//! }
+//! ```
+//!
+//! The same checks are available as guards of `builder::incoming()`: `.require_owner(&access)` and
+//! `.require_role(&access, role)`. Add `.or_reply_unauthorized()?` after them to reply with the
+//! same [`ErrorReply`] when the sender is denied.
+
+use crate::{
+    backend::{ActiveBackend, Backend},
+    error_reply::ErrorReply,
+};
+use alloc::collections::{BTreeMap, BTreeSet};
+use core::fmt;
+use gstd::{prelude::*, ActorId, Decode, Encode};
+
+/// The error returned when the sender is not allowed to perform the action.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct Unauthorized {
+    /// The actor that was denied.
+    pub actor: ActorId,
+}
+
+impl Unauthorized {
+    /// The code of the [`ErrorReply`] converted from this error.
+    pub const CODE: u32 = 401;
+}
+
+impl From<Unauthorized> for ErrorReply {
+    fn from(err: Unauthorized) -> Self {
+        Self::new(Unauthorized::CODE, format!("{err}"))
+    }
+}
+
+impl fmt::Display for Unauthorized {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?} is not authorized", self.actor)
+    }
+}
+
+/// The owner of a program and the roles granted to other actors.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct Access<Role: Ord> {
+    owner: ActorId,
+    roles: BTreeMap<ActorId, BTreeSet<Role>>,
+}
+
+impl<Role: Ord> Access<Role> {
+    pub fn new(owner: ActorId) -> Self {
+        Self {
+            owner,
+            roles: BTreeMap::new(),
+        }
+    }
+
+    pub fn owner(&self) -> ActorId {
+        self.owner
+    }
+
+    /// Replaces the owner, the roles granted to the previous owner are kept.
+    pub fn transfer_ownership(&mut self, owner: ActorId) {
+        self.owner = owner;
+    }
+
+    /// Grants the role to the actor. Returns `false` if the actor already has it.
+    pub fn grant(&mut self, actor: ActorId, role: Role) -> bool {
+        self.roles.entry(actor).or_default().insert(role)
+    }
+
+    /// Revokes the role from the actor. Returns `false` if the actor doesn't have it.
+    pub fn revoke(&mut self, actor: ActorId, role: &Role) -> bool {
+        let Some(roles) = self.roles.get_mut(&actor) else {
+            return false;
+        };
+        let revoked = roles.remove(role);
+        if roles.is_empty() {
+            self.roles.remove(&actor);
+        }
+
+        revoked
+    }
+
+    /// Returns `true` if the actor is the owner or was granted the role.
+    pub fn has_role(&self, actor: ActorId, role: &Role) -> bool {
+        actor == self.owner
+            || self
+                .roles
+                .get(&actor)
+                .is_some_and(|roles| roles.contains(role))
+    }
+
+    /// Checks that the actor is the owner.
+    pub fn check_owner(&self, actor: ActorId) -> Result<(), Unauthorized> {
+        if actor != self.owner {
+            return Err(Unauthorized { actor });
+        }
+
+        Ok(())
+    }
+
+    /// Checks that the actor is the owner or was granted the role.
+    pub fn check_role(&self, actor: ActorId, role: &Role) -> Result<(), Unauthorized> {
+        if !self.has_role(actor, role) {
+            return Err(Unauthorized { actor });
+        }
+
+        Ok(())
+    }
+}
+
+/// Wraps the handler so that it is only called if the sender of the message being processed is
+/// the owner. Otherwise, the handler returns [`Unauthorized`].
+pub fn only_owner<'a, Role: Ord, Input, Reply, Failure: From<Unauthorized>>(
+    access: &'a Access<Role>,
+    handler: impl FnOnce(Input) -> Result<Reply, Failure> + 'a,
+) -> impl FnOnce(Input) -> Result<Reply, Failure> + 'a {
+    move |input| {
+        access.check_owner(ActiveBackend::message_source())?;
+        handler(input)
+    }
+}
+
+/// Wraps the handler so that it is only called if the sender of the message being processed is
+/// the owner or was granted the role. Otherwise, the handler returns [`Unauthorized`].
+pub fn only_role<'a, Role: Ord + 'a, Input, Reply, Failure: From<Unauthorized>>(
+    access: &'a Access<Role>,
+    role: Role,
+    handler: impl FnOnce(Input) -> Result<Reply, Failure> + 'a,
+) -> impl FnOnce(Input) -> Result<Reply, Failure> + 'a {
+    move |input| {
+        access.check_role(ActiveBackend::message_source(), &role)?;
+        handler(input)
+    }
+}
@@ -0,0 +1,1228 @@
+//! In-memory backend that records the executed builders instead of performing syscalls.
+//!
+//! ```ignore
+//! use gstd_fluent::{self as builder, backend::mock, gstd::ActorId};
+//!
+//! #[test]
+//! fn sends_ping() {
+//!     mock::reset();
+//!     mock::push_reply_encoded("PONG");
+//!
+//!     let reply = mock::block_on(async {
+//!         builder::send(ActorId::from([1; 32]), "PING")
+//!             .with_value(42)
+//!             .for_reply_as::<String>()
+//!             .execute()
+//!             .unwrap()
+//!             .await
+//!     });
+//!
+//!     let call = mock::last_call().unwrap();
+//!     assert_eq!(call.function, "send_for_reply_as");
+//!     assert_eq!(call.destination, Some(ActorId::from([1; 32])));
+//!     assert_eq!(call.value, 42);
+//!     assert_eq!(reply.unwrap(), "PONG");
+//! }
+//! ```
+
+use super::{Backend, WaitingProgram, WaitingReply};
+use crate::config::Config;
+use core::{
+    cell::RefCell,
+    future::Future,
+    ops::{Bound, RangeBounds},
+    pin::Pin,
+    task::{Context, Poll, RawWaker, RawWakerVTable, Waker},
+};
+use gstd::{
+    errors::{ContractError, Result},
+    prelude::*,
+    ActorId, CodeId, Decode, Encode, MessageId, ReservationId,
+};
+
+/// The payload of a recorded call.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Payload {
+    /// The payload passed to `*_bytes*` functions or encoded by the other functions.
+    Bytes(Vec<u8>),
+    /// The range of the incoming message passed to `*_input*` functions.
+    Input(Bound<usize>, Bound<usize>),
+}
+
+impl Payload {
+    fn bytes<Buffer: AsRef<[u8]>>(payload: Buffer) -> Self {
+        Self::Bytes(payload.as_ref().to_vec())
+    }
+
+    fn encode<Encodable: Encode>(payload: Encodable) -> Self {
+        Self::Bytes(payload.encode())
+    }
+
+    fn input<Range: RangeBounds<usize>>(payload: Range) -> Self {
+        Self::Input(payload.start_bound().cloned(), payload.end_bound().cloned())
+    }
+}
+
+/// A call of a `gstd` function recorded by [`MockBackend`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Call {
+    /// The name of the `gstd` function, e.g. `"send_bytes_with_gas_delayed"`.
+    pub function: &'static str,
+    /// The id of the message returned to the builder.
+    pub message_id: MessageId,
+    /// The receiver of the message, or the created program. `None` for replies.
+    pub destination: Option<ActorId>,
+    /// The code id passed to `create_program*` functions.
+    pub code_id: Option<CodeId>,
+    pub payload: Payload,
+    pub value: u128,
+    pub gas_limit: Option<u64>,
+    /// The delay, or the duration passed to `wait_for` and `wait_up_to`.
+    pub delay: Option<u32>,
+    pub reservation_id: Option<ReservationId>,
+    pub reply_deposit: Option<u64>,
+    /// The message woken by `wake*` functions.
+    pub woken: Option<MessageId>,
+}
+
+impl Call {
+    fn new(function: &'static str, payload: Payload, value: u128) -> Self {
+        Self {
+            function,
+            message_id: MessageId::default(),
+            destination: None,
+            code_id: None,
+            payload,
+            value,
+            gas_limit: None,
+            delay: None,
+            reservation_id: None,
+            reply_deposit: None,
+            woken: None,
+        }
+    }
+}
+
+/// The panic message of `exec::exit()`, which can't return.
+pub const EXIT_MESSAGE: &str = "exec::exit() was called";
+
+/// The panic message of `exec::wait*()`, which can't return.
+pub const WAIT_MESSAGE: &str = "exec::wait*() was called";
+
+/// The message being processed, returned by the `msg::*` functions.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct IncomingMessage {
+    pub id: MessageId,
+    pub source: ActorId,
+    pub value: u128,
+    pub payload: Vec<u8>,
+}
+
+#[derive(Default)]
+struct State {
+    calls: Vec<Call>,
+    replies: VecDeque<Result<Vec<u8>>>,
+    gas_available: u64,
+    value_available: u128,
+    block_height: u32,
+    program_id: ActorId,
+    message: IncomingMessage,
+    nonce: u64,
+    config: Config,
+    send_failure: Option<(usize, ContractError)>,
+}
+
+std::thread_local! {
+    static STATE: RefCell<State> = RefCell::new(State::default());
+}
+
+/// Clears the recorded calls, scripted replies and send failures, sets the available gas and value and the block
+/// height to 0, the message being processed to the default one and the settings to the default
+/// [`Config`].
+pub fn reset() {
+    STATE.with(|state| *state.borrow_mut() = State::default());
+}
+
+/// Returns the calls recorded since the last [`reset()`] or [`take_calls()`].
+pub fn calls() -> Vec<Call> {
+    STATE.with(|state| state.borrow().calls.clone())
+}
+
+/// Returns the last recorded call.
+pub fn last_call() -> Option<Call> {
+    STATE.with(|state| state.borrow().calls.last().cloned())
+}
+
+/// Returns the recorded calls and clears them.
+pub fn take_calls() -> Vec<Call> {
+    STATE.with(|state| core::mem::take(&mut state.borrow_mut().calls))
+}
+
+/// Sets the value returned by `exec::gas_available()`.
+pub fn set_gas_available(gas: u64) {
+    STATE.with(|state| state.borrow_mut().gas_available = gas);
+}
+
+/// Sets the value returned by `exec::value_available()`.
+pub fn set_value_available(value: u128) {
+    STATE.with(|state| state.borrow_mut().value_available = value);
+}
+
+/// Sets the value returned by `exec::block_height()`.
+pub fn set_block_height(block_height: u32) {
+    STATE.with(|state| state.borrow_mut().block_height = block_height);
+}
+
+/// Sets the value returned by `exec::program_id()`.
+pub fn set_program_id(program_id: ActorId) {
+    STATE.with(|state| state.borrow_mut().program_id = program_id);
+}
+
+/// Sets the message returned by `msg::id()`, `msg::source()`, `msg::value()` and
+/// `msg::load_bytes()`.
+pub fn set_message(message: IncomingMessage) {
+    STATE.with(|state| state.borrow_mut().message = message);
+}
+
+/// Adds a reply to the queue of replies resolved by the `for_reply*` futures in FIFO order.
+pub fn push_reply<Buffer: AsRef<[u8]>>(reply: Buffer) {
+    push_reply_result(Ok(reply.as_ref().to_vec()));
+}
+
+/// Adds an encoded reply to the queue of replies.
+pub fn push_reply_encoded<Encodable: Encode>(reply: Encodable) {
+    push_reply_result(Ok(reply.encode()));
+}
+
+/// Adds an error to the queue of replies.
+pub fn push_reply_error(err: ContractError) {
+    push_reply_result(Err(err));
+}
+
+/// Makes the `send*` functions fail with `err` once `sent` more messages are sent. Only one send
+/// fails.
+pub fn fail_send_after(sent: usize, err: ContractError) {
+    STATE.with(|state| state.borrow_mut().send_failure = Some((sent, err)));
+}
+
+fn scripted_send_failure() -> Result<()> {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        match state.send_failure.take() {
+            Some((0, err)) => Err(err),
+            Some((sent, err)) => {
+                state.send_failure = Some((sent - 1, err));
+                Ok(())
+            }
+            None => Ok(()),
+        }
+    })
+}
+
+fn push_reply_result(reply: Result<Vec<u8>>) {
+    STATE.with(|state| state.borrow_mut().replies.push_back(reply));
+}
+
+pub(crate) fn config() -> Config {
+    STATE.with(|state| state.borrow().config)
+}
+
+pub(crate) fn set_config(config: Config) {
+    STATE.with(|state| state.borrow_mut().config = config);
+}
+
+fn next_reply() -> Result<Vec<u8>> {
+    STATE
+        .with(|state| state.borrow_mut().replies.pop_front())
+        .expect("no scripted reply left, use `mock::push_reply(...)`")
+}
+
+fn next_id() -> [u8; 32] {
+    STATE.with(|state| {
+        let mut state = state.borrow_mut();
+        state.nonce += 1;
+
+        let mut id = [0; 32];
+        id[..8].copy_from_slice(&state.nonce.to_le_bytes());
+        id
+    })
+}
+
+fn next_program_id() -> ActorId {
+    ActorId::from(next_id())
+}
+
+fn record(mut call: Call) -> MessageId {
+    let message_id = MessageId::from(next_id());
+    call.message_id = message_id;
+    STATE.with(|state| state.borrow_mut().calls.push(call));
+    message_id
+}
+
+fn decode<Decodable: Decode>(reply: Vec<u8>) -> Result<Decodable> {
+    Decodable::decode(&mut reply.as_ref()).map_err(ContractError::Decode)
+}
+
+/// Runs a future returned by the builders to completion.
+///
+/// The futures of [`MockBackend`] are resolved on the first poll, so no async runtime is needed.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    fn noop_raw_waker() -> RawWaker {
+        fn clone(_: *const ()) -> RawWaker {
+            noop_raw_waker()
+        }
+        fn noop(_: *const ()) {}
+
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(clone, noop, noop, noop);
+        RawWaker::new(core::ptr::null(), &VTABLE)
+    }
+
+    // SAFETY: The vtable functions do nothing.
+    let waker = unsafe { Waker::from_raw(noop_raw_waker()) };
+    let mut cx = Context::from_waker(&waker);
+    let mut future = Box::pin(future);
+
+    match future.as_mut().poll(&mut cx) {
+        Poll::Ready(output) => output,
+        Poll::Pending => panic!("the future is not resolved by the mock backend"),
+    }
+}
+
+/// The future that resolves to the next scripted reply.
+pub struct ReplyFuture<T> {
+    message_id: MessageId,
+    map: fn(Vec<u8>) -> Result<T>,
+}
+
+impl<T> ReplyFuture<T> {
+    fn new(message_id: MessageId, map: fn(Vec<u8>) -> Result<T>) -> Self {
+        Self { message_id, map }
+    }
+}
+
+impl<T> WaitingReply for ReplyFuture<T> {
+    fn waiting_reply_to(&self) -> MessageId {
+        self.message_id
+    }
+}
+
+impl<T> Future for ReplyFuture<T> {
+    type Output = Result<T>;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Poll::Ready(next_reply().and_then(self.map))
+    }
+}
+
+/// The future that resolves to the created program and the next scripted reply.
+pub struct CreateProgramReplyFuture<T> {
+    message_id: MessageId,
+    program_id: ActorId,
+    map: fn(Vec<u8>) -> Result<T>,
+}
+
+impl<T> CreateProgramReplyFuture<T> {
+    fn new(message_id: MessageId, program_id: ActorId, map: fn(Vec<u8>) -> Result<T>) -> Self {
+        Self {
+            message_id,
+            program_id,
+            map,
+        }
+    }
+}
+
+impl<T> WaitingReply for CreateProgramReplyFuture<T> {
+    fn waiting_reply_to(&self) -> MessageId {
+        self.message_id
+    }
+}
+
+impl<T> WaitingProgram for CreateProgramReplyFuture<T> {
+    fn program_id(&self) -> ActorId {
+        self.program_id
+    }
+}
+
+impl<T> Future for CreateProgramReplyFuture<T> {
+    type Output = Result<(ActorId, T)>;
+
+    fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let program_id = self.program_id;
+        Poll::Ready(
+            next_reply()
+                .and_then(self.map)
+                .map(|reply| (program_id, reply)),
+        )
+    }
+}
+
+/// The backend that records calls in memory.
+pub struct MockBackend;
+
+impl Backend for MockBackend {
+    type MessageFuture = ReplyFuture<Vec<u8>>;
+    type CodecMessageFuture<Decodable: Decode> = ReplyFuture<Decodable>;
+    type CreateProgramFuture = CreateProgramReplyFuture<Vec<u8>>;
+    type CodecCreateProgramFuture<Decodable: Decode> = CreateProgramReplyFuture<Decodable>;
+
+    fn gas_available() -> u64 {
+        STATE.with(|state| state.borrow().gas_available)
+    }
+
+    fn value_available() -> u128 {
+        STATE.with(|state| state.borrow().value_available)
+    }
+
+    fn block_height() -> u32 {
+        STATE.with(|state| state.borrow().block_height)
+    }
+
+    fn program_id() -> ActorId {
+        STATE.with(|state| state.borrow().program_id)
+    }
+
+    fn message_id() -> MessageId {
+        STATE.with(|state| state.borrow().message.id)
+    }
+
+    fn message_source() -> ActorId {
+        STATE.with(|state| state.borrow().message.source)
+    }
+
+    fn message_value() -> u128 {
+        STATE.with(|state| state.borrow().message.value)
+    }
+
+    fn message_size() -> usize {
+        STATE.with(|state| state.borrow().message.payload.len())
+    }
+
+    fn load_bytes() -> Result<Vec<u8>> {
+        Ok(STATE.with(|state| state.borrow().message.payload.clone()))
+    }
+
+    /// Records the call and panics with [`EXIT_MESSAGE`] instead of terminating the program.
+    fn exit(inheritor: ActorId) -> ! {
+        record(Call {
+            destination: Some(inheritor),
+            ..Call::new("exit", Payload::Bytes(Vec::new()), 0)
+        });
+        panic!("{EXIT_MESSAGE}")
+    }
+
+    /// Records the call and panics with [`WAIT_MESSAGE`] instead of suspending the message.
+    fn wait() -> ! {
+        record(Call::new("wait", Payload::Bytes(Vec::new()), 0));
+        panic!("{WAIT_MESSAGE}")
+    }
+
+    /// Records the call and panics with [`WAIT_MESSAGE`] instead of suspending the message.
+    fn wait_for(duration: u32) -> ! {
+        record(Call {
+            delay: Some(duration),
+            ..Call::new("wait_for", Payload::Bytes(Vec::new()), 0)
+        });
+        panic!("{WAIT_MESSAGE}")
+    }
+
+    /// Records the call and panics with [`WAIT_MESSAGE`] instead of suspending the message.
+    fn wait_up_to(duration: u32) -> ! {
+        record(Call {
+            delay: Some(duration),
+            ..Call::new("wait_up_to", Payload::Bytes(Vec::new()), 0)
+        });
+        panic!("{WAIT_MESSAGE}")
+    }
+
+    fn wake(message_id: MessageId) -> Result<()> {
+        record(Call {
+            woken: Some(message_id),
+            ..Call::new("wake", Payload::Bytes(Vec::new()), 0)
+        });
+        Ok(())
+    }
+
+    fn wake_delayed(message_id: MessageId, delay: u32) -> Result<()> {
+        record(Call {
+            woken: Some(message_id),
+            delay: Some(delay),
+            ..Call::new("wake_delayed", Payload::Bytes(Vec::new()), 0)
+        });
+        Ok(())
+    }
+
+    fn send_bytes<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            ..Call::new("send_bytes", Payload::bytes(payload), value)
+        }))
+    }
+
+    fn send_bytes_from_reservation<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            reservation_id: Some(reservation_id),
+            ..Call::new(
+                "send_bytes_from_reservation",
+                Payload::bytes(payload),
+                value,
+            )
+        }))
+    }
+
+    fn send_bytes_with_gas<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            gas_limit: Some(gas_limit),
+            ..Call::new("send_bytes_with_gas", Payload::bytes(payload), value)
+        }))
+    }
+
+    fn send_bytes_delayed<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            delay: Some(delay),
+            ..Call::new("send_bytes_delayed", Payload::bytes(payload), value)
+        }))
+    }
+
+    fn send_bytes_delayed_from_reservation<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            delay: Some(delay),
+            reservation_id: Some(reservation_id),
+            ..Call::new(
+                "send_bytes_delayed_from_reservation",
+                Payload::bytes(payload),
+                value,
+            )
+        }))
+    }
+
+    fn send_bytes_with_gas_delayed<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            gas_limit: Some(gas_limit),
+            delay: Some(delay),
+            ..Call::new(
+                "send_bytes_with_gas_delayed",
+                Payload::bytes(payload),
+                value,
+            )
+        }))
+    }
+
+    fn send<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            ..Call::new("send", Payload::encode(payload), value)
+        }))
+    }
+
+    fn send_from_reservation<Encodable: Encode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            reservation_id: Some(reservation_id),
+            ..Call::new("send_from_reservation", Payload::encode(payload), value)
+        }))
+    }
+
+    fn send_with_gas<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            gas_limit: Some(gas_limit),
+            ..Call::new("send_with_gas", Payload::encode(payload), value)
+        }))
+    }
+
+    fn send_delayed<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            delay: Some(delay),
+            ..Call::new("send_delayed", Payload::encode(payload), value)
+        }))
+    }
+
+    fn send_delayed_from_reservation<Encodable: Encode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            delay: Some(delay),
+            reservation_id: Some(reservation_id),
+            ..Call::new(
+                "send_delayed_from_reservation",
+                Payload::encode(payload),
+                value,
+            )
+        }))
+    }
+
+    fn send_with_gas_delayed<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            gas_limit: Some(gas_limit),
+            delay: Some(delay),
+            ..Call::new("send_with_gas_delayed", Payload::encode(payload), value)
+        }))
+    }
+
+    fn send_input<Range: RangeBounds<usize>>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            ..Call::new("send_input", Payload::input(payload), value)
+        }))
+    }
+
+    fn send_input_with_gas<Range: RangeBounds<usize>>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            gas_limit: Some(gas_limit),
+            ..Call::new("send_input_with_gas", Payload::input(payload), value)
+        }))
+    }
+
+    fn send_input_delayed<Range: RangeBounds<usize>>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+        delay: u32,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            delay: Some(delay),
+            ..Call::new("send_input_delayed", Payload::input(payload), value)
+        }))
+    }
+
+    fn send_input_with_gas_delayed<Range: RangeBounds<usize>>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+        delay: u32,
+    ) -> Result<MessageId> {
+        scripted_send_failure()?;
+        Ok(record(Call {
+            destination: Some(program),
+            gas_limit: Some(gas_limit),
+            delay: Some(delay),
+            ..Call::new(
+                "send_input_with_gas_delayed",
+                Payload::input(payload),
+                value,
+            )
+        }))
+    }
+
+    fn send_bytes_for_reply<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new("send_bytes_for_reply", Payload::bytes(payload), value)
+        });
+        Ok(ReplyFuture::new(message_id, Ok))
+    }
+
+    fn send_bytes_from_reservation_for_reply<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            reservation_id: Some(reservation_id),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new(
+                "send_bytes_from_reservation_for_reply",
+                Payload::bytes(payload),
+                value,
+            )
+        });
+        Ok(ReplyFuture::new(message_id, Ok))
+    }
+
+    fn send_bytes_with_gas_for_reply<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            gas_limit: Some(gas_limit),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new(
+                "send_bytes_with_gas_for_reply",
+                Payload::bytes(payload),
+                value,
+            )
+        });
+        Ok(ReplyFuture::new(message_id, Ok))
+    }
+
+    fn send_for_reply<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new("send_for_reply", Payload::encode(payload), value)
+        });
+        Ok(ReplyFuture::new(message_id, Ok))
+    }
+
+    fn send_from_reservation_for_reply<Encodable: Encode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            reservation_id: Some(reservation_id),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new(
+                "send_from_reservation_for_reply",
+                Payload::encode(payload),
+                value,
+            )
+        });
+        Ok(ReplyFuture::new(message_id, Ok))
+    }
+
+    fn send_with_gas_for_reply<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            gas_limit: Some(gas_limit),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new("send_with_gas_for_reply", Payload::encode(payload), value)
+        });
+        Ok(ReplyFuture::new(message_id, Ok))
+    }
+
+    fn send_input_for_reply<Range: RangeBounds<usize>>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new("send_input_for_reply", Payload::input(payload), value)
+        });
+        Ok(ReplyFuture::new(message_id, Ok))
+    }
+
+    fn send_input_with_gas_for_reply<Range: RangeBounds<usize>>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            gas_limit: Some(gas_limit),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new(
+                "send_input_with_gas_for_reply",
+                Payload::input(payload),
+                value,
+            )
+        });
+        Ok(ReplyFuture::new(message_id, Ok))
+    }
+
+    fn send_bytes_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new("send_bytes_for_reply_as", Payload::bytes(payload), value)
+        });
+        Ok(ReplyFuture::new(message_id, decode))
+    }
+
+    fn send_bytes_from_reservation_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            reservation_id: Some(reservation_id),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new(
+                "send_bytes_from_reservation_for_reply_as",
+                Payload::bytes(payload),
+                value,
+            )
+        });
+        Ok(ReplyFuture::new(message_id, decode))
+    }
+
+    fn send_bytes_with_gas_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            gas_limit: Some(gas_limit),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new(
+                "send_bytes_with_gas_for_reply_as",
+                Payload::bytes(payload),
+                value,
+            )
+        });
+        Ok(ReplyFuture::new(message_id, decode))
+    }
+
+    fn send_for_reply_as<Encodable: Encode, Decodable: Decode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new("send_for_reply_as", Payload::encode(payload), value)
+        });
+        Ok(ReplyFuture::new(message_id, decode))
+    }
+
+    fn send_from_reservation_for_reply_as<Encodable: Encode, Decodable: Decode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            reservation_id: Some(reservation_id),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new(
+                "send_from_reservation_for_reply_as",
+                Payload::encode(payload),
+                value,
+            )
+        });
+        Ok(ReplyFuture::new(message_id, decode))
+    }
+
+    fn send_with_gas_for_reply_as<Encodable: Encode, Decodable: Decode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            gas_limit: Some(gas_limit),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new(
+                "send_with_gas_for_reply_as",
+                Payload::encode(payload),
+                value,
+            )
+        });
+        Ok(ReplyFuture::new(message_id, decode))
+    }
+
+    fn send_input_for_reply_as<Range: RangeBounds<usize>, Decodable: Decode>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new("send_input_for_reply_as", Payload::input(payload), value)
+        });
+        Ok(ReplyFuture::new(message_id, decode))
+    }
+
+    fn send_input_with_gas_for_reply_as<Range: RangeBounds<usize>, Decodable: Decode>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        scripted_send_failure()?;
+        let message_id = record(Call {
+            destination: Some(program),
+            gas_limit: Some(gas_limit),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new(
+                "send_input_with_gas_for_reply_as",
+                Payload::input(payload),
+                value,
+            )
+        });
+        Ok(ReplyFuture::new(message_id, decode))
+    }
+
+    fn reply_bytes<Buffer: AsRef<[u8]>>(payload: Buffer, value: u128) -> Result<MessageId> {
+        Ok(record(Call::new(
+            "reply_bytes",
+            Payload::bytes(payload),
+            value,
+        )))
+    }
+
+    fn reply_bytes_with_gas<Buffer: AsRef<[u8]>>(
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId> {
+        Ok(record(Call {
+            gas_limit: Some(gas_limit),
+            ..Call::new("reply_bytes_with_gas", Payload::bytes(payload), value)
+        }))
+    }
+
+    fn reply_bytes_from_reservation<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<MessageId> {
+        Ok(record(Call {
+            reservation_id: Some(reservation_id),
+            ..Call::new(
+                "reply_bytes_from_reservation",
+                Payload::bytes(payload),
+                value,
+            )
+        }))
+    }
+
+    fn reply<Encodable: Encode>(payload: Encodable, value: u128) -> Result<MessageId> {
+        Ok(record(Call::new("reply", Payload::encode(payload), value)))
+    }
+
+    fn reply_with_gas<Encodable: Encode>(
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId> {
+        Ok(record(Call {
+            gas_limit: Some(gas_limit),
+            ..Call::new("reply_with_gas", Payload::encode(payload), value)
+        }))
+    }
+
+    fn reply_from_reservation<Encodable: Encode>(
+        reservation_id: ReservationId,
+        payload: Encodable,
+        value: u128,
+    ) -> Result<MessageId> {
+        Ok(record(Call {
+            reservation_id: Some(reservation_id),
+            ..Call::new("reply_from_reservation", Payload::encode(payload), value)
+        }))
+    }
+
+    fn reply_input<Range: RangeBounds<usize>>(value: u128, payload: Range) -> Result<MessageId> {
+        Ok(record(Call::new(
+            "reply_input",
+            Payload::input(payload),
+            value,
+        )))
+    }
+
+    fn reply_input_with_gas<Range: RangeBounds<usize>>(
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+    ) -> Result<MessageId> {
+        Ok(record(Call {
+            gas_limit: Some(gas_limit),
+            ..Call::new("reply_input_with_gas", Payload::input(payload), value)
+        }))
+    }
+
+    fn create_program<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<(MessageId, ActorId)> {
+        let program_id = next_program_id();
+        let message_id = record(Call {
+            destination: Some(program_id),
+            code_id: Some(code_id),
+            ..Call::new("create_program", Payload::bytes(payload), value)
+        });
+        Ok((message_id, program_id))
+    }
+
+    fn create_program_with_gas<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<(MessageId, ActorId)> {
+        let program_id = next_program_id();
+        let message_id = record(Call {
+            destination: Some(program_id),
+            code_id: Some(code_id),
+            gas_limit: Some(gas_limit),
+            ..Call::new("create_program_with_gas", Payload::bytes(payload), value)
+        });
+        Ok((message_id, program_id))
+    }
+
+    fn create_program_delayed<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+        delay: u32,
+    ) -> Result<(MessageId, ActorId)> {
+        let program_id = next_program_id();
+        let message_id = record(Call {
+            destination: Some(program_id),
+            code_id: Some(code_id),
+            delay: Some(delay),
+            ..Call::new("create_program_delayed", Payload::bytes(payload), value)
+        });
+        Ok((message_id, program_id))
+    }
+
+    fn create_program_with_gas_delayed<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        delay: u32,
+    ) -> Result<(MessageId, ActorId)> {
+        let program_id = next_program_id();
+        let message_id = record(Call {
+            destination: Some(program_id),
+            code_id: Some(code_id),
+            gas_limit: Some(gas_limit),
+            delay: Some(delay),
+            ..Call::new(
+                "create_program_with_gas_delayed",
+                Payload::bytes(payload),
+                value,
+            )
+        });
+        Ok((message_id, program_id))
+    }
+
+    fn create_program_for_reply<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CreateProgramFuture> {
+        let program_id = next_program_id();
+        let message_id = record(Call {
+            destination: Some(program_id),
+            code_id: Some(code_id),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new("create_program_for_reply", Payload::bytes(payload), value)
+        });
+        Ok(CreateProgramReplyFuture::new(message_id, program_id, Ok))
+    }
+
+    fn create_program_with_gas_for_reply<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CreateProgramFuture> {
+        let program_id = next_program_id();
+        let message_id = record(Call {
+            destination: Some(program_id),
+            code_id: Some(code_id),
+            gas_limit: Some(gas_limit),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new(
+                "create_program_with_gas_for_reply",
+                Payload::bytes(payload),
+                value,
+            )
+        });
+        Ok(CreateProgramReplyFuture::new(message_id, program_id, Ok))
+    }
+
+    fn create_program_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecCreateProgramFuture<Decodable>> {
+        let program_id = next_program_id();
+        let message_id = record(Call {
+            destination: Some(program_id),
+            code_id: Some(code_id),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new(
+                "create_program_for_reply_as",
+                Payload::bytes(payload),
+                value,
+            )
+        });
+        Ok(CreateProgramReplyFuture::new(
+            message_id, program_id, decode,
+        ))
+    }
+
+    fn create_program_with_gas_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecCreateProgramFuture<Decodable>> {
+        let program_id = next_program_id();
+        let message_id = record(Call {
+            destination: Some(program_id),
+            code_id: Some(code_id),
+            gas_limit: Some(gas_limit),
+            reply_deposit: Some(reply_deposit),
+            ..Call::new(
+                "create_program_with_gas_for_reply_as",
+                Payload::bytes(payload),
+                value,
+            )
+        });
+        Ok(CreateProgramReplyFuture::new(
+            message_id, program_id, decode,
+        ))
+    }
+}
@@ -0,0 +1,430 @@
+//! Abstraction over the `gstd` functions called by the builders.
+//!
+//! Every `execute()` dispatches through [`ActiveBackend`], which is [`GstdBackend`] by default.
+//! With the `mock` feature enabled, it is replaced by [`mock::MockBackend`], which records the
+//! calls in memory instead of performing syscalls, so code using the builders can be tested with
+//! plain `cargo test`. With the `trace` feature enabled, the selected backend is wrapped in
+//! [`TracedBackend`], which records every call in [`crate::trace`].
+
+use core::{future::Future, ops::RangeBounds};
+use gstd::{errors::Result, prelude::*, ActorId, CodeId, Decode, Encode, MessageId, ReservationId};
+
+pub use runtime::GstdBackend;
+#[cfg(feature = "trace")]
+pub use traced::TracedBackend;
+
+#[cfg(feature = "mock")]
+pub mod mock;
+mod runtime;
+#[cfg(feature = "trace")]
+mod traced;
+
+/// The backend that performs the calls.
+#[cfg(not(feature = "mock"))]
+pub type BaseBackend = GstdBackend;
+
+/// The backend that performs the calls.
+#[cfg(feature = "mock")]
+pub type BaseBackend = mock::MockBackend;
+
+/// The backend used by the builders.
+#[cfg(not(feature = "trace"))]
+pub type ActiveBackend = BaseBackend;
+
+/// The backend used by the builders.
+#[cfg(feature = "trace")]
+pub type ActiveBackend = TracedBackend<BaseBackend>;
+
+/// The future returned by `for_reply()` builders.
+pub type MessageFuture = <ActiveBackend as Backend>::MessageFuture;
+
+/// The future returned by `for_reply_as::<Decodable>()` builders.
+pub type CodecMessageFuture<Decodable> = <ActiveBackend as Backend>::CodecMessageFuture<Decodable>;
+
+/// The future returned by `create_program(...).for_reply()` builders.
+pub type CreateProgramFuture = <ActiveBackend as Backend>::CreateProgramFuture;
+
+/// The future returned by `create_program(...).for_reply_as::<Decodable>()` builders.
+pub type CodecCreateProgramFuture<Decodable> =
+    <ActiveBackend as Backend>::CodecCreateProgramFuture<Decodable>;
+
+/// A future that resolves to the reply to a sent message.
+pub trait WaitingReply {
+    /// Returns the id of the sent message.
+    fn waiting_reply_to(&self) -> MessageId;
+}
+
+/// A future that resolves to the reply to the init message of a created program.
+pub trait WaitingProgram: WaitingReply {
+    /// Returns the id of the created program.
+    fn program_id(&self) -> ActorId;
+}
+
+/// A set of functions with the same signatures as the `gstd` functions called by the builders.
+pub trait Backend {
+    type MessageFuture: Future<Output = Result<Vec<u8>>> + WaitingReply;
+    type CodecMessageFuture<Decodable: Decode>: Future<Output = Result<Decodable>> + WaitingReply;
+    type CreateProgramFuture: Future<Output = Result<(ActorId, Vec<u8>)>> + WaitingProgram;
+    type CodecCreateProgramFuture<Decodable: Decode>: Future<Output = Result<(ActorId, Decodable)>>
+        + WaitingProgram;
+
+    fn gas_available() -> u64;
+
+    fn value_available() -> u128;
+
+    fn block_height() -> u32;
+
+    fn program_id() -> ActorId;
+
+    fn message_id() -> MessageId;
+
+    fn message_source() -> ActorId;
+
+    fn message_value() -> u128;
+
+    fn message_size() -> usize;
+
+    fn load_bytes() -> Result<Vec<u8>>;
+
+    fn exit(inheritor: ActorId) -> !;
+
+    fn wait() -> !;
+
+    fn wait_for(duration: u32) -> !;
+
+    fn wait_up_to(duration: u32) -> !;
+
+    fn wake(message_id: MessageId) -> Result<()>;
+
+    fn wake_delayed(message_id: MessageId, delay: u32) -> Result<()>;
+
+    fn send_bytes<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<MessageId>;
+
+    fn send_bytes_from_reservation<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<MessageId>;
+
+    fn send_bytes_with_gas<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId>;
+
+    fn send_bytes_delayed<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId>;
+
+    fn send_bytes_delayed_from_reservation<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId>;
+
+    fn send_bytes_with_gas_delayed<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId>;
+
+    fn send<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+    ) -> Result<MessageId>;
+
+    fn send_from_reservation<Encodable: Encode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+    ) -> Result<MessageId>;
+
+    fn send_with_gas<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId>;
+
+    fn send_delayed<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId>;
+
+    fn send_delayed_from_reservation<Encodable: Encode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId>;
+
+    fn send_with_gas_delayed<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId>;
+
+    fn send_input<Range: RangeBounds<usize>>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+    ) -> Result<MessageId>;
+
+    fn send_input_with_gas<Range: RangeBounds<usize>>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+    ) -> Result<MessageId>;
+
+    fn send_input_delayed<Range: RangeBounds<usize>>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+        delay: u32,
+    ) -> Result<MessageId>;
+
+    fn send_input_with_gas_delayed<Range: RangeBounds<usize>>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+        delay: u32,
+    ) -> Result<MessageId>;
+
+    fn send_bytes_for_reply<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture>;
+
+    fn send_bytes_from_reservation_for_reply<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture>;
+
+    fn send_bytes_with_gas_for_reply<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture>;
+
+    fn send_for_reply<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture>;
+
+    fn send_from_reservation_for_reply<Encodable: Encode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture>;
+
+    fn send_with_gas_for_reply<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture>;
+
+    fn send_input_for_reply<Range: RangeBounds<usize>>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture>;
+
+    fn send_input_with_gas_for_reply<Range: RangeBounds<usize>>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture>;
+
+    fn send_bytes_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>>;
+
+    fn send_bytes_from_reservation_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>>;
+
+    fn send_bytes_with_gas_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>>;
+
+    fn send_for_reply_as<Encodable: Encode, Decodable: Decode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>>;
+
+    fn send_from_reservation_for_reply_as<Encodable: Encode, Decodable: Decode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>>;
+
+    fn send_with_gas_for_reply_as<Encodable: Encode, Decodable: Decode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>>;
+
+    fn send_input_for_reply_as<Range: RangeBounds<usize>, Decodable: Decode>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>>;
+
+    fn send_input_with_gas_for_reply_as<Range: RangeBounds<usize>, Decodable: Decode>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>>;
+
+    fn reply_bytes<Buffer: AsRef<[u8]>>(payload: Buffer, value: u128) -> Result<MessageId>;
+
+    fn reply_bytes_with_gas<Buffer: AsRef<[u8]>>(
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId>;
+
+    fn reply_bytes_from_reservation<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<MessageId>;
+
+    fn reply<Encodable: Encode>(payload: Encodable, value: u128) -> Result<MessageId>;
+
+    fn reply_with_gas<Encodable: Encode>(
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId>;
+
+    fn reply_from_reservation<Encodable: Encode>(
+        reservation_id: ReservationId,
+        payload: Encodable,
+        value: u128,
+    ) -> Result<MessageId>;
+
+    fn reply_input<Range: RangeBounds<usize>>(value: u128, payload: Range) -> Result<MessageId>;
+
+    fn reply_input_with_gas<Range: RangeBounds<usize>>(
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+    ) -> Result<MessageId>;
+
+    fn create_program<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<(MessageId, ActorId)>;
+
+    fn create_program_with_gas<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<(MessageId, ActorId)>;
+
+    fn create_program_delayed<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+        delay: u32,
+    ) -> Result<(MessageId, ActorId)>;
+
+    fn create_program_with_gas_delayed<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        delay: u32,
+    ) -> Result<(MessageId, ActorId)>;
+
+    fn create_program_for_reply<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CreateProgramFuture>;
+
+    fn create_program_with_gas_for_reply<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CreateProgramFuture>;
+
+    fn create_program_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecCreateProgramFuture<Decodable>>;
+
+    fn create_program_with_gas_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecCreateProgramFuture<Decodable>>;
+}
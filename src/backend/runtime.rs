@@ -0,0 +1,572 @@
+use super::{Backend, WaitingProgram, WaitingReply};
+use core::ops::RangeBounds;
+use gstd::{
+    errors::Result,
+    exec,
+    msg::{self, CodecMessageFuture, MessageFuture},
+    prelude::*,
+    prog::{CodecCreateProgramFuture, CreateProgramFuture, ProgramGenerator},
+    ActorId, CodeId, Decode, Encode, MessageId, ReservationId,
+};
+
+/// The backend that calls `gstd`.
+pub struct GstdBackend;
+
+impl WaitingReply for MessageFuture {
+    fn waiting_reply_to(&self) -> MessageId {
+        self.waiting_reply_to
+    }
+}
+
+impl<Decodable: Decode> WaitingReply for CodecMessageFuture<Decodable> {
+    fn waiting_reply_to(&self) -> MessageId {
+        self.waiting_reply_to
+    }
+}
+
+impl WaitingReply for CreateProgramFuture {
+    fn waiting_reply_to(&self) -> MessageId {
+        self.waiting_reply_to
+    }
+}
+
+impl WaitingProgram for CreateProgramFuture {
+    fn program_id(&self) -> ActorId {
+        self.program_id
+    }
+}
+
+impl<Decodable: Decode> WaitingReply for CodecCreateProgramFuture<Decodable> {
+    fn waiting_reply_to(&self) -> MessageId {
+        self.waiting_reply_to
+    }
+}
+
+impl<Decodable: Decode> WaitingProgram for CodecCreateProgramFuture<Decodable> {
+    fn program_id(&self) -> ActorId {
+        self.program_id
+    }
+}
+
+impl Backend for GstdBackend {
+    type MessageFuture = MessageFuture;
+    type CodecMessageFuture<Decodable: Decode> = CodecMessageFuture<Decodable>;
+    type CreateProgramFuture = CreateProgramFuture;
+    type CodecCreateProgramFuture<Decodable: Decode> = CodecCreateProgramFuture<Decodable>;
+
+    fn gas_available() -> u64 {
+        exec::gas_available()
+    }
+
+    fn value_available() -> u128 {
+        exec::value_available()
+    }
+
+    fn block_height() -> u32 {
+        exec::block_height()
+    }
+
+    fn program_id() -> ActorId {
+        exec::program_id()
+    }
+
+    fn message_id() -> MessageId {
+        msg::id()
+    }
+
+    fn message_source() -> ActorId {
+        msg::source()
+    }
+
+    fn message_value() -> u128 {
+        msg::value()
+    }
+
+    fn message_size() -> usize {
+        msg::size()
+    }
+
+    fn load_bytes() -> Result<Vec<u8>> {
+        msg::load_bytes()
+    }
+
+    fn exit(inheritor: ActorId) -> ! {
+        exec::exit(inheritor)
+    }
+
+    fn wait() -> ! {
+        exec::wait()
+    }
+
+    fn wait_for(duration: u32) -> ! {
+        exec::wait_for(duration)
+    }
+
+    fn wait_up_to(duration: u32) -> ! {
+        exec::wait_up_to(duration)
+    }
+
+    fn wake(message_id: MessageId) -> Result<()> {
+        exec::wake(message_id)
+    }
+
+    fn wake_delayed(message_id: MessageId, delay: u32) -> Result<()> {
+        exec::wake_delayed(message_id, delay)
+    }
+
+    fn send_bytes<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<MessageId> {
+        msg::send_bytes(program, payload, value)
+    }
+
+    fn send_bytes_from_reservation<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<MessageId> {
+        msg::send_bytes_from_reservation(reservation_id, program, payload, value)
+    }
+
+    fn send_bytes_with_gas<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId> {
+        msg::send_bytes_with_gas(program, payload, gas_limit, value)
+    }
+
+    fn send_bytes_delayed<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        msg::send_bytes_delayed(program, payload, value, delay)
+    }
+
+    fn send_bytes_delayed_from_reservation<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        msg::send_bytes_delayed_from_reservation(reservation_id, program, payload, value, delay)
+    }
+
+    fn send_bytes_with_gas_delayed<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        msg::send_bytes_with_gas_delayed(program, payload, gas_limit, value, delay)
+    }
+
+    fn send<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+    ) -> Result<MessageId> {
+        msg::send(program, payload, value)
+    }
+
+    fn send_from_reservation<Encodable: Encode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+    ) -> Result<MessageId> {
+        msg::send_from_reservation(reservation_id, program, payload, value)
+    }
+
+    fn send_with_gas<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId> {
+        msg::send_with_gas(program, payload, gas_limit, value)
+    }
+
+    fn send_delayed<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        msg::send_delayed(program, payload, value, delay)
+    }
+
+    fn send_delayed_from_reservation<Encodable: Encode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        msg::send_delayed_from_reservation(reservation_id, program, payload, value, delay)
+    }
+
+    fn send_with_gas_delayed<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        msg::send_with_gas_delayed(program, payload, gas_limit, value, delay)
+    }
+
+    fn send_input<Range: RangeBounds<usize>>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+    ) -> Result<MessageId> {
+        msg::send_input(program, value, payload)
+    }
+
+    fn send_input_with_gas<Range: RangeBounds<usize>>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+    ) -> Result<MessageId> {
+        msg::send_input_with_gas(program, gas_limit, value, payload)
+    }
+
+    fn send_input_delayed<Range: RangeBounds<usize>>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+        delay: u32,
+    ) -> Result<MessageId> {
+        msg::send_input_delayed(program, value, payload, delay)
+    }
+
+    fn send_input_with_gas_delayed<Range: RangeBounds<usize>>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+        delay: u32,
+    ) -> Result<MessageId> {
+        msg::send_input_with_gas_delayed(program, gas_limit, value, payload, delay)
+    }
+
+    fn send_bytes_for_reply<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        msg::send_bytes_for_reply(program, payload, value, reply_deposit)
+    }
+
+    fn send_bytes_from_reservation_for_reply<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        msg::send_bytes_from_reservation_for_reply(
+            reservation_id,
+            program,
+            payload,
+            value,
+            reply_deposit,
+        )
+    }
+
+    fn send_bytes_with_gas_for_reply<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        msg::send_bytes_with_gas_for_reply(program, payload, gas_limit, value, reply_deposit)
+    }
+
+    fn send_for_reply<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        msg::send_for_reply(program, payload, value, reply_deposit)
+    }
+
+    fn send_from_reservation_for_reply<Encodable: Encode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        msg::send_from_reservation_for_reply(reservation_id, program, payload, value, reply_deposit)
+    }
+
+    fn send_with_gas_for_reply<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        msg::send_with_gas_for_reply(program, payload, gas_limit, value, reply_deposit)
+    }
+
+    fn send_input_for_reply<Range: RangeBounds<usize>>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        msg::send_input_for_reply(program, value, payload, reply_deposit)
+    }
+
+    fn send_input_with_gas_for_reply<Range: RangeBounds<usize>>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        msg::send_input_with_gas_for_reply(program, gas_limit, value, payload, reply_deposit)
+    }
+
+    fn send_bytes_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        msg::send_bytes_for_reply_as(program, payload, value, reply_deposit)
+    }
+
+    fn send_bytes_from_reservation_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        msg::send_bytes_from_reservation_for_reply_as(
+            reservation_id,
+            program,
+            payload,
+            value,
+            reply_deposit,
+        )
+    }
+
+    fn send_bytes_with_gas_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        msg::send_bytes_with_gas_for_reply_as(program, payload, gas_limit, value, reply_deposit)
+    }
+
+    fn send_for_reply_as<Encodable: Encode, Decodable: Decode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        msg::send_for_reply_as(program, payload, value, reply_deposit)
+    }
+
+    fn send_from_reservation_for_reply_as<Encodable: Encode, Decodable: Decode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        msg::send_from_reservation_for_reply_as(
+            reservation_id,
+            program,
+            payload,
+            value,
+            reply_deposit,
+        )
+    }
+
+    fn send_with_gas_for_reply_as<Encodable: Encode, Decodable: Decode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        msg::send_with_gas_for_reply_as(program, payload, gas_limit, value, reply_deposit)
+    }
+
+    fn send_input_for_reply_as<Range: RangeBounds<usize>, Decodable: Decode>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        msg::send_input_for_reply_as(program, value, payload, reply_deposit)
+    }
+
+    fn send_input_with_gas_for_reply_as<Range: RangeBounds<usize>, Decodable: Decode>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        msg::send_input_with_gas_for_reply_as(program, gas_limit, value, payload, reply_deposit)
+    }
+
+    fn reply_bytes<Buffer: AsRef<[u8]>>(payload: Buffer, value: u128) -> Result<MessageId> {
+        msg::reply_bytes(payload, value)
+    }
+
+    fn reply_bytes_with_gas<Buffer: AsRef<[u8]>>(
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId> {
+        msg::reply_bytes_with_gas(payload, gas_limit, value)
+    }
+
+    fn reply_bytes_from_reservation<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<MessageId> {
+        msg::reply_bytes_from_reservation(reservation_id, payload, value)
+    }
+
+    fn reply<Encodable: Encode>(payload: Encodable, value: u128) -> Result<MessageId> {
+        msg::reply(payload, value)
+    }
+
+    fn reply_with_gas<Encodable: Encode>(
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId> {
+        msg::reply_with_gas(payload, gas_limit, value)
+    }
+
+    fn reply_from_reservation<Encodable: Encode>(
+        reservation_id: ReservationId,
+        payload: Encodable,
+        value: u128,
+    ) -> Result<MessageId> {
+        msg::reply_from_reservation(reservation_id, payload, value)
+    }
+
+    fn reply_input<Range: RangeBounds<usize>>(value: u128, payload: Range) -> Result<MessageId> {
+        msg::reply_input(value, payload)
+    }
+
+    fn reply_input_with_gas<Range: RangeBounds<usize>>(
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+    ) -> Result<MessageId> {
+        msg::reply_input_with_gas(gas_limit, value, payload)
+    }
+
+    fn create_program<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<(MessageId, ActorId)> {
+        ProgramGenerator::create_program(code_id, payload, value)
+    }
+
+    fn create_program_with_gas<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<(MessageId, ActorId)> {
+        ProgramGenerator::create_program_with_gas(code_id, payload, gas_limit, value)
+    }
+
+    fn create_program_delayed<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+        delay: u32,
+    ) -> Result<(MessageId, ActorId)> {
+        ProgramGenerator::create_program_delayed(code_id, payload, value, delay)
+    }
+
+    fn create_program_with_gas_delayed<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        delay: u32,
+    ) -> Result<(MessageId, ActorId)> {
+        ProgramGenerator::create_program_with_gas_delayed(code_id, payload, gas_limit, value, delay)
+    }
+
+    fn create_program_for_reply<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CreateProgramFuture> {
+        ProgramGenerator::create_program_for_reply(code_id, payload, value, reply_deposit)
+    }
+
+    fn create_program_with_gas_for_reply<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CreateProgramFuture> {
+        ProgramGenerator::create_program_with_gas_for_reply(
+            code_id,
+            payload,
+            gas_limit,
+            value,
+            reply_deposit,
+        )
+    }
+
+    fn create_program_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecCreateProgramFuture<Decodable>> {
+        ProgramGenerator::create_program_for_reply_as(code_id, payload, value, reply_deposit)
+    }
+
+    fn create_program_with_gas_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecCreateProgramFuture<Decodable>> {
+        ProgramGenerator::create_program_with_gas_for_reply_as(
+            code_id,
+            payload,
+            gas_limit,
+            value,
+            reply_deposit,
+        )
+    }
+}
@@ -0,0 +1,1264 @@
+use super::{Backend, WaitingProgram, WaitingReply};
+use crate::trace::{self, BuilderKind, PayloadDigest, TraceEntry, TraceOptions};
+use core::{marker::PhantomData, ops::RangeBounds};
+use gstd::{errors::Result, prelude::*, ActorId, CodeId, Decode, Encode, MessageId, ReservationId};
+
+/// The backend that records every call of `B` in [`crate::trace`].
+pub struct TracedBackend<B>(PhantomData<B>);
+
+fn record<B: Backend>(
+    kind: BuilderKind,
+    destination: Option<ActorId>,
+    code_id: Option<CodeId>,
+    options: TraceOptions,
+    payload: PayloadDigest,
+    message_id: Option<MessageId>,
+) {
+    trace::push(TraceEntry {
+        index: 0,
+        block_height: B::block_height(),
+        kind,
+        destination,
+        code_id,
+        options,
+        payload,
+        message_id,
+    });
+}
+
+impl<B: Backend> Backend for TracedBackend<B> {
+    type MessageFuture = B::MessageFuture;
+    type CodecMessageFuture<Decodable: Decode> = B::CodecMessageFuture<Decodable>;
+    type CreateProgramFuture = B::CreateProgramFuture;
+    type CodecCreateProgramFuture<Decodable: Decode> = B::CodecCreateProgramFuture<Decodable>;
+
+    fn gas_available() -> u64 {
+        B::gas_available()
+    }
+
+    fn value_available() -> u128 {
+        B::value_available()
+    }
+
+    fn block_height() -> u32 {
+        B::block_height()
+    }
+
+    fn program_id() -> ActorId {
+        B::program_id()
+    }
+
+    fn message_id() -> MessageId {
+        B::message_id()
+    }
+
+    fn message_source() -> ActorId {
+        B::message_source()
+    }
+
+    fn message_value() -> u128 {
+        B::message_value()
+    }
+
+    fn message_size() -> usize {
+        B::message_size()
+    }
+
+    fn load_bytes() -> Result<Vec<u8>> {
+        B::load_bytes()
+    }
+
+    fn exit(inheritor: ActorId) -> ! {
+        B::exit(inheritor)
+    }
+
+    fn wait() -> ! {
+        B::wait()
+    }
+
+    fn wait_for(duration: u32) -> ! {
+        B::wait_for(duration)
+    }
+
+    fn wait_up_to(duration: u32) -> ! {
+        B::wait_up_to(duration)
+    }
+
+    fn wake(message_id: MessageId) -> Result<()> {
+        B::wake(message_id)
+    }
+
+    fn wake_delayed(message_id: MessageId, delay: u32) -> Result<()> {
+        B::wake_delayed(message_id, delay)
+    }
+
+    fn send_bytes<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::send_bytes(program, payload, value);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_bytes_from_reservation<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::send_bytes_from_reservation(reservation_id, program, payload, value);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                reservation_id: Some(reservation_id),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_bytes_with_gas<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::send_bytes_with_gas(program, payload, gas_limit, value);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_bytes_delayed<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::send_bytes_delayed(program, payload, value, delay);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                delay: Some(delay),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_bytes_delayed_from_reservation<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result =
+            B::send_bytes_delayed_from_reservation(reservation_id, program, payload, value, delay);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                delay: Some(delay),
+                reservation_id: Some(reservation_id),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_bytes_with_gas_delayed<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::send_bytes_with_gas_delayed(program, payload, gas_limit, value, delay);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                delay: Some(delay),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::send(program, payload, value);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_from_reservation<Encodable: Encode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::send_from_reservation(reservation_id, program, payload, value);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                reservation_id: Some(reservation_id),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_with_gas<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::send_with_gas(program, payload, gas_limit, value);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_delayed<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::send_delayed(program, payload, value, delay);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                delay: Some(delay),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_delayed_from_reservation<Encodable: Encode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result =
+            B::send_delayed_from_reservation(reservation_id, program, payload, value, delay);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                delay: Some(delay),
+                reservation_id: Some(reservation_id),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_with_gas_delayed<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+        delay: u32,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::send_with_gas_delayed(program, payload, gas_limit, value, delay);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                delay: Some(delay),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_input<Range: RangeBounds<usize>>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::input(&payload);
+        let result = B::send_input(program, value, payload);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_input_with_gas<Range: RangeBounds<usize>>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::input(&payload);
+        let result = B::send_input_with_gas(program, gas_limit, value, payload);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_input_delayed<Range: RangeBounds<usize>>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+        delay: u32,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::input(&payload);
+        let result = B::send_input_delayed(program, value, payload, delay);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                delay: Some(delay),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_input_with_gas_delayed<Range: RangeBounds<usize>>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+        delay: u32,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::input(&payload);
+        let result = B::send_input_with_gas_delayed(program, gas_limit, value, payload, delay);
+        record::<B>(
+            BuilderKind::Send,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                delay: Some(delay),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn send_bytes_for_reply<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::send_bytes_for_reply(program, payload, value, reply_deposit);
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_bytes_from_reservation_for_reply<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::send_bytes_from_reservation_for_reply(
+            reservation_id,
+            program,
+            payload,
+            value,
+            reply_deposit,
+        );
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                reservation_id: Some(reservation_id),
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_bytes_with_gas_for_reply<Buffer: AsRef<[u8]>>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result =
+            B::send_bytes_with_gas_for_reply(program, payload, gas_limit, value, reply_deposit);
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_for_reply<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::send_for_reply(program, payload, value, reply_deposit);
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_from_reservation_for_reply<Encodable: Encode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::send_from_reservation_for_reply(
+            reservation_id,
+            program,
+            payload,
+            value,
+            reply_deposit,
+        );
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                reservation_id: Some(reservation_id),
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_with_gas_for_reply<Encodable: Encode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::send_with_gas_for_reply(program, payload, gas_limit, value, reply_deposit);
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_input_for_reply<Range: RangeBounds<usize>>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        let payload_digest = PayloadDigest::input(&payload);
+        let result = B::send_input_for_reply(program, value, payload, reply_deposit);
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_input_with_gas_for_reply<Range: RangeBounds<usize>>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::MessageFuture> {
+        let payload_digest = PayloadDigest::input(&payload);
+        let result =
+            B::send_input_with_gas_for_reply(program, gas_limit, value, payload, reply_deposit);
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_bytes_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result =
+            B::send_bytes_for_reply_as::<Buffer, Decodable>(program, payload, value, reply_deposit);
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_bytes_from_reservation_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::send_bytes_from_reservation_for_reply_as::<Buffer, Decodable>(
+            reservation_id,
+            program,
+            payload,
+            value,
+            reply_deposit,
+        );
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                reservation_id: Some(reservation_id),
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_bytes_with_gas_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        program: ActorId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::send_bytes_with_gas_for_reply_as::<Buffer, Decodable>(
+            program,
+            payload,
+            gas_limit,
+            value,
+            reply_deposit,
+        );
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_for_reply_as<Encodable: Encode, Decodable: Decode>(
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result =
+            B::send_for_reply_as::<Encodable, Decodable>(program, payload, value, reply_deposit);
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_from_reservation_for_reply_as<Encodable: Encode, Decodable: Decode>(
+        reservation_id: ReservationId,
+        program: ActorId,
+        payload: Encodable,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::send_from_reservation_for_reply_as::<Encodable, Decodable>(
+            reservation_id,
+            program,
+            payload,
+            value,
+            reply_deposit,
+        );
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                reservation_id: Some(reservation_id),
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_with_gas_for_reply_as<Encodable: Encode, Decodable: Decode>(
+        program: ActorId,
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::send_with_gas_for_reply_as::<Encodable, Decodable>(
+            program,
+            payload,
+            gas_limit,
+            value,
+            reply_deposit,
+        );
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_input_for_reply_as<Range: RangeBounds<usize>, Decodable: Decode>(
+        program: ActorId,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        let payload_digest = PayloadDigest::input(&payload);
+        let result =
+            B::send_input_for_reply_as::<Range, Decodable>(program, value, payload, reply_deposit);
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn send_input_with_gas_for_reply_as<Range: RangeBounds<usize>, Decodable: Decode>(
+        program: ActorId,
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecMessageFuture<Decodable>> {
+        let payload_digest = PayloadDigest::input(&payload);
+        let result = B::send_input_with_gas_for_reply_as::<Range, Decodable>(
+            program,
+            gas_limit,
+            value,
+            payload,
+            reply_deposit,
+        );
+        record::<B>(
+            BuilderKind::SendForReply,
+            Some(program),
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn reply_bytes<Buffer: AsRef<[u8]>>(payload: Buffer, value: u128) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::reply_bytes(payload, value);
+        record::<B>(
+            BuilderKind::Reply,
+            None,
+            None,
+            TraceOptions {
+                value,
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn reply_bytes_with_gas<Buffer: AsRef<[u8]>>(
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::reply_bytes_with_gas(payload, gas_limit, value);
+        record::<B>(
+            BuilderKind::Reply,
+            None,
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn reply_bytes_from_reservation<Buffer: AsRef<[u8]>>(
+        reservation_id: ReservationId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::reply_bytes_from_reservation(reservation_id, payload, value);
+        record::<B>(
+            BuilderKind::Reply,
+            None,
+            None,
+            TraceOptions {
+                value,
+                reservation_id: Some(reservation_id),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn reply<Encodable: Encode>(payload: Encodable, value: u128) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::reply(payload, value);
+        record::<B>(
+            BuilderKind::Reply,
+            None,
+            None,
+            TraceOptions {
+                value,
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn reply_with_gas<Encodable: Encode>(
+        payload: Encodable,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::reply_with_gas(payload, gas_limit, value);
+        record::<B>(
+            BuilderKind::Reply,
+            None,
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn reply_from_reservation<Encodable: Encode>(
+        reservation_id: ReservationId,
+        payload: Encodable,
+        value: u128,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::bytes(&payload.encode());
+        let result = B::reply_from_reservation(reservation_id, payload, value);
+        record::<B>(
+            BuilderKind::Reply,
+            None,
+            None,
+            TraceOptions {
+                value,
+                reservation_id: Some(reservation_id),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn reply_input<Range: RangeBounds<usize>>(value: u128, payload: Range) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::input(&payload);
+        let result = B::reply_input(value, payload);
+        record::<B>(
+            BuilderKind::Reply,
+            None,
+            None,
+            TraceOptions {
+                value,
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn reply_input_with_gas<Range: RangeBounds<usize>>(
+        gas_limit: u64,
+        value: u128,
+        payload: Range,
+    ) -> Result<MessageId> {
+        let payload_digest = PayloadDigest::input(&payload);
+        let result = B::reply_input_with_gas(gas_limit, value, payload);
+        record::<B>(
+            BuilderKind::Reply,
+            None,
+            None,
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().copied(),
+        );
+        result
+    }
+
+    fn create_program<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+    ) -> Result<(MessageId, ActorId)> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::create_program(code_id, payload, value);
+        record::<B>(
+            BuilderKind::CreateProgram,
+            result.as_ref().ok().map(|&(_, program_id)| program_id),
+            Some(code_id),
+            TraceOptions {
+                value,
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(|&(message_id, _)| message_id),
+        );
+        result
+    }
+
+    fn create_program_with_gas<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+    ) -> Result<(MessageId, ActorId)> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::create_program_with_gas(code_id, payload, gas_limit, value);
+        record::<B>(
+            BuilderKind::CreateProgram,
+            result.as_ref().ok().map(|&(_, program_id)| program_id),
+            Some(code_id),
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(|&(message_id, _)| message_id),
+        );
+        result
+    }
+
+    fn create_program_delayed<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+        delay: u32,
+    ) -> Result<(MessageId, ActorId)> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::create_program_delayed(code_id, payload, value, delay);
+        record::<B>(
+            BuilderKind::CreateProgram,
+            result.as_ref().ok().map(|&(_, program_id)| program_id),
+            Some(code_id),
+            TraceOptions {
+                value,
+                delay: Some(delay),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(|&(message_id, _)| message_id),
+        );
+        result
+    }
+
+    fn create_program_with_gas_delayed<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        delay: u32,
+    ) -> Result<(MessageId, ActorId)> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::create_program_with_gas_delayed(code_id, payload, gas_limit, value, delay);
+        record::<B>(
+            BuilderKind::CreateProgram,
+            result.as_ref().ok().map(|&(_, program_id)| program_id),
+            Some(code_id),
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                delay: Some(delay),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(|&(message_id, _)| message_id),
+        );
+        result
+    }
+
+    fn create_program_for_reply<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CreateProgramFuture> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::create_program_for_reply(code_id, payload, value, reply_deposit);
+        record::<B>(
+            BuilderKind::CreateProgramForReply,
+            result.as_ref().ok().map(WaitingProgram::program_id),
+            Some(code_id),
+            TraceOptions {
+                value,
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn create_program_with_gas_for_reply<Buffer: AsRef<[u8]>>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CreateProgramFuture> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result =
+            B::create_program_with_gas_for_reply(code_id, payload, gas_limit, value, reply_deposit);
+        record::<B>(
+            BuilderKind::CreateProgramForReply,
+            result.as_ref().ok().map(WaitingProgram::program_id),
+            Some(code_id),
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn create_program_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        code_id: CodeId,
+        payload: Buffer,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecCreateProgramFuture<Decodable>> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::create_program_for_reply_as::<Buffer, Decodable>(
+            code_id,
+            payload,
+            value,
+            reply_deposit,
+        );
+        record::<B>(
+            BuilderKind::CreateProgramForReply,
+            result.as_ref().ok().map(WaitingProgram::program_id),
+            Some(code_id),
+            TraceOptions {
+                value,
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+
+    fn create_program_with_gas_for_reply_as<Buffer: AsRef<[u8]>, Decodable: Decode>(
+        code_id: CodeId,
+        payload: Buffer,
+        gas_limit: u64,
+        value: u128,
+        reply_deposit: u64,
+    ) -> Result<Self::CodecCreateProgramFuture<Decodable>> {
+        let payload_digest = PayloadDigest::bytes(payload.as_ref());
+        let result = B::create_program_with_gas_for_reply_as::<Buffer, Decodable>(
+            code_id,
+            payload,
+            gas_limit,
+            value,
+            reply_deposit,
+        );
+        record::<B>(
+            BuilderKind::CreateProgramForReply,
+            result.as_ref().ok().map(WaitingProgram::program_id),
+            Some(code_id),
+            TraceOptions {
+                value,
+                gas_limit: Some(gas_limit),
+                reply_deposit: Some(reply_deposit),
+                ..Default::default()
+            },
+            payload_digest,
+            result.as_ref().ok().map(WaitingReply::waiting_reply_to),
+        );
+        result
+    }
+}
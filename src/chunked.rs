@@ -0,0 +1,375 @@
+//! Transfer of payloads larger than one message.
+//!
+//! `builder::send_chunked(...)` encodes the payload, splits it into chunks and sends each chunk as
+//! a separate [`Chunk`] message. The sender keeps a [`ChunkSender`] in the program state, which
+//! numbers its transfers. The receiver keeps a [`ChunkAssembler`] and passes every incoming chunk
+//! to it:
+//!
+//! ```ignore
+//! // Sender
+//! static mut SENDER: ChunkSender = ChunkSender::new();
+//!
+//! builder::send_chunked(unsafe { &mut SENDER }, program, migration)
+//!     .with_chunk_size(64 * 1024)
+//!     .execute()?;
+//!
+//! // Receiver
+//! static mut ASSEMBLER: Option<ChunkAssembler<Migration>> = None;
+//!
+//! #[no_mangle]
+//! extern "C" fn handle() {
+//!     let assembler = unsafe { ASSEMBLER.get_or_insert_with(ChunkAssembler::new) };
+//!     if let Some(migration) = assembler.receive().expect("Invalid chunk") {
+//!         // All chunks were received
+//!     }
+//! }
+//! ```
+//!
+//! The chunks of a transfer must arrive in order: a chunk that skips an index aborts the transfer,
+//! a chunk that repeats an index is rejected and the transfer continues. The transfers of a sender
+//! must start in the order of their ids: a chunk of a transfer with an id below the last one
+//! reassembled from the sender is rejected, so a replayed transfer is not delivered twice.
+
+use crate::{
+    backend::{ActiveBackend, Backend},
+    common::{PayloadEncodableW, ProgramW},
+    errors::{Error, Result},
+    generated::SendBuilder,
+};
+use alloc::collections::BTreeMap;
+use core::{fmt, marker::PhantomData};
+use gstd::{codec, errors::ContractError, prelude::*, ActorId, Decode, Encode, MessageId};
+
+/// The size of the [`Chunk`] fields other than `data`, including the length prefix of `data`.
+pub const CHUNK_OVERHEAD: usize = 32;
+
+/// A part of a payload sent by `builder::send_chunked(...)`.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct Chunk {
+    /// The id of the transfer, unique among the transfers started by the sender.
+    pub transfer_id: u64,
+    /// The sequence number of the chunk, starting from 0.
+    pub index: u32,
+    /// The number of chunks in the transfer.
+    pub count: u32,
+    /// The Adler-32 checksum of `data`.
+    pub checksum: u32,
+    pub data: Vec<u8>,
+}
+
+/// Same encoding as [`Chunk`], but borrows the data from the encoded payload.
+#[derive(Encode)]
+#[codec(crate = gstd::codec)]
+struct ChunkRef<'a> {
+    transfer_id: u64,
+    index: u32,
+    count: u32,
+    checksum: u32,
+    data: &'a [u8],
+}
+
+/// Computes the Adler-32 checksum.
+pub fn checksum(data: &[u8]) -> u32 {
+    const MOD: u32 = 65_521;
+    // The largest number of bytes that can be summed before `b` overflows.
+    const BLOCK: usize = 5_552;
+
+    let (mut a, mut b) = (1u32, 0u32);
+    for block in data.chunks(BLOCK) {
+        for &byte in block {
+            a += byte as u32;
+            b += a;
+        }
+        a %= MOD;
+        b %= MOD;
+    }
+
+    (b << 16) | a
+}
+
+/// The numbering of the transfers started by `builder::send_chunked(...)`, kept in the program
+/// state of the sender.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct ChunkSender {
+    next_transfer_id: u64,
+}
+
+impl ChunkSender {
+    pub const fn new() -> Self {
+        Self {
+            next_transfer_id: 0,
+        }
+    }
+
+    /// Returns the id of the next transfer.
+    pub fn next_transfer_id(&self) -> u64 {
+        self.next_transfer_id
+    }
+
+    fn start_transfer(&mut self) -> Result<u64> {
+        let transfer_id = self.next_transfer_id;
+        self.next_transfer_id = transfer_id
+            .checked_add(1)
+            .ok_or(Error::TransferIdsExhausted)?;
+        Ok(transfer_id)
+    }
+}
+
+pub struct ChunkSizeW(pub(crate) usize);
+
+pub trait IntoChunkSize {
+    fn into_chunk_size(self) -> usize;
+}
+
+impl IntoChunkSize for () {
+    /// The largest chunk that fits into a message.
+    fn into_chunk_size(self) -> usize {
+        crate::config::get()
+            .max_payload_size()
+            .saturating_sub(CHUNK_OVERHEAD)
+    }
+}
+
+impl IntoChunkSize for ChunkSizeW {
+    fn into_chunk_size(self) -> usize {
+        self.0
+    }
+}
+
+/// A builder that splits a payload into several messages.
+pub struct ChunkedSendBuilder<'a, Fields = ((), (), ())> {
+    sender: &'a mut ChunkSender,
+    fields: Fields,
+}
+
+impl<'a, Encodable: Encode> ChunkedSendBuilder<'a, (ProgramW, PayloadEncodableW<Encodable>, ())> {
+    pub fn encode(sender: &'a mut ChunkSender, program: ActorId, payload: Encodable) -> Self {
+        Self {
+            sender,
+            fields: (ProgramW(program), PayloadEncodableW(payload), ()),
+        }
+    }
+}
+
+impl<'a, Program, Payload> ChunkedSendBuilder<'a, (Program, Payload, ())> {
+    /// Sets the maximum number of payload bytes in one chunk.
+    pub fn with_chunk_size(
+        self,
+        chunk_size: usize,
+    ) -> ChunkedSendBuilder<'a, (Program, Payload, ChunkSizeW)> {
+        let (program, payload, _) = self.fields;
+        ChunkedSendBuilder {
+            sender: self.sender,
+            fields: (program, payload, ChunkSizeW(chunk_size)),
+        }
+    }
+}
+
+impl<Encodable: Encode, ChunkSize: IntoChunkSize>
+    ChunkedSendBuilder<'_, (ProgramW, PayloadEncodableW<Encodable>, ChunkSize)>
+{
+    /// Sends the chunks and returns their message ids.
+    ///
+    /// If sending a chunk fails, the chunks sent before it are not revoked.
+    pub fn execute(self) -> Result<Vec<MessageId>> {
+        let (ProgramW(program), PayloadEncodableW(payload), chunk_size) = self.fields;
+        let chunk_size = chunk_size.into_chunk_size();
+        if chunk_size == 0 {
+            return Err(Error::InvalidChunkSize { chunk_size });
+        }
+
+        let payload = payload.encode();
+        let count = payload.chunks(chunk_size).len().max(1);
+        let count = u32::try_from(count).map_err(|_| Error::InvalidChunkSize { chunk_size })?;
+        let transfer_id = self.sender.start_transfer()?;
+
+        let mut message_ids = Vec::with_capacity(count as usize);
+        // An empty payload is sent as one empty chunk.
+        let chunks = payload
+            .chunks(chunk_size)
+            .chain(payload.is_empty().then_some(&[][..]));
+        for (index, data) in (0..).zip(chunks) {
+            let chunk = ChunkRef {
+                transfer_id,
+                index,
+                count,
+                checksum: checksum(data),
+                data,
+            };
+            message_ids.push(SendBuilder::encode(program, chunk).execute()?);
+        }
+
+        Ok(message_ids)
+    }
+}
+
+/// An error returned by [`ChunkAssembler`].
+#[derive(Debug)]
+pub enum ChunkError {
+    /// The chunk skips the expected index. The transfer is aborted.
+    Gap {
+        transfer_id: u64,
+        expected: u32,
+        index: u32,
+    },
+    /// The chunk repeats an index that was already received, or belongs to a transfer with an id
+    /// below the last one reassembled from the sender. The chunk is ignored.
+    Duplicate { transfer_id: u64, index: u32 },
+    /// The checksum does not match the data. The transfer is aborted.
+    Checksum { transfer_id: u64, index: u32 },
+    /// The number of chunks differs from the previous chunks of the transfer. The transfer is
+    /// aborted.
+    CountMismatch {
+        transfer_id: u64,
+        expected: u32,
+        count: u32,
+    },
+    /// The message is not a chunk, or the reassembled payload can't be decoded.
+    Decode(codec::Error),
+}
+
+impl fmt::Display for ChunkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gap {
+                transfer_id,
+                expected,
+                index,
+            } => write!(
+                f,
+                "transfer {transfer_id}: expected chunk {expected}, got chunk {index}"
+            ),
+            Self::Duplicate { transfer_id, index } => {
+                write!(
+                    f,
+                    "transfer {transfer_id}: chunk {index} was already received"
+                )
+            }
+            Self::Checksum { transfer_id, index } => {
+                write!(
+                    f,
+                    "transfer {transfer_id}: chunk {index} has an invalid checksum"
+                )
+            }
+            Self::CountMismatch {
+                transfer_id,
+                expected,
+                count,
+            } => write!(
+                f,
+                "transfer {transfer_id}: expected {expected} chunks, got a chunk of {count}"
+            ),
+            Self::Decode(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+struct Transfer {
+    count: u32,
+    next_index: u32,
+    data: Vec<u8>,
+}
+
+/// Reassembles the payloads sent by `builder::send_chunked(...)`.
+///
+/// Several transfers can be in progress at the same time, they are told apart by the sender and
+/// the transfer id. Only the id following the last reassembled transfer is kept per sender.
+pub struct ChunkAssembler<Decodable> {
+    transfers: BTreeMap<(ActorId, u64), Transfer>,
+    completed: BTreeMap<ActorId, u64>,
+    _marker: PhantomData<fn() -> Decodable>,
+}
+
+impl<Decodable: Decode> ChunkAssembler<Decodable> {
+    pub const fn new() -> Self {
+        Self {
+            transfers: BTreeMap::new(),
+            completed: BTreeMap::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of transfers that are not complete yet.
+    pub fn pending(&self) -> usize {
+        self.transfers.len()
+    }
+
+    /// Loads the message being processed as a chunk sent by `msg::source()`.
+    pub fn receive(&mut self) -> Result<Option<Decodable>, ChunkError> {
+        let bytes = ActiveBackend::load_bytes().map_err(|err| match err {
+            ContractError::Decode(err) => ChunkError::Decode(err),
+            _ => ChunkError::Decode("Unable to load the message".into()),
+        })?;
+        let chunk = Chunk::decode(&mut bytes.as_slice()).map_err(ChunkError::Decode)?;
+        self.push(ActiveBackend::message_source(), chunk)
+    }
+
+    /// Adds a chunk sent by `source`. Returns the decoded payload once the last chunk is added.
+    pub fn push(&mut self, source: ActorId, chunk: Chunk) -> Result<Option<Decodable>, ChunkError> {
+        let Chunk {
+            transfer_id,
+            index,
+            count,
+            checksum: expected_checksum,
+            data,
+        } = chunk;
+        let key = (source, transfer_id);
+        let completed = self
+            .completed
+            .get(&source)
+            .is_some_and(|&next_transfer_id| transfer_id < next_transfer_id);
+        if completed && !self.transfers.contains_key(&key) {
+            return Err(ChunkError::Duplicate { transfer_id, index });
+        }
+
+        let transfer = self.transfers.entry(key).or_insert_with(|| Transfer {
+            count,
+            next_index: 0,
+            data: Vec::new(),
+        });
+        let error = if transfer.count != count {
+            Some(ChunkError::CountMismatch {
+                transfer_id,
+                expected: transfer.count,
+                count,
+            })
+        } else if index < transfer.next_index {
+            return Err(ChunkError::Duplicate { transfer_id, index });
+        } else if index > transfer.next_index {
+            Some(ChunkError::Gap {
+                transfer_id,
+                expected: transfer.next_index,
+                index,
+            })
+        } else if checksum(&data) != expected_checksum {
+            Some(ChunkError::Checksum { transfer_id, index })
+        } else {
+            None
+        };
+        if let Some(err) = error {
+            self.transfers.remove(&key);
+            return Err(err);
+        }
+
+        transfer.data.extend_from_slice(&data);
+        transfer.next_index += 1;
+        if transfer.next_index < count {
+            return Ok(None);
+        }
+
+        let transfer = self.transfers.remove(&key).expect("the transfer exists");
+        let next_transfer_id = self.completed.entry(source).or_default();
+        *next_transfer_id = (*next_transfer_id).max(transfer_id.saturating_add(1));
+        Decodable::decode(&mut transfer.data.as_slice())
+            .map(Some)
+            .map_err(ChunkError::Decode)
+    }
+}
+
+impl<Decodable: Decode> Default for ChunkAssembler<Decodable> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
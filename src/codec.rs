@@ -0,0 +1,198 @@
+//! Payload codecs used instead of SCALE by `.with_codec::<Codec>()` and
+//! `builder::send_with::<Codec>(...)`.
+//!
+//! By default, the builders call the `gstd` functions that encode payloads and decode replies with
+//! SCALE. With a codec, payloads are encoded before calling the `*_bytes*` functions, and replies
+//! are decoded by the codec:
+//!
+//! ```ignore
+//! use gstd_fluent::{self as builder, codec::Json};
+//!
+//! let output: Output = builder::send_with::<Json, _>(program, Input { a: 1 })
+//!     .for_reply_as::<Output>()
+//!     .execute()?
+//!     .await?;
+//! ```
+//!
+//! `builder::send(program, payload).with_codec::<Json>()` is the same as `send_with`, the payload
+//! only has to be encodable by the codec.
+//!
+//! The [`Scale`], [`Json`] and [`Borsh`] codecs are enabled by the `scale`, `serde-json` and
+//! `borsh` features.
+//!
+//! `.encoded_size()` is not available with a codec, since a codec can't tell a size without
+//! encoding. `.with_reply_deposit_auto()` after `.for_reply_as::<Decodable>()` sizes the deposit
+//! from the payload encoded by the codec instead of `Decodable::max_encoded_len()`; set the reply
+//! deposit with `.with_reply_deposit(...)` if the reply is much larger than the payload.
+
+use crate::backend::{WaitingProgram, WaitingReply};
+use core::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    task::{Context, Poll},
+};
+#[cfg(any(feature = "scale", feature = "serde-json", feature = "borsh"))]
+use gstd::errors::ContractError;
+use gstd::{errors::Result, prelude::*, ActorId, MessageId};
+
+/// A format of payloads and replies.
+pub trait Codec {}
+
+/// A codec that can encode `T`.
+pub trait Encoder<T: ?Sized>: Codec {
+    fn encode(value: &T) -> Result<Vec<u8>>;
+}
+
+/// A codec that can decode `T`.
+pub trait Decoder<T>: Codec {
+    fn decode(bytes: &[u8]) -> Result<T>;
+}
+
+/// SCALE, the same format as the builders use without a codec.
+#[cfg(feature = "scale")]
+pub struct Scale;
+
+#[cfg(feature = "scale")]
+impl Codec for Scale {}
+
+#[cfg(feature = "scale")]
+impl<T: gstd::Encode + ?Sized> Encoder<T> for Scale {
+    fn encode(value: &T) -> Result<Vec<u8>> {
+        Ok(value.encode())
+    }
+}
+
+#[cfg(feature = "scale")]
+impl<T: gstd::Decode> Decoder<T> for Scale {
+    fn decode(mut bytes: &[u8]) -> Result<T> {
+        T::decode(&mut bytes).map_err(ContractError::Decode)
+    }
+}
+
+/// JSON via `serde_json`.
+#[cfg(feature = "serde-json")]
+pub struct Json;
+
+#[cfg(feature = "serde-json")]
+impl Codec for Json {}
+
+#[cfg(feature = "serde-json")]
+impl<T: serde::Serialize + ?Sized> Encoder<T> for Json {
+    fn encode(value: &T) -> Result<Vec<u8>> {
+        serde_json::to_vec(value).map_err(|_| ContractError::Convert("Failed to encode JSON"))
+    }
+}
+
+#[cfg(feature = "serde-json")]
+impl<T: serde::de::DeserializeOwned> Decoder<T> for Json {
+    fn decode(bytes: &[u8]) -> Result<T> {
+        serde_json::from_slice(bytes).map_err(|_| ContractError::Convert("Failed to decode JSON"))
+    }
+}
+
+/// Borsh via `borsh`.
+#[cfg(feature = "borsh")]
+pub struct Borsh;
+
+#[cfg(feature = "borsh")]
+impl Codec for Borsh {}
+
+#[cfg(feature = "borsh")]
+impl<T: borsh::BorshSerialize + ?Sized> Encoder<T> for Borsh {
+    fn encode(value: &T) -> Result<Vec<u8>> {
+        borsh::to_vec(value).map_err(|_| ContractError::Convert("Failed to encode Borsh"))
+    }
+}
+
+#[cfg(feature = "borsh")]
+impl<T: borsh::BorshDeserialize> Decoder<T> for Borsh {
+    fn decode(bytes: &[u8]) -> Result<T> {
+        borsh::from_slice(bytes).map_err(|_| ContractError::Convert("Failed to decode Borsh"))
+    }
+}
+
+/// The future returned by `for_reply_as::<Decodable>()` builders with a codec.
+pub struct CodecReplyFuture<Reply, Decodable, PayloadCodec> {
+    reply: Reply,
+    _marker: PhantomData<fn() -> (Decodable, PayloadCodec)>,
+}
+
+impl<Reply, Decodable, PayloadCodec> CodecReplyFuture<Reply, Decodable, PayloadCodec> {
+    pub(crate) fn new(reply: Reply) -> Self {
+        Self {
+            reply,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Reply, Decodable, PayloadCodec> Future for CodecReplyFuture<Reply, Decodable, PayloadCodec>
+where
+    Reply: Future<Output = Result<Vec<u8>>> + Unpin,
+    PayloadCodec: Decoder<Decodable>,
+{
+    type Output = Result<Decodable>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.get_mut().reply)
+            .poll(cx)
+            .map(|reply| reply.and_then(|reply| PayloadCodec::decode(&reply)))
+    }
+}
+
+impl<Reply: WaitingReply, Decodable, PayloadCodec> WaitingReply
+    for CodecReplyFuture<Reply, Decodable, PayloadCodec>
+{
+    fn waiting_reply_to(&self) -> MessageId {
+        self.reply.waiting_reply_to()
+    }
+}
+
+/// The future returned by `create_program(...).for_reply_as::<Decodable>()` builders with a codec.
+pub struct CodecCreateProgramReplyFuture<Reply, Decodable, PayloadCodec> {
+    reply: Reply,
+    _marker: PhantomData<fn() -> (Decodable, PayloadCodec)>,
+}
+
+impl<Reply, Decodable, PayloadCodec> CodecCreateProgramReplyFuture<Reply, Decodable, PayloadCodec> {
+    pub(crate) fn new(reply: Reply) -> Self {
+        Self {
+            reply,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<Reply, Decodable, PayloadCodec> Future
+    for CodecCreateProgramReplyFuture<Reply, Decodable, PayloadCodec>
+where
+    Reply: Future<Output = Result<(ActorId, Vec<u8>)>> + Unpin,
+    PayloadCodec: Decoder<Decodable>,
+{
+    type Output = Result<(ActorId, Decodable)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.get_mut().reply).poll(cx).map(|reply| {
+            reply.and_then(|(program_id, reply)| {
+                PayloadCodec::decode(&reply).map(|reply| (program_id, reply))
+            })
+        })
+    }
+}
+
+impl<Reply: WaitingReply, Decodable, PayloadCodec> WaitingReply
+    for CodecCreateProgramReplyFuture<Reply, Decodable, PayloadCodec>
+{
+    fn waiting_reply_to(&self) -> MessageId {
+        self.reply.waiting_reply_to()
+    }
+}
+
+impl<Reply: WaitingProgram, Decodable, PayloadCodec> WaitingProgram
+    for CodecCreateProgramReplyFuture<Reply, Decodable, PayloadCodec>
+{
+    fn program_id(&self) -> ActorId {
+        self.reply.program_id()
+    }
+}
@@ -1,18 +1,32 @@
-pub use core::{marker::PhantomData, ops::RangeBounds};
-pub use gstd::{
-    errors::Result, msg::*, prog::*, ActorId, CodeId, Decode, Encode, MessageId, ReservationId,
+pub(crate) use crate::log::ExecuteLog;
+pub use crate::{
+    backend::{
+        ActiveBackend, Backend, CodecCreateProgramFuture, CodecMessageFuture, CreateProgramFuture,
+        MessageFuture,
+    },
+    codec::{Codec, CodecCreateProgramReplyFuture, CodecReplyFuture, Decoder, Encoder},
+    created::CreatedFuture,
+    errors::{Error, Result, ValidationError},
+    gas::GasBudget,
 };
+use core::ops::Bound;
+pub use core::{marker::PhantomData, ops::RangeBounds};
+pub use gstd::{codec::MaxEncodedLen, ActorId, CodeId, Decode, Encode, MessageId, ReservationId};
 
 pub struct ProgramW(pub(crate) ActorId);
 pub struct CodeIdW(pub(crate) CodeId);
 pub struct PayloadBytesW<Buffer: AsRef<[u8]>>(pub(crate) Buffer);
-pub struct PayloadEncodableW<Encodable: Encode>(pub(crate) Encodable);
+pub struct PayloadEncodableW<Encodable>(pub(crate) Encodable);
 pub struct PayloadInputW<Range: RangeBounds<usize>>(pub(crate) Range);
 pub struct ValueW(pub(crate) u128);
 pub struct ReservationIdW(pub(crate) ReservationId);
 pub struct GasLimitW(pub(crate) u64);
+pub struct GasFractionW(pub(crate) u64, pub(crate) u64);
+pub struct GasRemainingMinusW(pub(crate) u64);
+pub struct AllRemainingGasW;
+pub struct GasBudgetW<'a>(pub(crate) &'a mut GasBudget, pub(crate) u64);
 pub struct DelayW(pub(crate) u32);
-pub struct DecodableW<Decodable: Decode>(pub(crate) PhantomData<Decodable>);
+pub struct DecodableW<Decodable>(pub(crate) PhantomData<Decodable>);
 pub struct ReplyDepositW(pub(crate) u64);
 
 impl From<()> for ValueW {
@@ -27,10 +41,145 @@ impl From<()> for ReplyDepositW {
     }
 }
 
+impl ReplyDepositW {
+    pub(crate) fn auto(reply_size: usize) -> Self {
+        Self(crate::config::get().reply_deposit(reply_size))
+    }
+}
+
+pub trait IntoGasLimit: Sized {
+    fn into_gas_limit(self) -> Result<u64>;
+
+    /// Same as `into_gas_limit()`, but fails if the gas limit is below the mailbox threshold.
+    fn into_validated_gas_limit(self) -> Result<u64> {
+        validate_gas_limit(self.into_gas_limit()?)
+    }
+}
+
+impl IntoGasLimit for GasLimitW {
+    fn into_gas_limit(self) -> Result<u64> {
+        Ok(self.0)
+    }
+}
+
+impl IntoGasLimit for GasFractionW {
+    fn into_gas_limit(self) -> Result<u64> {
+        let GasFractionW(numerator, denominator) = self;
+        if denominator == 0 || numerator > denominator {
+            return Err(Error::InvalidGasFraction {
+                numerator,
+                denominator,
+            });
+        }
+
+        let gas_limit =
+            ActiveBackend::gas_available() as u128 * numerator as u128 / denominator as u128;
+        check_gas_floor(gas_limit as u64)
+    }
+}
+
+impl IntoGasLimit for GasRemainingMinusW {
+    fn into_gas_limit(self) -> Result<u64> {
+        check_gas_floor(ActiveBackend::gas_available().saturating_sub(self.0))
+    }
+}
+
+impl IntoGasLimit for AllRemainingGasW {
+    fn into_gas_limit(self) -> Result<u64> {
+        check_gas_floor(ActiveBackend::gas_available())
+    }
+}
+
+impl IntoGasLimit for GasBudgetW<'_> {
+    fn into_gas_limit(self) -> Result<u64> {
+        let GasBudgetW(budget, weight) = self;
+        budget.allot(weight)
+    }
+
+    /// The budget is not changed if the allotment is below the mailbox threshold.
+    fn into_validated_gas_limit(self) -> Result<u64> {
+        let GasBudgetW(budget, weight) = self;
+        validate_gas_limit(budget.share(weight)?)?;
+        budget.allot(weight)
+    }
+}
+
+fn check_gas_floor(gas_limit: u64) -> Result<u64> {
+    let floor = crate::config::get().gas_floor();
+    if gas_limit < floor {
+        return Err(Error::GasLimitBelowFloor { gas_limit, floor });
+    }
+
+    Ok(gas_limit)
+}
+
+pub(crate) fn validate_value(value: u128) -> Result<u128> {
+    match crate::config::get().existential_deposit() {
+        Some(existential_deposit) if value != 0 && value < existential_deposit => {
+            Err(ValidationError::ValueBelowExistentialDeposit {
+                value,
+                existential_deposit,
+            }
+            .into())
+        }
+        _ => Ok(value),
+    }
+}
+
+pub(crate) fn validate_gas_limit(gas_limit: u64) -> Result<u64> {
+    match crate::config::get().mailbox_threshold() {
+        Some(mailbox_threshold) if gas_limit < mailbox_threshold => {
+            Err(ValidationError::GasBelowMailboxThreshold {
+                gas_limit,
+                mailbox_threshold,
+            }
+            .into())
+        }
+        _ => Ok(gas_limit),
+    }
+}
+
+pub(crate) fn validate_payload_bytes<Buffer: AsRef<[u8]>>(payload: Buffer) -> Result<Buffer> {
+    check_payload_size(payload.as_ref().len())?;
+    Ok(payload)
+}
+
+pub(crate) fn validate_payload_encodable<Encodable: Encode>(
+    payload: Encodable,
+) -> Result<Encodable> {
+    check_payload_size(payload.encoded_size())?;
+    Ok(payload)
+}
+
+/// The size of the forwarded part of the incoming message is only known if the range is bounded.
+pub(crate) fn validate_payload_input<Range: RangeBounds<usize>>(payload: Range) -> Result<Range> {
+    let start = match payload.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    let end = match payload.end_bound() {
+        Bound::Included(&end) => end.saturating_add(1),
+        Bound::Excluded(&end) => end,
+        Bound::Unbounded => return Ok(payload),
+    };
+    check_payload_size(end.saturating_sub(start))?;
+    Ok(payload)
+}
+
+fn check_payload_size(len: usize) -> Result<()> {
+    let max = crate::config::get().max_payload_size();
+    if len > max {
+        return Err(Error::PayloadTooLarge { len, max });
+    }
+
+    Ok(())
+}
+
 pub trait PayloadWithGasReservationMarker {}
 
 impl<Buffer: AsRef<[u8]>> PayloadWithGasReservationMarker for PayloadBytesW<Buffer> {}
-impl<Encodable: Encode> PayloadWithGasReservationMarker for PayloadEncodableW<Encodable> {}
+impl<Encodable> PayloadWithGasReservationMarker for PayloadEncodableW<Encodable> {}
 
 // Currently the `GasLimit` and `ReservationId` generics do not overlap,
 // but in future versions of gstd they may be activated at the same time.
@@ -0,0 +1,211 @@
+//! Compression of encoded payloads, enabled by the `compression` feature.
+//!
+//! `.compressed()` encodes the payload with SCALE and compresses it with DEFLATE before sending.
+//! The compressed payload starts with a [`HEADER_LEN`]-byte header: the [`MAGIC`] bytes followed by
+//! the uncompressed length as a little-endian `u32`.
+//!
+//! ```ignore
+//! builder::send(program, order_book).compressed().execute()?;
+//! ```
+//!
+//! Compression is not detected on the receiving side, since an uncompressed payload may start with
+//! the same bytes. The receiver uses [`load()`] or [`decode()`] for payloads it expects to be
+//! compressed, and they reject payloads without the header:
+//!
+//! ```ignore
+//! #[no_mangle]
+//! extern "C" fn handle() {
+//!     let order_book: OrderBook = compression::load().expect("Unable to decode");
+//! }
+//! ```
+//!
+//! Compressed replies are decoded in the same way by `.for_reply_as_compressed::<Decodable>()`, so
+//! the reply must be sent with `.compressed()`.
+
+use crate::{
+    backend::{ActiveBackend, Backend},
+    codec::{Codec, Decoder, Encoder},
+    common::{DecodableW, PayloadEncodableW, ProgramW},
+    generated::{ReplyBuilder, SendBuilder, SendBuilderForReply},
+};
+use gstd::{
+    errors::{ContractError, Result},
+    prelude::*,
+    Decode, Encode,
+};
+use miniz_oxide::{deflate, inflate};
+
+/// The bytes that start a compressed payload.
+pub const MAGIC: [u8; 4] = *b"GFZ\x01";
+/// The length of the header that precedes the compressed data.
+pub const HEADER_LEN: usize = MAGIC.len() + 4;
+
+/// The DEFLATE level, a trade-off between the payload size and the gas spent on compression.
+const LEVEL: u8 = 6;
+
+/// Compresses the bytes and prepends the header.
+pub fn compress(bytes: &[u8]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(HEADER_LEN + bytes.len() / 2);
+    payload.extend_from_slice(&MAGIC);
+    payload.extend_from_slice(&(bytes.len() as u32).to_le_bytes());
+    payload.extend_from_slice(&deflate::compress_to_vec(bytes, LEVEL));
+    payload
+}
+
+/// Decompresses the payload. Returns an error if it doesn't start with the header.
+pub fn decompress(payload: &[u8]) -> Result<Vec<u8>> {
+    let Some(data) = payload.strip_prefix(&MAGIC) else {
+        return Err(ContractError::Convert("Payload is not compressed"));
+    };
+    if data.len() < HEADER_LEN - MAGIC.len() {
+        return Err(ContractError::Convert(
+            "Compressed payload header is truncated",
+        ));
+    }
+    let (len, data) = data.split_at(HEADER_LEN - MAGIC.len());
+    let len = u32::from_le_bytes([len[0], len[1], len[2], len[3]]) as usize;
+
+    // The limit protects from payloads that inflate far beyond the declared length.
+    let bytes = inflate::decompress_to_vec_with_limit(data, len)
+        .map_err(|_| ContractError::Convert("Failed to decompress payload"))?;
+    if bytes.len() != len {
+        return Err(ContractError::Convert(
+            "Decompressed payload length does not match the header",
+        ));
+    }
+
+    Ok(bytes)
+}
+
+/// Decompresses the payload and decodes it with SCALE.
+pub fn decode<Decodable: Decode>(payload: &[u8]) -> Result<Decodable> {
+    let bytes = decompress(payload)?;
+    Decodable::decode(&mut bytes.as_slice()).map_err(ContractError::Decode)
+}
+
+/// Loads the message being processed, decompresses it and decodes it with SCALE.
+pub fn load<Decodable: Decode>() -> Result<Decodable> {
+    decode(&ActiveBackend::load_bytes()?)
+}
+
+/// The codec used by `.compressed()`: SCALE and compression in both directions.
+pub struct Compressed;
+
+impl Codec for Compressed {}
+
+impl<Encodable: Encode + ?Sized> Encoder<Encodable> for Compressed {
+    fn encode(value: &Encodable) -> Result<Vec<u8>> {
+        Ok(compress(&value.encode()))
+    }
+}
+
+impl<Decodable: Decode> Decoder<Decodable> for Compressed {
+    fn decode(bytes: &[u8]) -> Result<Decodable> {
+        decode(bytes)
+    }
+}
+
+/// The codec used by `.for_reply_as_compressed::<Decodable>()`: the payload is sent uncompressed,
+/// the reply is decompressed.
+pub struct CompressedReply;
+
+impl Codec for CompressedReply {}
+
+impl<Encodable: Encode + ?Sized> Encoder<Encodable> for CompressedReply {
+    fn encode(value: &Encodable) -> Result<Vec<u8>> {
+        Ok(value.encode())
+    }
+}
+
+impl<Decodable: Decode> Decoder<Decodable> for CompressedReply {
+    fn decode(bytes: &[u8]) -> Result<Decodable> {
+        decode(bytes)
+    }
+}
+
+impl<Encodable: Encode, Value, Delay, GasLimit, ReservationId>
+    SendBuilder<(
+        ProgramW,
+        PayloadEncodableW<Encodable>,
+        Value,
+        Delay,
+        GasLimit,
+        ReservationId,
+    )>
+{
+    /// Compresses the encoded payload before sending.
+    #[allow(clippy::type_complexity)]
+    pub fn compressed(
+        self,
+    ) -> SendBuilder<
+        (
+            ProgramW,
+            PayloadEncodableW<Encodable>,
+            Value,
+            Delay,
+            GasLimit,
+            ReservationId,
+        ),
+        Compressed,
+    > {
+        self.with_codec()
+    }
+}
+
+impl<Encodable: Encode, Value, ReservationId, GasLimit>
+    ReplyBuilder<(PayloadEncodableW<Encodable>, Value, ReservationId, GasLimit)>
+{
+    /// Compresses the encoded payload before replying.
+    pub fn compressed(
+        self,
+    ) -> ReplyBuilder<(PayloadEncodableW<Encodable>, Value, ReservationId, GasLimit), Compressed>
+    {
+        self.with_codec()
+    }
+}
+
+impl<Program, Payload, Value, GasLimit, ReservationId>
+    SendBuilder<(Program, Payload, Value, (), GasLimit, ReservationId)>
+{
+    /// Same as `.for_reply_as::<Decodable>()`, but decompresses the reply.
+    #[allow(clippy::type_complexity)]
+    pub fn for_reply_as_compressed<Decodable: Decode>(
+        self,
+    ) -> SendBuilderForReply<
+        (
+            Program,
+            Payload,
+            Value,
+            GasLimit,
+            ReservationId,
+            (),
+            DecodableW<Decodable>,
+        ),
+        CompressedReply,
+    > {
+        self.with_codec::<CompressedReply>().for_reply_as()
+    }
+}
+
+impl<Program, Payload, Value, GasLimit, ReservationId>
+    SendBuilder<(Program, Payload, Value, (), GasLimit, ReservationId), Compressed>
+{
+    /// Same as `.for_reply_as::<Decodable>()`, the reply is decompressed.
+    #[allow(clippy::type_complexity)]
+    pub fn for_reply_as_compressed<Decodable: Decode>(
+        self,
+    ) -> SendBuilderForReply<
+        (
+            Program,
+            Payload,
+            Value,
+            GasLimit,
+            ReservationId,
+            (),
+            DecodableW<Decodable>,
+        ),
+        Compressed,
+    > {
+        self.for_reply_as()
+    }
+}
@@ -0,0 +1,164 @@
+//! Program-wide settings used by the builders at execution time.
+//!
+//! The settings are stored in the program memory, so it is enough to set them once in `init()`:
+//!
+//! ```ignore
+//! use gstd_fluent::config::{self, Config};
+//!
+//! #[no_mangle]
+//! extern "C" fn init() {
+//!     config::set(
+//!         Config::new()
+//!             .with_gas_floor(1_000_000)
+//!             .with_existential_deposit(10_000_000_000_000)
+//!             .with_mailbox_threshold(3_000)
+//!             .with_reply_deposit_cost(1_000_000, 1_000),
+//!     );
+//! }
+//! ```
+
+/// The maximum size of a message payload accepted by the Gear Protocol, 8 MiB.
+pub const MAX_PAYLOAD_SIZE: usize = 8 * 1024 * 1024;
+
+/// The settings used by the builders.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    pub(crate) gas_floor: u64,
+    pub(crate) existential_deposit: Option<u128>,
+    pub(crate) mailbox_threshold: Option<u64>,
+    pub(crate) reply_deposit_base: u64,
+    pub(crate) reply_deposit_per_byte: u64,
+    pub(crate) trace_capacity: usize,
+    pub(crate) max_payload_size: usize,
+}
+
+impl Config {
+    /// Creates the default settings.
+    pub const fn new() -> Self {
+        Self {
+            gas_floor: 0,
+            existential_deposit: None,
+            mailbox_threshold: None,
+            reply_deposit_base: 0,
+            reply_deposit_per_byte: 0,
+            trace_capacity: 64,
+            max_payload_size: MAX_PAYLOAD_SIZE,
+        }
+    }
+
+    /// Sets the minimum gas limit that `.with_gas_fraction(...)`, `.with_gas_remaining_minus(...)`
+    /// and `.with_all_remaining_gas()` are allowed to compute. The floor is 0 by default.
+    pub const fn with_gas_floor(mut self, gas_floor: u64) -> Self {
+        self.gas_floor = gas_floor;
+        self
+    }
+
+    /// Enables the validation of values passed to `send*` and `reply*` builders: a non-zero value
+    /// below the existential deposit is rejected before the message is sent.
+    pub const fn with_existential_deposit(mut self, existential_deposit: u128) -> Self {
+        self.existential_deposit = Some(existential_deposit);
+        self
+    }
+
+    /// Enables the validation of gas limits passed to `send*` and `reply*` builders: a gas limit
+    /// below the mailbox threshold is rejected before the message is sent.
+    ///
+    /// The builders can't tell a user from a program, so the check applies to every destination:
+    /// a message to a program with a gas limit below the threshold is rejected as well. Messages
+    /// sent without a gas limit are not checked.
+    pub const fn with_mailbox_threshold(mut self, mailbox_threshold: u64) -> Self {
+        self.mailbox_threshold = Some(mailbox_threshold);
+        self
+    }
+
+    /// Sets the cost model used by `.with_reply_deposit_auto()`: the reply deposit is `base` plus
+    /// `per_byte` for each byte of the maximum encoded size of the expected reply, or of the payload
+    /// if the builder has a codec. Both are 0 by default.
+    pub const fn with_reply_deposit_cost(mut self, base: u64, per_byte: u64) -> Self {
+        self.reply_deposit_base = base;
+        self.reply_deposit_per_byte = per_byte;
+        self
+    }
+
+    /// Sets the number of entries kept by [`crate::trace`] when the `trace` feature is enabled.
+    /// 64 by default, 0 disables the recording.
+    pub const fn with_trace_capacity(mut self, trace_capacity: usize) -> Self {
+        self.trace_capacity = trace_capacity;
+        self
+    }
+
+    /// Sets the maximum size of payloads passed to the builders: a larger payload is rejected
+    /// before the message is sent. [`MAX_PAYLOAD_SIZE`] by default.
+    pub const fn with_max_payload_size(mut self, max_payload_size: usize) -> Self {
+        self.max_payload_size = max_payload_size;
+        self
+    }
+
+    /// Returns the minimum gas limit computed from the available gas.
+    pub const fn gas_floor(&self) -> u64 {
+        self.gas_floor
+    }
+
+    /// Returns the existential deposit used to validate values, if any.
+    pub const fn existential_deposit(&self) -> Option<u128> {
+        self.existential_deposit
+    }
+
+    /// Returns the mailbox threshold used to validate gas limits, if any.
+    pub const fn mailbox_threshold(&self) -> Option<u64> {
+        self.mailbox_threshold
+    }
+
+    /// Returns the reply deposit computed for a reply of `reply_size` bytes.
+    pub const fn reply_deposit(&self, reply_size: usize) -> u64 {
+        self.reply_deposit_base.saturating_add(
+            self.reply_deposit_per_byte
+                .saturating_mul(reply_size as u64),
+        )
+    }
+
+    /// Returns the number of entries kept by [`crate::trace`].
+    pub const fn trace_capacity(&self) -> usize {
+        self.trace_capacity
+    }
+
+    /// Returns the maximum size of payloads passed to the builders.
+    pub const fn max_payload_size(&self) -> usize {
+        self.max_payload_size
+    }
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(not(feature = "mock"))]
+static mut CONFIG: Config = Config::new();
+
+/// Returns the current settings.
+#[cfg(not(feature = "mock"))]
+pub fn get() -> Config {
+    // SAFETY: Gear programs are single-threaded.
+    unsafe { CONFIG }
+}
+
+/// Replaces the current settings.
+#[cfg(not(feature = "mock"))]
+pub fn set(config: Config) {
+    // SAFETY: Gear programs are single-threaded.
+    unsafe { CONFIG = config }
+}
+
+/// Returns the current settings, kept per test thread by the mock backend.
+#[cfg(feature = "mock")]
+pub fn get() -> Config {
+    crate::backend::mock::config()
+}
+
+/// Replaces the current settings of the test thread until the next `mock::reset()`.
+#[cfg(feature = "mock")]
+pub fn set(config: Config) {
+    crate::backend::mock::set_config(config)
+}
@@ -0,0 +1,100 @@
+//! The result of `create_program(...).for_reply()` builders.
+//!
+//! The awaited future resolves to a [`Created`] with the id of the program, the id of its init
+//! message and the reply. If the init message fails, the error tells whether the program was not
+//! created at all or exists but was not initialized:
+//!
+//! ```ignore
+//! match builder::create_program(code_id, init).for_reply_as::<Ready>().execute()?.await {
+//!     Ok(Created { program, reply, .. }) => register(program, reply),
+//!     Err(Error::InitFailed { program, failure: InitFailure::OutOfGas }) => retry(program),
+//!     Err(Error::CreationRejected { .. }) => upload_code(),
+//!     Err(err) => return Err(err),
+//! }
+//! ```
+
+use crate::{
+    backend::{WaitingProgram, WaitingReply},
+    errors::{Error, InitFailure, Result},
+};
+use core::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use gstd::{
+    errors::{ContractError, ErrorReplyReason, SimpleExecutionError},
+    ActorId, MessageId,
+};
+
+/// A program created and initialized by `create_program(...).for_reply()` builders.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Created<Reply> {
+    /// The id of the program.
+    pub program: ActorId,
+    /// The id of the init message.
+    pub init_message: MessageId,
+    /// The reply to the init message.
+    pub reply: Reply,
+}
+
+/// The future returned by `create_program(...).for_reply()` builders.
+pub struct CreatedFuture<Reply> {
+    reply: Reply,
+}
+
+impl<Reply> CreatedFuture<Reply> {
+    pub(crate) fn new(reply: Reply) -> Self {
+        Self { reply }
+    }
+}
+
+impl<Reply, Decodable> Future for CreatedFuture<Reply>
+where
+    Reply: Future<Output = gstd::errors::Result<(ActorId, Decodable)>> + WaitingProgram + Unpin,
+{
+    type Output = Result<Created<Decodable>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+        let program = this.reply.program_id();
+        let init_message = this.reply.waiting_reply_to();
+        Pin::new(&mut this.reply).poll(cx).map(|reply| match reply {
+            Ok((program, reply)) => Ok(Created {
+                program,
+                init_message,
+                reply,
+            }),
+            Err(err) => Err(init_error(program, err)),
+        })
+    }
+}
+
+impl<Reply: WaitingReply> WaitingReply for CreatedFuture<Reply> {
+    fn waiting_reply_to(&self) -> MessageId {
+        self.reply.waiting_reply_to()
+    }
+}
+
+impl<Reply: WaitingProgram> WaitingProgram for CreatedFuture<Reply> {
+    fn program_id(&self) -> ActorId {
+        self.reply.program_id()
+    }
+}
+
+fn init_error(program: ActorId, err: ContractError) -> Error {
+    let ContractError::ErrorReply(_, reason) = err else {
+        return Error::Contract(err);
+    };
+
+    let failure = match reason {
+        ErrorReplyReason::FailedToCreateProgram(_) => {
+            return Error::CreationRejected { program };
+        }
+        ErrorReplyReason::Execution(SimpleExecutionError::RanOutOfGas) => InitFailure::OutOfGas,
+        ErrorReplyReason::Execution(err) => InitFailure::Trap(err),
+        reason => InitFailure::ErrorReply(reason),
+    };
+
+    Error::InitFailed { program, failure }
+}
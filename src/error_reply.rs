@@ -0,0 +1,114 @@
+//! Replies that carry either a value or a structured error.
+//!
+//! The reply is the SCALE-encoded `Result<Reply, ErrorReply>`. The program sends it with
+//! `builder::reply_result(...)`, or returns it from a function marked with
+//! [`#[handle]`](crate::handle):
+//!
+//! ```ignore
+//! #[gstd_fluent::handle]
+//! fn handle() -> Result<u64, ErrorReply> {
+//!     let amount: u64 = builder::incoming().decode().map_err(|_| ErrorReply::new(1, "Invalid amount"))?;
+//!     Ok(amount * 2)
+//! }
+//! ```
+//!
+//! The sender decodes it into `Result<Reply, Failure>` with `.for_reply_result::<Reply, Failure>()`:
+//!
+//! ```ignore
+//! let doubled: Result<u64, ErrorReply> = builder::send(program, 21u64)
+//!     .for_reply_result::<u64, ErrorReply>()
+//!     .execute()?
+//!     .await?;
+//! ```
+
+use crate::{
+    codec::{Codec, Decoder, Encoder},
+    common::DecodableW,
+    generated::{SendBuilder, SendBuilderForReply},
+};
+use core::fmt;
+use gstd::{
+    errors::{ContractError, Result},
+    prelude::*,
+    Decode, Encode,
+};
+
+/// The error part of a reply sent by `builder::reply_result(...)`.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct ErrorReply {
+    /// The program-defined error code, 0 if the error has no code.
+    pub code: u32,
+    pub message: String,
+}
+
+impl ErrorReply {
+    pub fn new(code: u32, message: impl Into<String>) -> Self {
+        Self {
+            code,
+            message: message.into(),
+        }
+    }
+}
+
+impl From<&str> for ErrorReply {
+    fn from(message: &str) -> Self {
+        Self::new(0, message)
+    }
+}
+
+impl From<String> for ErrorReply {
+    fn from(message: String) -> Self {
+        Self::new(0, message)
+    }
+}
+
+impl fmt::Display for ErrorReply {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error {}: {}", self.code, self.message)
+    }
+}
+
+/// The codec used by `.for_reply_result::<Reply, Failure>()`: the payload is encoded with SCALE,
+/// the error of the reply is converted from [`ErrorReply`].
+pub struct ResultReply;
+
+impl Codec for ResultReply {}
+
+impl<Encodable: Encode + ?Sized> Encoder<Encodable> for ResultReply {
+    fn encode(value: &Encodable) -> Result<Vec<u8>> {
+        Ok(value.encode())
+    }
+}
+
+impl<Reply: Decode, Failure: From<ErrorReply>> Decoder<Result<Reply, Failure>> for ResultReply {
+    fn decode(mut bytes: &[u8]) -> Result<Result<Reply, Failure>> {
+        let reply =
+            Result::<Reply, ErrorReply>::decode(&mut bytes).map_err(ContractError::Decode)?;
+        Ok(reply.map_err(Failure::from))
+    }
+}
+
+impl<Program, Payload, Value, GasLimit, ReservationId>
+    SendBuilder<(Program, Payload, Value, (), GasLimit, ReservationId)>
+{
+    /// Same as `.for_reply_as::<Result<Reply, Failure>>()`, but the error is converted from the
+    /// [`ErrorReply`] sent by `builder::reply_result(...)`.
+    #[allow(clippy::type_complexity)]
+    pub fn for_reply_result<Reply: Decode, Failure: From<ErrorReply>>(
+        self,
+    ) -> SendBuilderForReply<
+        (
+            Program,
+            Payload,
+            Value,
+            GasLimit,
+            ReservationId,
+            (),
+            DecodableW<Result<Reply, Failure>>,
+        ),
+        ResultReply,
+    > {
+        self.with_codec::<ResultReply>().for_reply_as()
+    }
+}
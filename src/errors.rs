@@ -0,0 +1,215 @@
+//! Errors that can occur when executing a builder.
+
+use core::fmt;
+use gstd::{
+    errors::{ErrorReplyReason, SimpleExecutionError},
+    ActorId,
+};
+
+pub use gstd::errors::ContractError;
+
+/// An error returned by `execute()`.
+#[derive(Debug)]
+pub enum Error {
+    /// The error returned by the underlying `gstd` function.
+    Contract(ContractError),
+    /// The gas fraction passed to `.with_gas_fraction(...)` is not in the range `0..=1`.
+    InvalidGasFraction { numerator: u64, denominator: u64 },
+    /// The gas limit computed from the available gas is below the configured floor.
+    GasLimitBelowFloor { gas_limit: u64, floor: u64 },
+    /// The allotment requested from a [`GasBudget`](crate::gas::GasBudget) exceeds what is left.
+    GasBudgetExceeded { requested: u64, remaining: u64 },
+    /// The (encoded) payload is larger than
+    /// [`Config::max_payload_size()`](crate::config::Config::max_payload_size).
+    PayloadTooLarge { len: usize, max: usize },
+    /// The chunk size passed to `.with_chunk_size(...)` is 0, or splits the payload into more than
+    /// `u32::MAX` chunks.
+    InvalidChunkSize { chunk_size: usize },
+    /// The [`ChunkSender`](crate::chunked::ChunkSender) has used up all transfer ids.
+    TransferIdsExhausted,
+    /// The amount passed to `builder::transfer(...)` is 0.
+    ZeroTransfer,
+    /// The transfer with `.keep_alive()` would leave the program with less than the existential
+    /// deposit.
+    KeepAliveViolated {
+        amount: u128,
+        value_available: u128,
+        existential_deposit: u128,
+    },
+    /// A job of a [`Scheduler`](crate::scheduler::Scheduler) was registered without a reservation
+    /// to send its first run with.
+    ReservationsExhausted { job_id: u64 },
+    /// The program was not created, e.g. its code does not exist.
+    CreationRejected { program: ActorId },
+    /// The program was created, but its init message failed.
+    InitFailed {
+        program: ActorId,
+        failure: InitFailure,
+    },
+    /// The message was rejected by the validation before it was sent.
+    Validation(ValidationError),
+    /// The message being processed was rejected by a guard of `builder::incoming()`.
+    Guard(GuardError),
+}
+
+/// An error returned when the validation enabled in [`Config`](crate::config::Config) fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ValidationError {
+    /// The value is not zero, but is below the existential deposit.
+    ValueBelowExistentialDeposit {
+        value: u128,
+        existential_deposit: u128,
+    },
+    /// The gas limit is below the mailbox threshold.
+    GasBelowMailboxThreshold {
+        gas_limit: u64,
+        mailbox_threshold: u64,
+    },
+}
+
+/// An error returned when a guard of [`Incoming`](crate::incoming::Incoming) fails.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GuardError {
+    /// The message was sent by another actor.
+    UnexpectedSource { expected: ActorId, actual: ActorId },
+    /// The value attached to the message is below the minimum.
+    ValueBelowMinimum { value: u128, min: u128 },
+    /// The payload is longer than the maximum.
+    PayloadTooLong { len: usize, max: usize },
+    /// The sender is not the owner or doesn't have the required role.
+    Unauthorized { actor: ActorId },
+}
+
+/// Why the init message of a created program failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InitFailure {
+    /// The init message ran out of gas.
+    OutOfGas,
+    /// The init message trapped, e.g. panicked.
+    Trap(SimpleExecutionError),
+    /// The reply to the init message has another error code.
+    ErrorReply(ErrorReplyReason),
+}
+
+/// `Result` type with [`Error`] as the default error.
+pub type Result<T, E = Error> = core::result::Result<T, E>;
+
+impl From<ContractError> for Error {
+    fn from(err: ContractError) -> Self {
+        Self::Contract(err)
+    }
+}
+
+impl From<ValidationError> for Error {
+    fn from(err: ValidationError) -> Self {
+        Self::Validation(err)
+    }
+}
+
+impl From<GuardError> for Error {
+    fn from(err: GuardError) -> Self {
+        Self::Guard(err)
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Contract(err) => write!(f, "{err:?}"),
+            Self::InvalidGasFraction {
+                numerator,
+                denominator,
+            } => write!(f, "invalid gas fraction {numerator}/{denominator}"),
+            Self::GasLimitBelowFloor { gas_limit, floor } => {
+                write!(
+                    f,
+                    "computed gas limit {gas_limit} is below the floor {floor}"
+                )
+            }
+            Self::GasBudgetExceeded {
+                requested,
+                remaining,
+            } => write!(
+                f,
+                "requested {requested} gas from the budget, but only {remaining} is left"
+            ),
+            Self::PayloadTooLarge { len, max } => {
+                write!(
+                    f,
+                    "payload of {len} bytes exceeds the maximum of {max} bytes"
+                )
+            }
+            Self::InvalidChunkSize { chunk_size } => write!(f, "invalid chunk size {chunk_size}"),
+            Self::TransferIdsExhausted => write!(f, "transfer ids are exhausted"),
+            Self::ZeroTransfer => write!(f, "transfer amount is 0"),
+            Self::KeepAliveViolated {
+                amount,
+                value_available,
+                existential_deposit,
+            } => write!(
+                f,
+                "transfer of {amount} out of {value_available} leaves less than the existential deposit {existential_deposit}"
+            ),
+            Self::ReservationsExhausted { job_id } => {
+                write!(f, "job {job_id} has no reservation left for its first run")
+            }
+            Self::CreationRejected { program } => write!(f, "program {program:?} was not created"),
+            Self::InitFailed { program, failure } => {
+                write!(f, "init of program {program:?} failed: {failure}")
+            }
+            Self::Validation(err) => write!(f, "{err}"),
+            Self::Guard(err) => write!(f, "{err}"),
+        }
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ValueBelowExistentialDeposit {
+                value,
+                existential_deposit,
+            } => write!(
+                f,
+                "value {value} is below the existential deposit {existential_deposit}"
+            ),
+            Self::GasBelowMailboxThreshold {
+                gas_limit,
+                mailbox_threshold,
+            } => write!(
+                f,
+                "gas limit {gas_limit} is below the mailbox threshold {mailbox_threshold}"
+            ),
+        }
+    }
+}
+
+impl fmt::Display for GuardError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnexpectedSource { expected, actual } => {
+                write!(f, "message was sent by {actual:?}, expected {expected:?}")
+            }
+            Self::ValueBelowMinimum { value, min } => {
+                write!(f, "value {value} is below the minimum {min}")
+            }
+            Self::PayloadTooLong { len, max } => {
+                write!(
+                    f,
+                    "payload of {len} bytes exceeds the maximum of {max} bytes"
+                )
+            }
+            Self::Unauthorized { actor } => write!(f, "{actor:?} is not authorized"),
+        }
+    }
+}
+
+impl fmt::Display for InitFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfGas => write!(f, "ran out of gas"),
+            Self::Trap(err) => write!(f, "trapped with {err:?}"),
+            Self::ErrorReply(reason) => write!(f, "replied with {reason:?}"),
+        }
+    }
+}
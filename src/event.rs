@@ -0,0 +1,173 @@
+//! Events emitted by a program.
+//!
+//! An event is a message sent to `ActorId::zero()` with zero value. `builder::emit(...)` encodes
+//! the event with SCALE, and `.with_topic(...)` and `.with_version(...)` prepend the SCALE-encoded
+//! topic and the version byte to it, in this order:
+//!
+//! ```ignore
+//! builder::emit(Event::Transferred { from, to, amount })
+//!     .with_topic(b"token")
+//!     .with_version(1)
+//!     .execute()?;
+//! ```
+//!
+//! An [`EventDecoder`] with the same topic and version checks and strips the prefix, e.g. when
+//! asserting on the log of gtest:
+//!
+//! ```ignore
+//! let decoder = EventDecoder::new().with_topic(b"token").with_version(1);
+//! let event: Event = decoder.decode(log.payload()).expect("Not a token event");
+//! ```
+
+use crate::{
+    common::{PayloadBytesW, PayloadEncodableW},
+    errors::Result,
+    generated::SendBuilder,
+};
+use gstd::{errors::ContractError, prelude::*, ActorId, Decode, Encode, MessageId};
+
+pub struct TopicW(pub(crate) Vec<u8>);
+pub struct VersionW(pub(crate) u8);
+
+pub trait IntoEventPayload {
+    fn write_payload(self, bytes: &mut Vec<u8>);
+}
+
+impl<Buffer: AsRef<[u8]>> IntoEventPayload for PayloadBytesW<Buffer> {
+    fn write_payload(self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(self.0.as_ref());
+    }
+}
+
+impl<Encodable: Encode> IntoEventPayload for PayloadEncodableW<Encodable> {
+    fn write_payload(self, bytes: &mut Vec<u8>) {
+        self.0.encode_to(bytes);
+    }
+}
+
+pub trait IntoEventPrefix {
+    fn write_prefix(self, bytes: &mut Vec<u8>);
+}
+
+impl IntoEventPrefix for () {
+    fn write_prefix(self, _: &mut Vec<u8>) {}
+}
+
+impl IntoEventPrefix for TopicW {
+    /// The topic is length-prefixed, so a topic is never mistaken for the start of a longer one.
+    fn write_prefix(self, bytes: &mut Vec<u8>) {
+        self.0.encode_to(bytes);
+    }
+}
+
+impl IntoEventPrefix for VersionW {
+    fn write_prefix(self, bytes: &mut Vec<u8>) {
+        bytes.push(self.0);
+    }
+}
+
+/// A builder that emits an event.
+pub struct EmitBuilder<Fields = ((), (), ())> {
+    fields: Fields,
+}
+
+impl<Buffer: AsRef<[u8]>> EmitBuilder<(PayloadBytesW<Buffer>, (), ())> {
+    pub fn bytes(payload: Buffer) -> Self {
+        Self {
+            fields: (PayloadBytesW(payload), (), ()),
+        }
+    }
+}
+
+impl<Encodable: Encode> EmitBuilder<(PayloadEncodableW<Encodable>, (), ())> {
+    pub fn encode(payload: Encodable) -> Self {
+        Self {
+            fields: (PayloadEncodableW(payload), (), ()),
+        }
+    }
+}
+
+impl<Payload, Version> EmitBuilder<(Payload, (), Version)> {
+    /// Prepends the SCALE-encoded topic to the payload.
+    pub fn with_topic(self, topic: impl AsRef<[u8]>) -> EmitBuilder<(Payload, TopicW, Version)> {
+        let (payload, _, version) = self.fields;
+        EmitBuilder {
+            fields: (payload, TopicW(topic.as_ref().to_vec()), version),
+        }
+    }
+}
+
+impl<Payload, Topic> EmitBuilder<(Payload, Topic, ())> {
+    /// Prepends the version byte to the payload, after the topic.
+    pub fn with_version(self, version: u8) -> EmitBuilder<(Payload, Topic, VersionW)> {
+        let (payload, topic, _) = self.fields;
+        EmitBuilder {
+            fields: (payload, topic, VersionW(version)),
+        }
+    }
+}
+
+impl<Payload: IntoEventPayload, Topic: IntoEventPrefix, Version: IntoEventPrefix>
+    EmitBuilder<(Payload, Topic, Version)>
+{
+    /// Sends the event to `ActorId::zero()` with zero value.
+    pub fn execute(self) -> Result<MessageId> {
+        let (payload, topic, version) = self.fields;
+        let mut bytes = Vec::new();
+        topic.write_prefix(&mut bytes);
+        version.write_prefix(&mut bytes);
+        payload.write_payload(&mut bytes);
+
+        SendBuilder::bytes(ActorId::zero(), bytes).execute()
+    }
+}
+
+/// Decodes the events emitted with the given topic and version.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventDecoder {
+    topic: Option<Vec<u8>>,
+    version: Option<u8>,
+}
+
+impl EventDecoder {
+    /// Creates a decoder of the events emitted without a topic and a version.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Expects the payload to start with the topic.
+    pub fn with_topic(mut self, topic: impl AsRef<[u8]>) -> Self {
+        self.topic = Some(topic.as_ref().to_vec());
+        self
+    }
+
+    /// Expects the payload to start with the version byte, after the topic.
+    pub fn with_version(mut self, version: u8) -> Self {
+        self.version = Some(version);
+        self
+    }
+
+    /// Returns `true` if the payload starts with the topic and the version.
+    pub fn matches(&self, payload: &[u8]) -> bool {
+        payload.starts_with(&self.prefix())
+    }
+
+    /// Checks and strips the topic and the version, and decodes the event with SCALE.
+    pub fn decode<Decodable: Decode>(&self, payload: &[u8]) -> Result<Decodable, ContractError> {
+        let Some(mut event) = payload.strip_prefix(self.prefix().as_slice()) else {
+            return Err(ContractError::Convert(
+                "Event topic or version does not match",
+            ));
+        };
+        Decodable::decode(&mut event).map_err(ContractError::Decode)
+    }
+    /// The prefix written by `builder::emit(...)`: the topic, then the version.
+    fn prefix(&self) -> Vec<u8> {
+        let mut prefix = Vec::new();
+        if let Some(topic) = &self.topic {
+            topic.encode_to(&mut prefix);
+        }
+        prefix.extend(self.version);
+        prefix
+    }
+}
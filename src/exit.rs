@@ -0,0 +1,100 @@
+//! Termination of the program.
+//!
+//! `builder::exit()` notifies the given actors and calls `exec::exit(inheritor)`, which transfers
+//! the value left on the program to the inheritor:
+//!
+//! ```ignore
+//! builder::exit()
+//!     .with_inheritor(factory)
+//!     .after_notifying(subscribers, Event::Decommissioned)
+//!     .execute()
+//!     .expect("Unable to notify");
+//! ```
+//!
+//! If a notification can't be sent, `.execute()` returns the error and the program keeps running.
+//! The notifications sent before it are not revoked.
+
+use crate::{
+    backend::{ActiveBackend, Backend},
+    errors::Result,
+    generated::SendBuilder,
+};
+use core::convert::Infallible;
+use gstd::{prelude::*, ActorId, Encode};
+
+pub struct InheritorW(pub(crate) ActorId);
+pub struct NotificationsW(pub(crate) Vec<ActorId>, pub(crate) Vec<u8>);
+
+pub trait IntoNotifications {
+    fn notify(self) -> Result<()>;
+}
+
+impl IntoNotifications for () {
+    fn notify(self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl IntoNotifications for NotificationsW {
+    fn notify(self) -> Result<()> {
+        let NotificationsW(recipients, payload) = self;
+        for recipient in recipients {
+            SendBuilder::bytes(recipient, payload.as_slice()).execute()?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A builder that terminates the program.
+pub struct ExitBuilder<Fields = ((), ())> {
+    fields: Fields,
+}
+
+impl ExitBuilder {
+    pub fn new() -> Self {
+        Self { fields: ((), ()) }
+    }
+}
+
+impl Default for ExitBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Notifications> ExitBuilder<((), Notifications)> {
+    /// Sets the actor that receives the value left on the program.
+    pub fn with_inheritor(self, inheritor: ActorId) -> ExitBuilder<(InheritorW, Notifications)> {
+        let (_, notifications) = self.fields;
+        ExitBuilder {
+            fields: (InheritorW(inheritor), notifications),
+        }
+    }
+}
+
+impl<Inheritor> ExitBuilder<(Inheritor, ())> {
+    /// Sends the encoded payload to every recipient before exiting.
+    pub fn after_notifying<Encodable: Encode>(
+        self,
+        recipients: impl IntoIterator<Item = ActorId>,
+        payload: Encodable,
+    ) -> ExitBuilder<(Inheritor, NotificationsW)> {
+        let (inheritor, _) = self.fields;
+        ExitBuilder {
+            fields: (
+                inheritor,
+                NotificationsW(recipients.into_iter().collect(), payload.encode()),
+            ),
+        }
+    }
+}
+
+impl<Notifications: IntoNotifications> ExitBuilder<(InheritorW, Notifications)> {
+    /// Sends the notifications and exits. Only returns if a message can't be sent.
+    pub fn execute(self) -> Result<Infallible> {
+        let (InheritorW(inheritor), notifications) = self.fields;
+        notifications.notify()?;
+        ActiveBackend::exit(inheritor)
+    }
+}
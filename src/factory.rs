@@ -0,0 +1,155 @@
+//! Spawning of child programs from one code.
+//!
+//! A [`Factory`] in the program state creates programs from its code with the same init options,
+//! waits for the reply to their init messages, and remembers the programs that were initialized:
+//!
+//! ```ignore
+//! static mut FACTORY: Option<Factory> = None;
+//!
+//! #[gstd::async_main]
+//! async fn main() {
+//!     let factory = unsafe { FACTORY.get_or_insert_with(|| Factory::new(CODE_ID)) };
+//!     match msg::load().expect("Invalid action") {
+//!         Action::Open(init) => {
+//!             let pool = factory.spawn(init).await.expect("Unable to create pool");
+//!             builder::reply(pool).execute().expect("Unable to reply");
+//!         }
+//!         Action::Upgrade(code_id) => {
+//!             factory.upgrade(code_id);
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::{
+    backend::CreateProgramFuture,
+    created::{Created, CreatedFuture},
+    errors::Result,
+    generated::CreateProgramBuilder,
+};
+use alloc::collections::BTreeMap;
+use core::mem;
+use gstd::{prelude::*, ActorId, CodeId, Decode, Encode};
+
+/// The code, the init options and the spawned programs of a program factory.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct Factory {
+    code_id: CodeId,
+    value: u128,
+    gas_limit: Option<u64>,
+    children: BTreeMap<ActorId, CodeId>,
+}
+
+impl Factory {
+    pub const fn new(code_id: CodeId) -> Self {
+        Self {
+            code_id,
+            value: 0,
+            gas_limit: None,
+            children: BTreeMap::new(),
+        }
+    }
+
+    /// Sends the value with every init message. Value is 0 by default.
+    pub fn with_value(mut self, value: u128) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Sends every init message with the gas limit. The gas limit is not set by default.
+    pub fn with_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+
+    /// Returns the code the programs are created from.
+    pub fn code_id(&self) -> CodeId {
+        self.code_id
+    }
+
+    /// Creates the next programs from another code and returns the previous one.
+    ///
+    /// The programs already spawned keep running the code they were created from.
+    pub fn upgrade(&mut self, code_id: CodeId) -> CodeId {
+        mem::replace(&mut self.code_id, code_id)
+    }
+
+    /// Returns the spawned programs.
+    pub fn children(&self) -> impl Iterator<Item = ActorId> + '_ {
+        self.children.keys().copied()
+    }
+
+    /// Returns the code the program was created from, or `None` if it was not spawned.
+    pub fn code_of(&self, program: ActorId) -> Option<CodeId> {
+        self.children.get(&program).copied()
+    }
+
+    /// Returns the spawned programs created from another code than the current one.
+    pub fn outdated(&self) -> impl Iterator<Item = ActorId> + '_ {
+        self.children
+            .iter()
+            .filter(|(_, &code_id)| code_id != self.code_id)
+            .map(|(&program, _)| program)
+    }
+
+    /// Returns `true` if no program was spawned.
+    pub fn is_empty(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// Forgets the program, e.g. after it exited. Returns `false` if it was not spawned.
+    pub fn remove(&mut self, program: ActorId) -> bool {
+        self.children.remove(&program).is_some()
+    }
+
+    /// Creates a program with the encoded init payload and waits for the reply to the init message.
+    ///
+    /// The program is remembered only if it replied successfully. If its init message failed, the
+    /// program still exists and the error is [`Error::InitFailed`](crate::errors::Error::InitFailed).
+    pub async fn spawn<Encodable: Encode>(&mut self, init: Encodable) -> Result<ActorId> {
+        let code_id = self.code_id;
+        let future = self.create(init.encode())?;
+        self.register(code_id, future.await)
+    }
+
+    /// Creates a program for every init payload, then waits for the replies in the same order.
+    ///
+    /// All init messages are sent before the first reply is awaited. Returns the result of every
+    /// init payload in the same order, an init message that can't be sent doesn't stop the others.
+    pub async fn spawn_batch<Encodable: Encode>(
+        &mut self,
+        inits: impl IntoIterator<Item = Encodable>,
+    ) -> Vec<Result<ActorId>> {
+        let code_id = self.code_id;
+        let futures = inits
+            .into_iter()
+            .map(|init| self.create(init.encode()))
+            .collect::<Vec<_>>();
+
+        let mut programs = Vec::with_capacity(futures.len());
+        for future in futures {
+            let created = match future {
+                Ok(future) => future.await,
+                Err(err) => Err(err),
+            };
+            programs.push(self.register(code_id, created));
+        }
+
+        programs
+    }
+
+    fn create(&self, init: Vec<u8>) -> Result<CreatedFuture<CreateProgramFuture>> {
+        let builder = CreateProgramBuilder::bytes(self.code_id, init).with_value(self.value);
+        match self.gas_limit {
+            Some(gas_limit) => builder.with_gas_limit(gas_limit).for_reply().execute(),
+            None => builder.for_reply().execute(),
+        }
+    }
+
+    fn register(&mut self, code_id: CodeId, created: Result<Created<Vec<u8>>>) -> Result<ActorId> {
+        let Created { program, .. } = created?;
+        self.children.insert(program, code_id);
+        Ok(program)
+    }
+}
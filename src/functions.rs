@@ -1,4 +1,15 @@
-use crate::{common::*, generated::*};
+use crate::{
+    chunked::{ChunkSender, ChunkedSendBuilder},
+    common::*,
+    error_reply::ErrorReply,
+    event::EmitBuilder,
+    exit::ExitBuilder,
+    generated::*,
+    incoming::Incoming,
+    router::Router,
+    transfer::TransferBuilder,
+    wait::{MessageIdW, WaitBuilder, WakeBuilder},
+};
 
 /// Creates a builder that allows to send a new message as a reply to the message being processed.
 pub fn reply_bytes<Buffer: AsRef<[u8]>>(
@@ -8,12 +19,35 @@ pub fn reply_bytes<Buffer: AsRef<[u8]>>(
 }
 
 /// Creates a builder that allows to send a new message as a reply to the message being processed.
-pub fn reply<Encodable: Encode>(
+///
+/// The payload is encoded with SCALE, or with the codec set by `.with_codec::<Codec>()`, so
+/// `Encodable` is only required to implement `Encode` by `execute()` without a codec.
+pub fn reply<Encodable>(
     payload: Encodable,
 ) -> ReplyBuilder<(PayloadEncodableW<Encodable>, (), (), ())> {
     ReplyBuilder::encode(payload)
 }
 
+/// Creates a builder that allows to send a new message as a reply to the message being processed.
+///
+/// The payload is encoded with `PayloadCodec` instead of SCALE.
+pub fn reply_with<PayloadCodec: Encoder<Encodable>, Encodable>(
+    payload: Encodable,
+) -> ReplyBuilder<(PayloadEncodableW<Encodable>, (), (), ()), PayloadCodec> {
+    ReplyBuilder::encode_with(payload)
+}
+
+/// Creates a builder that allows to send a new message as a reply to the message being processed.
+///
+/// The payload is the result with the error converted to [`ErrorReply`], it is decoded by
+/// `.for_reply_result::<Reply, Failure>()`.
+#[allow(clippy::type_complexity)]
+pub fn reply_result<Reply: Encode, Failure: Into<ErrorReply>>(
+    result: Result<Reply, Failure>,
+) -> ReplyBuilder<(PayloadEncodableW<Result<Reply, ErrorReply>>, (), (), ())> {
+    ReplyBuilder::encode(result.map_err(Into::into))
+}
+
 /// Creates a builder that allows to send a new message as a reply to the message being processed.
 pub fn reply_input<Range: RangeBounds<usize>>(
     payload: Range,
@@ -30,13 +64,40 @@ pub fn send_bytes<Buffer: AsRef<[u8]>>(
 }
 
 /// Creates a builder that allows to send a new message to a program or user.
-pub fn send<Encodable: Encode>(
+///
+/// The payload is encoded with SCALE, or with the codec set by `.with_codec::<Codec>()`, so
+/// `Encodable` is only required to implement `Encode` by `execute()` without a codec.
+pub fn send<Encodable>(
     program: ActorId,
     payload: Encodable,
 ) -> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, (), (), (), ())> {
     SendBuilder::encode(program, payload)
 }
 
+/// Creates a builder that allows to send a new message to a program or user.
+///
+/// The payload is encoded with `PayloadCodec` instead of SCALE, and so is the reply decoded by
+/// `.for_reply_as::<Decodable>()`.
+#[allow(clippy::type_complexity)]
+pub fn send_with<PayloadCodec: Encoder<Encodable>, Encodable>(
+    program: ActorId,
+    payload: Encodable,
+) -> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, (), (), (), ()), PayloadCodec> {
+    SendBuilder::encode_with(program, payload)
+}
+
+/// Creates a builder that allows to send a payload larger than one message as several chunks.
+///
+/// The transfer is numbered by `sender`, the chunks are reassembled by
+/// [`ChunkAssembler`](crate::chunked::ChunkAssembler).
+pub fn send_chunked<Encodable: Encode>(
+    sender: &mut ChunkSender,
+    program: ActorId,
+    payload: Encodable,
+) -> ChunkedSendBuilder<'_, (ProgramW, PayloadEncodableW<Encodable>, ())> {
+    ChunkedSendBuilder::encode(sender, program, payload)
+}
+
 /// Creates a builder that allows to send a new message to a program or user.
 pub fn send_input<Range: RangeBounds<usize>>(
     program: ActorId,
@@ -45,6 +106,11 @@ pub fn send_input<Range: RangeBounds<usize>>(
     SendBuilder::input(program, payload)
 }
 
+/// Creates a builder that allows to transfer value to a program or user with an empty message.
+pub fn transfer(to: ActorId, amount: u128) -> TransferBuilder<(ProgramW, ValueW, (), (), ())> {
+    TransferBuilder::new(to, amount)
+}
+
 /// Creates a builder that allows to create a new program from the already existing on-chain code.
 pub fn create_program<Buffer: AsRef<[u8]>>(
     code_id: CodeId,
@@ -52,3 +118,42 @@ pub fn create_program<Buffer: AsRef<[u8]>>(
 ) -> CreateProgramBuilder<(CodeIdW, PayloadBytesW<Buffer>, (), (), ())> {
     CreateProgramBuilder::bytes(code_id, payload)
 }
+
+/// Creates a typed view of the message being processed.
+pub fn incoming() -> Incoming {
+    Incoming::new()
+}
+
+/// Creates a router that dispatches the message being processed to the handler of its action.
+pub fn router<'a, Action, Reply, Failure>() -> Router<'a, Action, Reply, Failure> {
+    Router::new()
+}
+
+/// Creates a builder that allows to emit an event.
+pub fn emit<Encodable: Encode>(
+    event: Encodable,
+) -> EmitBuilder<(PayloadEncodableW<Encodable>, (), ())> {
+    EmitBuilder::encode(event)
+}
+
+/// Creates a builder that allows to emit an event.
+pub fn emit_bytes<Buffer: AsRef<[u8]>>(
+    event: Buffer,
+) -> EmitBuilder<(PayloadBytesW<Buffer>, (), ())> {
+    EmitBuilder::bytes(event)
+}
+
+/// Creates a builder that allows to terminate the program.
+pub fn exit() -> ExitBuilder {
+    ExitBuilder::new()
+}
+
+/// Creates a builder that allows to suspend the message being processed.
+pub fn wait() -> WaitBuilder {
+    WaitBuilder::new()
+}
+
+/// Creates a builder that allows to resume a suspended message.
+pub fn wake(message_id: MessageId) -> WakeBuilder<(MessageIdW, ())> {
+    WakeBuilder::new(message_id)
+}
@@ -0,0 +1,95 @@
+//! Helpers to distribute the available gas between several outgoing messages.
+
+use crate::{
+    backend::{ActiveBackend, Backend},
+    errors::{Error, Result},
+};
+
+/// Splits the gas available to the current message between several builders.
+///
+/// The budget is created from `exec::gas_available()` minus a safety margin that is left for the
+/// rest of the handler. Each builder receives `weight / total_weight` of the budget, and the last
+/// allotment also receives what is left after rounding. The gas is allotted by `execute()`, once
+/// the other arguments of the builder are validated.
+///
+/// ```ignore
+/// let mut budget = GasBudget::new(1_000_000_000, 3);
+///
+/// builder::send(first, payload)
+///     .with_gas_from_budget(&mut budget, 2)
+///     .execute()?;
+///
+/// builder::send(second, payload)
+///     .with_gas_from_budget(&mut budget, 1)
+///     .execute()?;
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct GasBudget {
+    total: u64,
+    remaining: u64,
+    total_weight: u64,
+    remaining_weight: u64,
+}
+
+impl GasBudget {
+    /// Creates a budget from `exec::gas_available()` minus `safety_margin`.
+    pub fn new(safety_margin: u64, total_weight: u64) -> Self {
+        Self::from_gas(
+            ActiveBackend::gas_available().saturating_sub(safety_margin),
+            total_weight,
+        )
+    }
+
+    /// Creates a budget from an explicit amount of gas.
+    pub fn from_gas(gas: u64, total_weight: u64) -> Self {
+        Self {
+            total: gas,
+            remaining: gas,
+            total_weight,
+            remaining_weight: total_weight,
+        }
+    }
+
+    /// Returns the gas that has not been allotted yet.
+    pub fn remaining(&self) -> u64 {
+        self.remaining
+    }
+
+    /// Returns the weight that has not been allotted yet.
+    pub fn remaining_weight(&self) -> u64 {
+        self.remaining_weight
+    }
+
+    /// Takes `weight / total_weight` of the budget. A weight of 0 gets no gas.
+    ///
+    /// Fails if `weight` exceeds the weight that is left, in which case the budget is not changed.
+    pub fn allot(&mut self, weight: u64) -> Result<u64> {
+        let requested = self.share(weight)?;
+        self.remaining -= requested;
+        self.remaining_weight -= weight;
+
+        Ok(requested)
+    }
+
+    /// Returns what `allot(weight)` would take without changing the budget.
+    pub(crate) fn share(&self, weight: u64) -> Result<u64> {
+        let requested = if weight == 0 {
+            0
+        } else if weight == self.remaining_weight {
+            self.remaining
+        } else {
+            (self.total as u128 * weight as u128)
+                .checked_div(self.total_weight as u128)
+                .map_or(u64::MAX, |gas| gas as u64)
+        };
+
+        if weight > self.remaining_weight || requested > self.remaining {
+            return Err(Error::GasBudgetExceeded {
+                requested,
+                remaining: self.remaining,
+            });
+        }
+
+        Ok(requested)
+    }
+}
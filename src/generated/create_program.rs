@@ -7,57 +7,141 @@ use crate::common::*;
 // CreateProgramBuilder<(CodeId, Payload, Value, Delay, GasLimit)>
 // ---------------------------------------------------------------------------------------------- //
 
-pub struct CreateProgramBuilder<Fields = ((), (), (), (), ())> {
+pub struct CreateProgramBuilder<Fields = ((), (), (), (), ()), PayloadCodec = ()> {
     fields: Fields,
+    quiet: bool,
+    codec: PhantomData<PayloadCodec>,
+}
+
+impl<Fields, PayloadCodec> CreateProgramBuilder<Fields, PayloadCodec> {
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+}
+
+impl<Fields> CreateProgramBuilder<Fields> {
+    pub fn with_codec<PayloadCodec: Codec>(self) -> CreateProgramBuilder<Fields, PayloadCodec> {
+        CreateProgramBuilder {
+            fields: self.fields,
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
 }
 
 impl<Buffer: AsRef<[u8]>> CreateProgramBuilder<(CodeIdW, PayloadBytesW<Buffer>, (), (), ())> {
     pub fn bytes(code_id: CodeId, payload: Buffer) -> Self {
         Self {
             fields: (CodeIdW(code_id), PayloadBytesW(payload), (), (), ()),
+            quiet: false,
+            codec: PhantomData,
         }
     }
 }
 
-impl<CodeId, Payload, Delay, GasLimit> CreateProgramBuilder<(CodeId, Payload, (), Delay, GasLimit)> {
-    pub fn with_value(self, value: u128) -> CreateProgramBuilder<(CodeId, Payload, ValueW, Delay, GasLimit)> {
+impl<CodeId, Payload, Delay, GasLimit, PayloadCodec> CreateProgramBuilder<(CodeId, Payload, (), Delay, GasLimit), PayloadCodec> {
+    pub fn with_value(self, value: u128) -> CreateProgramBuilder<(CodeId, Payload, ValueW, Delay, GasLimit), PayloadCodec> {
         let (code_id, payload, _, delay, gas_limit) = self.fields;
         CreateProgramBuilder {
             fields: (code_id, payload, ValueW(value), delay, gas_limit),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
 
-impl<CodeId, Payload, Value, GasLimit> CreateProgramBuilder<(CodeId, Payload, Value, (), GasLimit)> {
-    pub fn with_delay(self, delay: u32) -> CreateProgramBuilder<(CodeId, Payload, Value, DelayW, GasLimit)> {
+impl<CodeId, Payload, Value, GasLimit, PayloadCodec> CreateProgramBuilder<(CodeId, Payload, Value, (), GasLimit), PayloadCodec> {
+    pub fn with_delay(self, delay: u32) -> CreateProgramBuilder<(CodeId, Payload, Value, DelayW, GasLimit), PayloadCodec> {
         let (code_id, payload, value, _, gas_limit) = self.fields;
         CreateProgramBuilder {
             fields: (code_id, payload, value, DelayW(delay), gas_limit),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
 
-impl<CodeId, Payload, Value, Delay> CreateProgramBuilder<(CodeId, Payload, Value, Delay, ())> {
-    pub fn with_gas_limit(self, gas_limit: u64) -> CreateProgramBuilder<(CodeId, Payload, Value, Delay, GasLimitW)> {
+impl<CodeId, Payload, Value, Delay, PayloadCodec> CreateProgramBuilder<(CodeId, Payload, Value, Delay, ()), PayloadCodec> {
+    pub fn with_gas_limit(self, gas_limit: u64) -> CreateProgramBuilder<(CodeId, Payload, Value, Delay, GasLimitW), PayloadCodec> {
         let (code_id, payload, value, delay, _) = self.fields;
         CreateProgramBuilder {
             fields: (code_id, payload, value, delay, GasLimitW(gas_limit)),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+
+    pub fn with_gas_fraction(self, numerator: u64, denominator: u64) -> CreateProgramBuilder<(CodeId, Payload, Value, Delay, GasFractionW), PayloadCodec> {
+        let (code_id, payload, value, delay, _) = self.fields;
+        CreateProgramBuilder {
+            fields: (code_id, payload, value, delay, GasFractionW(numerator, denominator)),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+
+    pub fn with_gas_remaining_minus(self, reserve: u64) -> CreateProgramBuilder<(CodeId, Payload, Value, Delay, GasRemainingMinusW), PayloadCodec> {
+        let (code_id, payload, value, delay, _) = self.fields;
+        CreateProgramBuilder {
+            fields: (code_id, payload, value, delay, GasRemainingMinusW(reserve)),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+
+    pub fn with_all_remaining_gas(self) -> CreateProgramBuilder<(CodeId, Payload, Value, Delay, AllRemainingGasW), PayloadCodec> {
+        let (code_id, payload, value, delay, _) = self.fields;
+        CreateProgramBuilder {
+            fields: (code_id, payload, value, delay, AllRemainingGasW),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+
+    pub fn with_gas_from_budget(self, budget: &mut GasBudget, weight: u64) -> CreateProgramBuilder<(CodeId, Payload, Value, Delay, GasBudgetW<'_>), PayloadCodec> {
+        let (code_id, payload, value, delay, _) = self.fields;
+        CreateProgramBuilder {
+            fields: (code_id, payload, value, delay, GasBudgetW(budget, weight)),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
 
-impl<CodeId, Payload, Value, GasLimit> CreateProgramBuilder<(CodeId, Payload, Value, (), GasLimit)> {
-    pub fn for_reply(self) -> CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, (), ())> {
+impl<CodeId, Payload, Value, GasLimit, PayloadCodec> CreateProgramBuilder<(CodeId, Payload, Value, (), GasLimit), PayloadCodec> {
+    pub fn for_reply(self) -> CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, (), ()), PayloadCodec> {
         let (code_id, payload, value, _, gas_limit) = self.fields;
         CreateProgramBuilderForReply {
             fields: (code_id, payload, value, gas_limit, (), ()),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
+}
 
+impl<CodeId, Payload, Value, GasLimit> CreateProgramBuilder<(CodeId, Payload, Value, (), GasLimit)> {
     pub fn for_reply_as<Decodable: Decode>(self) -> CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, (), DecodableW<Decodable>)> {
         let (code_id, payload, value, _, gas_limit) = self.fields;
         CreateProgramBuilderForReply {
             fields: (code_id, payload, value, gas_limit, (), DecodableW(PhantomData)),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+}
+
+impl<CodeId, Payload, Value, GasLimit, PayloadCodec: Codec> CreateProgramBuilder<(CodeId, Payload, Value, (), GasLimit), PayloadCodec> {
+    #[allow(clippy::type_complexity)]
+    pub fn for_reply_as<Decodable>(self) -> CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, (), DecodableW<Decodable>), PayloadCodec>
+    where
+        PayloadCodec: Decoder<Decodable>,
+    {
+        let (code_id, payload, value, _, gas_limit) = self.fields;
+        CreateProgramBuilderForReply {
+            fields: (code_id, payload, value, gas_limit, (), DecodableW(PhantomData)),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
@@ -65,28 +149,61 @@ impl<CodeId, Payload, Value, GasLimit> CreateProgramBuilder<(CodeId, Payload, Va
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> CreateProgramBuilder<(CodeIdW, PayloadBytesW<Buffer>, Value, (), ())> {
     pub fn execute(self) -> Result<(MessageId, ActorId)> {
         let (CodeIdW(code_id), PayloadBytesW(payload), value, _, _) = self.fields;
-        ProgramGenerator::create_program(code_id, payload, value.into().0)
+        ExecuteLog::new("create_program", self.quiet).run(|log| Ok(ActiveBackend::create_program(log.code_id(code_id), log.payload_bytes(validate_payload_bytes(payload)?), log.value(value.into().0))?))
     }
 }
 
-impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> CreateProgramBuilder<(CodeIdW, PayloadBytesW<Buffer>, Value, (), GasLimitW)> {
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit> CreateProgramBuilder<(CodeIdW, PayloadBytesW<Buffer>, Value, (), GasLimit)> {
     pub fn execute(self) -> Result<(MessageId, ActorId)> {
-        let (CodeIdW(code_id), PayloadBytesW(payload), value, _, GasLimitW(gas_limit)) = self.fields;
-        ProgramGenerator::create_program_with_gas(code_id, payload, gas_limit, value.into().0)
+        let (CodeIdW(code_id), PayloadBytesW(payload), value, _, gas_limit) = self.fields;
+        ExecuteLog::new("create_program_with_gas", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let gas_limit = log.gas_limit(gas_limit.into_gas_limit()?); Ok(ActiveBackend::create_program_with_gas(log.code_id(code_id), payload, gas_limit, log.value(value.into().0))?) })
     }
 }
 
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> CreateProgramBuilder<(CodeIdW, PayloadBytesW<Buffer>, Value, DelayW, ())> {
     pub fn execute(self) -> Result<(MessageId, ActorId)> {
         let (CodeIdW(code_id), PayloadBytesW(payload), value, DelayW(delay), _) = self.fields;
-        ProgramGenerator::create_program_delayed(code_id, payload, value.into().0, delay)
+        ExecuteLog::new("create_program_delayed", self.quiet).run(|log| Ok(ActiveBackend::create_program_delayed(log.code_id(code_id), log.payload_bytes(validate_payload_bytes(payload)?), log.value(value.into().0), log.delay(delay))?))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit> CreateProgramBuilder<(CodeIdW, PayloadBytesW<Buffer>, Value, DelayW, GasLimit)> {
+    pub fn execute(self) -> Result<(MessageId, ActorId)> {
+        let (CodeIdW(code_id), PayloadBytesW(payload), value, DelayW(delay), gas_limit) = self.fields;
+        ExecuteLog::new("create_program_with_gas_delayed", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let gas_limit = log.gas_limit(gas_limit.into_gas_limit()?); Ok(ActiveBackend::create_program_with_gas_delayed(log.code_id(code_id), payload, gas_limit, log.value(value.into().0), log.delay(delay))?) })
+    }
+}
+
+// ---------------------------------------------------------------------------------------------- //
+// bindings for `create_program*` with `.with_codec::<PayloadCodec>()`
+// CreateProgramBuilder<(CodeId, Payload, Value, Delay, GasLimit), PayloadCodec>
+// ---------------------------------------------------------------------------------------------- //
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, PayloadCodec: Codec> CreateProgramBuilder<(CodeIdW, PayloadBytesW<Buffer>, Value, (), ()), PayloadCodec> {
+    pub fn execute(self) -> Result<(MessageId, ActorId)> {
+        let (CodeIdW(code_id), PayloadBytesW(payload), value, _, _) = self.fields;
+        ExecuteLog::new("create_program", self.quiet).run(|log| Ok(ActiveBackend::create_program(log.code_id(code_id), log.payload_bytes(validate_payload_bytes(payload)?), log.value(value.into().0))?))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, PayloadCodec: Codec> CreateProgramBuilder<(CodeIdW, PayloadBytesW<Buffer>, Value, (), GasLimit), PayloadCodec> {
+    pub fn execute(self) -> Result<(MessageId, ActorId)> {
+        let (CodeIdW(code_id), PayloadBytesW(payload), value, _, gas_limit) = self.fields;
+        ExecuteLog::new("create_program_with_gas", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let gas_limit = log.gas_limit(gas_limit.into_gas_limit()?); Ok(ActiveBackend::create_program_with_gas(log.code_id(code_id), payload, gas_limit, log.value(value.into().0))?) })
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, PayloadCodec: Codec> CreateProgramBuilder<(CodeIdW, PayloadBytesW<Buffer>, Value, DelayW, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<(MessageId, ActorId)> {
+        let (CodeIdW(code_id), PayloadBytesW(payload), value, DelayW(delay), _) = self.fields;
+        ExecuteLog::new("create_program_delayed", self.quiet).run(|log| Ok(ActiveBackend::create_program_delayed(log.code_id(code_id), log.payload_bytes(validate_payload_bytes(payload)?), log.value(value.into().0), log.delay(delay))?))
     }
 }
 
-impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> CreateProgramBuilder<(CodeIdW, PayloadBytesW<Buffer>, Value, DelayW, GasLimitW)> {
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, PayloadCodec: Codec> CreateProgramBuilder<(CodeIdW, PayloadBytesW<Buffer>, Value, DelayW, GasLimit), PayloadCodec> {
     pub fn execute(self) -> Result<(MessageId, ActorId)> {
-        let (CodeIdW(code_id), PayloadBytesW(payload), value, DelayW(delay), GasLimitW(gas_limit)) = self.fields;
-        ProgramGenerator::create_program_with_gas_delayed(code_id, payload, gas_limit, value.into().0, delay)
+        let (CodeIdW(code_id), PayloadBytesW(payload), value, DelayW(delay), gas_limit) = self.fields;
+        ExecuteLog::new("create_program_with_gas_delayed", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let gas_limit = log.gas_limit(gas_limit.into_gas_limit()?); Ok(ActiveBackend::create_program_with_gas_delayed(log.code_id(code_id), payload, gas_limit, log.value(value.into().0), log.delay(delay))?) })
     }
 }
 
@@ -95,43 +212,124 @@ impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> CreateProgramBuilder<(CodeIdW, Pa
 // CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, ReplyDeposit, Decodable)>
 // ---------------------------------------------------------------------------------------------- //
 
-pub struct CreateProgramBuilderForReply<Fields = ((), (), (), (), (), ())> {
+pub struct CreateProgramBuilderForReply<Fields = ((), (), (), (), (), ()), PayloadCodec = ()> {
     fields: Fields,
+    quiet: bool,
+    codec: PhantomData<PayloadCodec>,
 }
 
-impl<CodeId, Payload, Value, GasLimit, Decodable> CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, (), Decodable)> {
-    pub fn with_reply_deposit(self, reply_deposit: u64) -> CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, ReplyDepositW, Decodable)> {
+impl<Fields, PayloadCodec> CreateProgramBuilderForReply<Fields, PayloadCodec> {
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+}
+
+impl<CodeId, Payload, Value, GasLimit, Decodable, PayloadCodec> CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, (), Decodable), PayloadCodec> {
+    pub fn with_reply_deposit(self, reply_deposit: u64) -> CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, ReplyDepositW, Decodable), PayloadCodec> {
         let (code_id, payload, value, gas_limit, _, decodable) = self.fields;
         CreateProgramBuilderForReply {
             fields: (code_id, payload, value, gas_limit, ReplyDepositW(reply_deposit), decodable),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+}
+
+impl<CodeId, Payload, Value, GasLimit, PayloadCodec> CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, (), ()), PayloadCodec> {
+    pub fn with_reply_deposit_auto(self) -> CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, ReplyDepositW, ()), PayloadCodec> {
+        let (code_id, payload, value, gas_limit, _, decodable) = self.fields;
+        CreateProgramBuilderForReply {
+            fields: (code_id, payload, value, gas_limit, ReplyDepositW::auto(0), decodable),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+}
+
+impl<CodeId, Payload, Value, GasLimit, Decodable: Decode + MaxEncodedLen> CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, (), DecodableW<Decodable>)> {
+    pub fn with_reply_deposit_auto(self) -> CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, ReplyDepositW, DecodableW<Decodable>)> {
+        let (code_id, payload, value, gas_limit, _, decodable) = self.fields;
+        CreateProgramBuilderForReply {
+            fields: (code_id, payload, value, gas_limit, ReplyDepositW::auto(Decodable::max_encoded_len()), decodable),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+}
+
+impl<CodeId, Buffer: AsRef<[u8]>, Value, GasLimit, Decodable, PayloadCodec: Codec> CreateProgramBuilderForReply<(CodeId, PayloadBytesW<Buffer>, Value, GasLimit, (), DecodableW<Decodable>), PayloadCodec> {
+    #[allow(clippy::type_complexity)]
+    pub fn with_reply_deposit_auto(self) -> CreateProgramBuilderForReply<(CodeId, PayloadBytesW<Buffer>, Value, GasLimit, ReplyDepositW, DecodableW<Decodable>), PayloadCodec> {
+        // A codec can't tell the maximum size of the reply, so the deposit is sized from the
+        // payload.
+        let reply_size = self.fields.1.0.as_ref().len();
+        let (code_id, payload, value, gas_limit, _, decodable) = self.fields;
+        CreateProgramBuilderForReply {
+            fields: (code_id, payload, value, gas_limit, ReplyDepositW::auto(reply_size), decodable),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
 
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>> CreateProgramBuilderForReply<(CodeIdW, PayloadBytesW<Buffer>, Value, (), ReplyDeposit, ())> {
-    pub fn execute(self) -> Result<CreateProgramFuture> {
+    pub fn execute(self) -> Result<CreatedFuture<CreateProgramFuture>> {
         let (CodeIdW(code_id), PayloadBytesW(payload), value, _, reply_deposit, _) = self.fields;
-        ProgramGenerator::create_program_for_reply(code_id, payload, value.into().0, reply_deposit.into().0)
+        ExecuteLog::new("create_program_for_reply", self.quiet).run(|log| Ok(CreatedFuture::new(ActiveBackend::create_program_for_reply(log.code_id(code_id), log.payload_bytes(validate_payload_bytes(payload)?), log.value(value.into().0), log.reply_deposit(reply_deposit.into().0))?)))
     }
 }
 
-impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>> CreateProgramBuilderForReply<(CodeIdW, PayloadBytesW<Buffer>, Value, GasLimitW, ReplyDeposit, ())> {
-    pub fn execute(self) -> Result<CreateProgramFuture> {
-        let (CodeIdW(code_id), PayloadBytesW(payload), value, GasLimitW(gas_limit), reply_deposit, _) = self.fields;
-        ProgramGenerator::create_program_with_gas_for_reply(code_id, payload, gas_limit, value.into().0, reply_deposit.into().0)
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>> CreateProgramBuilderForReply<(CodeIdW, PayloadBytesW<Buffer>, Value, GasLimit, ReplyDeposit, ())> {
+    pub fn execute(self) -> Result<CreatedFuture<CreateProgramFuture>> {
+        let (CodeIdW(code_id), PayloadBytesW(payload), value, gas_limit, reply_deposit, _) = self.fields;
+        ExecuteLog::new("create_program_with_gas_for_reply", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let gas_limit = log.gas_limit(gas_limit.into_gas_limit()?); Ok(CreatedFuture::new(ActiveBackend::create_program_with_gas_for_reply(log.code_id(code_id), payload, gas_limit, log.value(value.into().0), log.reply_deposit(reply_deposit.into().0))?)) })
     }
 }
 
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> CreateProgramBuilderForReply<(CodeIdW, PayloadBytesW<Buffer>, Value, (), ReplyDeposit, DecodableW<Decodable>)> {
-    pub fn execute(self) -> Result<CodecCreateProgramFuture<Decodable>> {
+    pub fn execute(self) -> Result<CreatedFuture<CodecCreateProgramFuture<Decodable>>> {
+        let (CodeIdW(code_id), PayloadBytesW(payload), value, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("create_program_for_reply_as", self.quiet).run(|log| Ok(CreatedFuture::new(ActiveBackend::create_program_for_reply_as(log.code_id(code_id), log.payload_bytes(validate_payload_bytes(payload)?), log.value(value.into().0), log.reply_deposit(reply_deposit.into().0))?)))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> CreateProgramBuilderForReply<(CodeIdW, PayloadBytesW<Buffer>, Value, GasLimit, ReplyDeposit, DecodableW<Decodable>)> {
+    pub fn execute(self) -> Result<CreatedFuture<CodecCreateProgramFuture<Decodable>>> {
+        let (CodeIdW(code_id), PayloadBytesW(payload), value, gas_limit, reply_deposit, _) = self.fields;
+        ExecuteLog::new("create_program_with_gas_for_reply_as", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let gas_limit = log.gas_limit(gas_limit.into_gas_limit()?); Ok(CreatedFuture::new(ActiveBackend::create_program_with_gas_for_reply_as(log.code_id(code_id), payload, gas_limit, log.value(value.into().0), log.reply_deposit(reply_deposit.into().0))?)) })
+    }
+}
+
+// ---------------------------------------------------------------------------------------------- //
+// bindings for `create_program*`: for_reply(), for_reply_as::<Decodable>() with `.with_codec::<PayloadCodec>()`
+// CreateProgramBuilderForReply<(CodeId, Payload, Value, GasLimit, ReplyDeposit, Decodable), PayloadCodec>
+// ---------------------------------------------------------------------------------------------- //
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, PayloadCodec: Codec> CreateProgramBuilderForReply<(CodeIdW, PayloadBytesW<Buffer>, Value, (), ReplyDeposit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<CreatedFuture<CreateProgramFuture>> {
+        let (CodeIdW(code_id), PayloadBytesW(payload), value, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("create_program_for_reply", self.quiet).run(|log| Ok(CreatedFuture::new(ActiveBackend::create_program_for_reply(log.code_id(code_id), log.payload_bytes(validate_payload_bytes(payload)?), log.value(value.into().0), log.reply_deposit(reply_deposit.into().0))?)))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>, PayloadCodec: Codec> CreateProgramBuilderForReply<(CodeIdW, PayloadBytesW<Buffer>, Value, GasLimit, ReplyDeposit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<CreatedFuture<CreateProgramFuture>> {
+        let (CodeIdW(code_id), PayloadBytesW(payload), value, gas_limit, reply_deposit, _) = self.fields;
+        ExecuteLog::new("create_program_with_gas_for_reply", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let gas_limit = log.gas_limit(gas_limit.into_gas_limit()?); Ok(CreatedFuture::new(ActiveBackend::create_program_with_gas_for_reply(log.code_id(code_id), payload, gas_limit, log.value(value.into().0), log.reply_deposit(reply_deposit.into().0))?)) })
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable, PayloadCodec: Decoder<Decodable>> CreateProgramBuilderForReply<(CodeIdW, PayloadBytesW<Buffer>, Value, (), ReplyDeposit, DecodableW<Decodable>), PayloadCodec> {
+    pub fn execute(self) -> Result<CreatedFuture<CodecCreateProgramReplyFuture<CreateProgramFuture, Decodable, PayloadCodec>>> {
         let (CodeIdW(code_id), PayloadBytesW(payload), value, _, reply_deposit, _) = self.fields;
-        ProgramGenerator::create_program_for_reply_as(code_id, payload, value.into().0, reply_deposit.into().0)
+        ExecuteLog::new("create_program_for_reply", self.quiet).run(|log| Ok(CreatedFuture::new(CodecCreateProgramReplyFuture::new(ActiveBackend::create_program_for_reply(log.code_id(code_id), log.payload_bytes(validate_payload_bytes(payload)?), log.value(value.into().0), log.reply_deposit(reply_deposit.into().0))?))))
     }
 }
 
-impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> CreateProgramBuilderForReply<(CodeIdW, PayloadBytesW<Buffer>, Value, GasLimitW, ReplyDeposit, DecodableW<Decodable>)> {
-    pub fn execute(self) -> Result<CodecCreateProgramFuture<Decodable>> {
-        let (CodeIdW(code_id), PayloadBytesW(payload), value, GasLimitW(gas_limit), reply_deposit, _) = self.fields;
-        ProgramGenerator::create_program_with_gas_for_reply_as(code_id, payload, gas_limit, value.into().0, reply_deposit.into().0)
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>, Decodable, PayloadCodec: Decoder<Decodable>> CreateProgramBuilderForReply<(CodeIdW, PayloadBytesW<Buffer>, Value, GasLimit, ReplyDeposit, DecodableW<Decodable>), PayloadCodec> {
+    pub fn execute(self) -> Result<CreatedFuture<CodecCreateProgramReplyFuture<CreateProgramFuture, Decodable, PayloadCodec>>> {
+        let (CodeIdW(code_id), PayloadBytesW(payload), value, gas_limit, reply_deposit, _) = self.fields;
+        ExecuteLog::new("create_program_with_gas_for_reply", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let gas_limit = log.gas_limit(gas_limit.into_gas_limit()?); Ok(CreatedFuture::new(CodecCreateProgramReplyFuture::new(ActiveBackend::create_program_with_gas_for_reply(log.code_id(code_id), payload, gas_limit, log.value(value.into().0), log.reply_deposit(reply_deposit.into().0))?))) })
     }
 }
@@ -7,57 +7,140 @@ use crate::common::*;
 // ReplyBuilder<(Payload, Value, ReservationId, GasLimit)>
 // ---------------------------------------------------------------------------------------------- //
 
-pub struct ReplyBuilder<Fields = ((), (), (), ())> {
+pub struct ReplyBuilder<Fields = ((), (), (), ()), PayloadCodec = ()> {
     fields: Fields,
+    quiet: bool,
+    codec: PhantomData<PayloadCodec>,
+}
+
+impl<Fields, PayloadCodec> ReplyBuilder<Fields, PayloadCodec> {
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+}
+
+impl<Fields> ReplyBuilder<Fields> {
+    pub fn with_codec<PayloadCodec: Codec>(self) -> ReplyBuilder<Fields, PayloadCodec> {
+        ReplyBuilder {
+            fields: self.fields,
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
 }
 
 impl<Buffer: AsRef<[u8]>> ReplyBuilder<(PayloadBytesW<Buffer>, (), (), ())> {
     pub fn bytes(payload: Buffer) -> Self {
         Self {
             fields: (PayloadBytesW(payload), (), (), ()),
+            quiet: false,
+            codec: PhantomData,
         }
     }
 }
 
-impl<Encodable: Encode> ReplyBuilder<(PayloadEncodableW<Encodable>, (), (), ())> {
+impl<Encodable> ReplyBuilder<(PayloadEncodableW<Encodable>, (), (), ())> {
     pub fn encode(payload: Encodable) -> Self {
         Self {
             fields: (PayloadEncodableW(payload), (), (), ()),
+            quiet: false,
+            codec: PhantomData,
+        }
+    }
+}
+
+impl<Encodable, PayloadCodec: Encoder<Encodable>> ReplyBuilder<(PayloadEncodableW<Encodable>, (), (), ()), PayloadCodec> {
+    pub fn encode_with(payload: Encodable) -> Self {
+        Self {
+            fields: (PayloadEncodableW(payload), (), (), ()),
+            quiet: false,
+            codec: PhantomData,
         }
     }
 }
 
+impl<Encodable: Encode, Value, ReservationId, GasLimit> ReplyBuilder<(PayloadEncodableW<Encodable>, Value, ReservationId, GasLimit)> {
+    pub fn encoded_size(&self) -> usize {
+        self.fields.0.0.encoded_size()
+    }
+}
+
 impl<Range: RangeBounds<usize>> ReplyBuilder<(PayloadInputW<Range>, (), (), ())> {
     pub fn input(payload: Range) -> Self {
         Self {
             fields: (PayloadInputW(payload), (), (), ()),
+            quiet: false,
+            codec: PhantomData,
         }
     }
 }
 
-impl<Payload, ReservationId, GasLimit> ReplyBuilder<(Payload, (), ReservationId, GasLimit)> {
-    pub fn with_value(self, value: u128) -> ReplyBuilder<(Payload, ValueW, ReservationId, GasLimit)> {
+impl<Payload, ReservationId, GasLimit, PayloadCodec> ReplyBuilder<(Payload, (), ReservationId, GasLimit), PayloadCodec> {
+    pub fn with_value(self, value: u128) -> ReplyBuilder<(Payload, ValueW, ReservationId, GasLimit), PayloadCodec> {
         let (payload, _, reservation_id, gas_limit) = self.fields;
         ReplyBuilder {
             fields: (payload, ValueW(value), reservation_id, gas_limit),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
 
-impl<Payload: PayloadWithGasReservationMarker, Value, GasLimit: UnitTypeMarker> ReplyBuilder<(Payload, Value, (), GasLimit)> {
-    pub fn with_gas_from_reservation(self, reservation_id: ReservationId) -> ReplyBuilder<(Payload, Value, ReservationIdW, GasLimit)> {
+impl<Payload: PayloadWithGasReservationMarker, Value, GasLimit: UnitTypeMarker, PayloadCodec> ReplyBuilder<(Payload, Value, (), GasLimit), PayloadCodec> {
+    pub fn with_gas_from_reservation(self, reservation_id: ReservationId) -> ReplyBuilder<(Payload, Value, ReservationIdW, GasLimit), PayloadCodec> {
         let (payload, value, _, gas_limit) = self.fields;
         ReplyBuilder {
             fields: (payload, value, ReservationIdW(reservation_id), gas_limit),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
 
-impl<Payload, Value, ReservationId: UnitTypeMarker> ReplyBuilder<(Payload, Value, ReservationId, ())> {
-    pub fn with_gas_limit(self, gas_limit: u64) -> ReplyBuilder<(Payload, Value, ReservationId, GasLimitW)> {
+impl<Payload, Value, ReservationId: UnitTypeMarker, PayloadCodec> ReplyBuilder<(Payload, Value, ReservationId, ()), PayloadCodec> {
+    pub fn with_gas_limit(self, gas_limit: u64) -> ReplyBuilder<(Payload, Value, ReservationId, GasLimitW), PayloadCodec> {
         let (payload, value, reservation_id, _) = self.fields;
         ReplyBuilder {
             fields: (payload, value, reservation_id, GasLimitW(gas_limit)),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+
+    pub fn with_gas_fraction(self, numerator: u64, denominator: u64) -> ReplyBuilder<(Payload, Value, ReservationId, GasFractionW), PayloadCodec> {
+        let (payload, value, reservation_id, _) = self.fields;
+        ReplyBuilder {
+            fields: (payload, value, reservation_id, GasFractionW(numerator, denominator)),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+
+    pub fn with_gas_remaining_minus(self, reserve: u64) -> ReplyBuilder<(Payload, Value, ReservationId, GasRemainingMinusW), PayloadCodec> {
+        let (payload, value, reservation_id, _) = self.fields;
+        ReplyBuilder {
+            fields: (payload, value, reservation_id, GasRemainingMinusW(reserve)),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+
+    pub fn with_all_remaining_gas(self) -> ReplyBuilder<(Payload, Value, ReservationId, AllRemainingGasW), PayloadCodec> {
+        let (payload, value, reservation_id, _) = self.fields;
+        ReplyBuilder {
+            fields: (payload, value, reservation_id, AllRemainingGasW),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+
+    pub fn with_gas_from_budget(self, budget: &mut GasBudget, weight: u64) -> ReplyBuilder<(Payload, Value, ReservationId, GasBudgetW<'_>), PayloadCodec> {
+        let (payload, value, reservation_id, _) = self.fields;
+        ReplyBuilder {
+            fields: (payload, value, reservation_id, GasBudgetW(budget, weight)),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
@@ -65,55 +148,116 @@ impl<Payload, Value, ReservationId: UnitTypeMarker> ReplyBuilder<(Payload, Value
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> ReplyBuilder<(PayloadBytesW<Buffer>, Value, (), ())> {
     pub fn execute(self) -> Result<MessageId> {
         let (PayloadBytesW(payload), value, _, _) = self.fields;
-        reply_bytes(payload, value.into().0)
+        ExecuteLog::new("reply_bytes", self.quiet).run(|log| Ok(ActiveBackend::reply_bytes(log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?))?))
     }
 }
 
-impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> ReplyBuilder<(PayloadBytesW<Buffer>, Value, (), GasLimitW)> {
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit> ReplyBuilder<(PayloadBytesW<Buffer>, Value, (), GasLimit)> {
     pub fn execute(self) -> Result<MessageId> {
-        let (PayloadBytesW(payload), value, _, GasLimitW(gas_limit)) = self.fields;
-        reply_bytes_with_gas(payload, gas_limit, value.into().0)
+        let (PayloadBytesW(payload), value, _, gas_limit) = self.fields;
+        ExecuteLog::new("reply_bytes_with_gas", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::reply_bytes_with_gas(payload, gas_limit, value)?) })
     }
 }
 
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> ReplyBuilder<(PayloadBytesW<Buffer>, Value, ReservationIdW, ())> {
     pub fn execute(self) -> Result<MessageId> {
         let (PayloadBytesW(payload), value, ReservationIdW(reservation_id), _) = self.fields;
-        reply_bytes_from_reservation(reservation_id, payload, value.into().0)
+        ExecuteLog::new("reply_bytes_from_reservation", self.quiet).run(|log| Ok(ActiveBackend::reply_bytes_from_reservation(log.reservation_id(reservation_id), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?))?))
     }
 }
 
 impl<Encodable: Encode, Value: Into<ValueW>> ReplyBuilder<(PayloadEncodableW<Encodable>, Value, (), ())> {
     pub fn execute(self) -> Result<MessageId> {
         let (PayloadEncodableW(payload), value, _, _) = self.fields;
-        reply(payload, value.into().0)
+        ExecuteLog::new("reply", self.quiet).run(|log| Ok(ActiveBackend::reply(log.payload_encodable(validate_payload_encodable(payload)?), log.value(validate_value(value.into().0)?))?))
     }
 }
 
-impl<Encodable: Encode, Value: Into<ValueW>> ReplyBuilder<(PayloadEncodableW<Encodable>, Value, (), GasLimitW)> {
+impl<Encodable: Encode, Value: Into<ValueW>, GasLimit: IntoGasLimit> ReplyBuilder<(PayloadEncodableW<Encodable>, Value, (), GasLimit)> {
     pub fn execute(self) -> Result<MessageId> {
-        let (PayloadEncodableW(payload), value, _, GasLimitW(gas_limit)) = self.fields;
-        reply_with_gas(payload, gas_limit, value.into().0)
+        let (PayloadEncodableW(payload), value, _, gas_limit) = self.fields;
+        ExecuteLog::new("reply_with_gas", self.quiet).run(|log| { let payload = log.payload_encodable(validate_payload_encodable(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::reply_with_gas(payload, gas_limit, value)?) })
     }
 }
 
 impl<Encodable: Encode, Value: Into<ValueW>> ReplyBuilder<(PayloadEncodableW<Encodable>, Value, ReservationIdW, ())> {
     pub fn execute(self) -> Result<MessageId> {
         let (PayloadEncodableW(payload), value, ReservationIdW(reservation_id), _) = self.fields;
-        reply_from_reservation(reservation_id, payload, value.into().0)
+        ExecuteLog::new("reply_from_reservation", self.quiet).run(|log| Ok(ActiveBackend::reply_from_reservation(log.reservation_id(reservation_id), log.payload_encodable(validate_payload_encodable(payload)?), log.value(validate_value(value.into().0)?))?))
     }
 }
 
 impl<Range: RangeBounds<usize>, Value: Into<ValueW>> ReplyBuilder<(PayloadInputW<Range>, Value, (), ())> {
     pub fn execute(self) -> Result<MessageId> {
         let (PayloadInputW(payload), value, _, _) = self.fields;
-        reply_input(value.into().0, payload)
+        ExecuteLog::new("reply_input", self.quiet).run(|log| Ok(ActiveBackend::reply_input(log.value(validate_value(value.into().0)?), log.payload_input(validate_payload_input(payload)?))?))
+    }
+}
+
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, GasLimit: IntoGasLimit> ReplyBuilder<(PayloadInputW<Range>, Value, (), GasLimit)> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (PayloadInputW(payload), value, _, gas_limit) = self.fields;
+        ExecuteLog::new("reply_input_with_gas", self.quiet).run(|log| { let value = log.value(validate_value(value.into().0)?); let payload = log.payload_input(validate_payload_input(payload)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::reply_input_with_gas(gas_limit, value, payload)?) })
+    }
+}
+
+// ---------------------------------------------------------------------------------------------- //
+// bindings for `reply*` with `.with_codec::<PayloadCodec>()`
+// ReplyBuilder<(Payload, Value, ReservationId, GasLimit), PayloadCodec>
+// ---------------------------------------------------------------------------------------------- //
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, PayloadCodec: Codec> ReplyBuilder<(PayloadBytesW<Buffer>, Value, (), ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (PayloadBytesW(payload), value, _, _) = self.fields;
+        ExecuteLog::new("reply_bytes", self.quiet).run(|log| Ok(ActiveBackend::reply_bytes(log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?))?))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, PayloadCodec: Codec> ReplyBuilder<(PayloadBytesW<Buffer>, Value, (), GasLimit), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (PayloadBytesW(payload), value, _, gas_limit) = self.fields;
+        ExecuteLog::new("reply_bytes_with_gas", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::reply_bytes_with_gas(payload, gas_limit, value)?) })
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, PayloadCodec: Codec> ReplyBuilder<(PayloadBytesW<Buffer>, Value, ReservationIdW, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (PayloadBytesW(payload), value, ReservationIdW(reservation_id), _) = self.fields;
+        ExecuteLog::new("reply_bytes_from_reservation", self.quiet).run(|log| Ok(ActiveBackend::reply_bytes_from_reservation(log.reservation_id(reservation_id), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?))?))
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, PayloadCodec: Encoder<Encodable>> ReplyBuilder<(PayloadEncodableW<Encodable>, Value, (), ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (PayloadEncodableW(payload), value, _, _) = self.fields;
+        ExecuteLog::new("reply_bytes", self.quiet).run(|log| Ok(ActiveBackend::reply_bytes(log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?), log.value(validate_value(value.into().0)?))?))
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, GasLimit: IntoGasLimit, PayloadCodec: Encoder<Encodable>> ReplyBuilder<(PayloadEncodableW<Encodable>, Value, (), GasLimit), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (PayloadEncodableW(payload), value, _, gas_limit) = self.fields;
+        ExecuteLog::new("reply_bytes_with_gas", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::reply_bytes_with_gas(payload, gas_limit, value)?) })
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, PayloadCodec: Encoder<Encodable>> ReplyBuilder<(PayloadEncodableW<Encodable>, Value, ReservationIdW, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (PayloadEncodableW(payload), value, ReservationIdW(reservation_id), _) = self.fields;
+        ExecuteLog::new("reply_bytes_from_reservation", self.quiet).run(|log| Ok(ActiveBackend::reply_bytes_from_reservation(log.reservation_id(reservation_id), log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?), log.value(validate_value(value.into().0)?))?))
+    }
+}
+
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, PayloadCodec: Codec> ReplyBuilder<(PayloadInputW<Range>, Value, (), ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (PayloadInputW(payload), value, _, _) = self.fields;
+        ExecuteLog::new("reply_input", self.quiet).run(|log| Ok(ActiveBackend::reply_input(log.value(validate_value(value.into().0)?), log.payload_input(validate_payload_input(payload)?))?))
     }
 }
 
-impl<Range: RangeBounds<usize>, Value: Into<ValueW>> ReplyBuilder<(PayloadInputW<Range>, Value, (), GasLimitW)> {
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, GasLimit: IntoGasLimit, PayloadCodec: Codec> ReplyBuilder<(PayloadInputW<Range>, Value, (), GasLimit), PayloadCodec> {
     pub fn execute(self) -> Result<MessageId> {
-        let (PayloadInputW(payload), value, _, GasLimitW(gas_limit)) = self.fields;
-        reply_input_with_gas(gas_limit, value.into().0, payload)
+        let (PayloadInputW(payload), value, _, gas_limit) = self.fields;
+        ExecuteLog::new("reply_input_with_gas", self.quiet).run(|log| { let value = log.value(validate_value(value.into().0)?); let payload = log.payload_input(validate_payload_input(payload)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::reply_input_with_gas(gas_limit, value, payload)?) })
     }
 }
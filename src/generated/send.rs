@@ -7,83 +7,190 @@ use crate::common::*;
 // SendBuilder<(Program, Payload, Value, Delay, GasLimit, ReservationId)>
 // ---------------------------------------------------------------------------------------------- //
 
-pub struct SendBuilder<Fields = ((), (), (), (), (), ())> {
+pub struct SendBuilder<Fields = ((), (), (), (), (), ()), PayloadCodec = ()> {
     fields: Fields,
+    quiet: bool,
+    codec: PhantomData<PayloadCodec>,
+}
+
+impl<Fields, PayloadCodec> SendBuilder<Fields, PayloadCodec> {
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+}
+
+impl<Fields> SendBuilder<Fields> {
+    pub fn with_codec<PayloadCodec: Codec>(self) -> SendBuilder<Fields, PayloadCodec> {
+        SendBuilder {
+            fields: self.fields,
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
 }
 
 impl<Buffer: AsRef<[u8]>> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, (), (), (), ())> {
     pub fn bytes(program: ActorId, payload: Buffer) -> Self {
         Self {
             fields: (ProgramW(program), PayloadBytesW(payload), (), (), (), ()),
+            quiet: false,
+            codec: PhantomData,
         }
     }
 }
 
-impl<Encodable: Encode> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, (), (), (), ())> {
+impl<Encodable> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, (), (), (), ())> {
     pub fn encode(program: ActorId, payload: Encodable) -> Self {
         Self {
             fields: (ProgramW(program), PayloadEncodableW(payload), (), (), (), ()),
+            quiet: false,
+            codec: PhantomData,
+        }
+    }
+}
+
+impl<Encodable, PayloadCodec: Encoder<Encodable>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, (), (), (), ()), PayloadCodec> {
+    pub fn encode_with(program: ActorId, payload: Encodable) -> Self {
+        Self {
+            fields: (ProgramW(program), PayloadEncodableW(payload), (), (), (), ()),
+            quiet: false,
+            codec: PhantomData,
         }
     }
 }
 
+impl<Encodable: Encode, Value, Delay, GasLimit, ReservationId> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, Delay, GasLimit, ReservationId)> {
+    pub fn encoded_size(&self) -> usize {
+        self.fields.1.0.encoded_size()
+    }
+}
+
 impl<Range: RangeBounds<usize>> SendBuilder<(ProgramW, PayloadInputW<Range>, (), (), (), ())> {
     pub fn input(program: ActorId, payload: Range) -> Self {
         Self {
             fields: (ProgramW(program), PayloadInputW(payload), (), (), (), ()),
+            quiet: false,
+            codec: PhantomData,
         }
     }
 }
 
-impl<Program, Payload, Delay, GasLimit, ReservationId> SendBuilder<(Program, Payload, (), Delay, GasLimit, ReservationId)> {
-    pub fn with_value(self, value: u128) -> SendBuilder<(Program, Payload, ValueW, Delay, GasLimit, ReservationId)> {
+impl<Program, Payload, Delay, GasLimit, ReservationId, PayloadCodec> SendBuilder<(Program, Payload, (), Delay, GasLimit, ReservationId), PayloadCodec> {
+    pub fn with_value(self, value: u128) -> SendBuilder<(Program, Payload, ValueW, Delay, GasLimit, ReservationId), PayloadCodec> {
         let (program, payload, _, delay, gas_limit, reservation_id) = self.fields;
         SendBuilder {
             fields: (program, payload, ValueW(value), delay, gas_limit, reservation_id),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
 
-impl<Program, Payload, Value, GasLimit, ReservationId> SendBuilder<(Program, Payload, Value, (), GasLimit, ReservationId)> {
-    pub fn with_delay(self, delay: u32) -> SendBuilder<(Program, Payload, Value, DelayW, GasLimit, ReservationId)> {
+impl<Program, Payload, Value, GasLimit, ReservationId, PayloadCodec> SendBuilder<(Program, Payload, Value, (), GasLimit, ReservationId), PayloadCodec> {
+    pub fn with_delay(self, delay: u32) -> SendBuilder<(Program, Payload, Value, DelayW, GasLimit, ReservationId), PayloadCodec> {
         let (program, payload, value, _, gas_limit, reservation_id) = self.fields;
         SendBuilder {
             fields: (program, payload, value, DelayW(delay), gas_limit, reservation_id),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
 
-impl<Program, Payload, Value, Delay, ReservationId: UnitTypeMarker> SendBuilder<(Program, Payload, Value, Delay, (), ReservationId)> {
-    pub fn with_gas_limit(self, gas_limit: u64) -> SendBuilder<(Program, Payload, Value, Delay, GasLimitW, ReservationId)> {
+impl<Program, Payload, Value, Delay, ReservationId: UnitTypeMarker, PayloadCodec> SendBuilder<(Program, Payload, Value, Delay, (), ReservationId), PayloadCodec> {
+    pub fn with_gas_limit(self, gas_limit: u64) -> SendBuilder<(Program, Payload, Value, Delay, GasLimitW, ReservationId), PayloadCodec> {
         let (program, payload, value, delay, _, reservation_id) = self.fields;
         SendBuilder {
             fields: (program, payload, value, delay, GasLimitW(gas_limit), reservation_id),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+
+    pub fn with_gas_fraction(self, numerator: u64, denominator: u64) -> SendBuilder<(Program, Payload, Value, Delay, GasFractionW, ReservationId), PayloadCodec> {
+        let (program, payload, value, delay, _, reservation_id) = self.fields;
+        SendBuilder {
+            fields: (program, payload, value, delay, GasFractionW(numerator, denominator), reservation_id),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+
+    pub fn with_gas_remaining_minus(self, reserve: u64) -> SendBuilder<(Program, Payload, Value, Delay, GasRemainingMinusW, ReservationId), PayloadCodec> {
+        let (program, payload, value, delay, _, reservation_id) = self.fields;
+        SendBuilder {
+            fields: (program, payload, value, delay, GasRemainingMinusW(reserve), reservation_id),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+
+    pub fn with_all_remaining_gas(self) -> SendBuilder<(Program, Payload, Value, Delay, AllRemainingGasW, ReservationId), PayloadCodec> {
+        let (program, payload, value, delay, _, reservation_id) = self.fields;
+        SendBuilder {
+            fields: (program, payload, value, delay, AllRemainingGasW, reservation_id),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+
+    pub fn with_gas_from_budget(self, budget: &mut GasBudget, weight: u64) -> SendBuilder<(Program, Payload, Value, Delay, GasBudgetW<'_>, ReservationId), PayloadCodec> {
+        let (program, payload, value, delay, _, reservation_id) = self.fields;
+        SendBuilder {
+            fields: (program, payload, value, delay, GasBudgetW(budget, weight), reservation_id),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
 
-impl<Program, Payload: PayloadWithGasReservationMarker, Value, Delay, GasLimit: UnitTypeMarker> SendBuilder<(Program, Payload, Value, Delay, GasLimit, ())> {
-    pub fn with_gas_from_reservation(self, reservation_id: ReservationId) -> SendBuilder<(Program, Payload, Value, Delay, GasLimit, ReservationIdW)> {
+impl<Program, Payload: PayloadWithGasReservationMarker, Value, Delay, GasLimit: UnitTypeMarker, PayloadCodec> SendBuilder<(Program, Payload, Value, Delay, GasLimit, ()), PayloadCodec> {
+    pub fn with_gas_from_reservation(self, reservation_id: ReservationId) -> SendBuilder<(Program, Payload, Value, Delay, GasLimit, ReservationIdW), PayloadCodec> {
         let (program, payload, value, delay, gas_limit, _) = self.fields;
         SendBuilder {
             fields: (program, payload, value, delay, gas_limit, ReservationIdW(reservation_id)),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
 
-impl<Program, Payload, Value, GasLimit, ReservationId> SendBuilder<(Program, Payload, Value, (), GasLimit, ReservationId)> {
-    pub fn for_reply(self) -> SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, (), ())> {
+impl<Program, Payload, Value, GasLimit, ReservationId, PayloadCodec> SendBuilder<(Program, Payload, Value, (), GasLimit, ReservationId), PayloadCodec> {
+    #[allow(clippy::type_complexity)]
+    pub fn for_reply(self) -> SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, (), ()), PayloadCodec> {
         let (program, payload, value, _, gas_limit, reservation_id) = self.fields;
         SendBuilderForReply {
             fields: (program, payload, value, gas_limit, reservation_id, (), ()),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
+}
 
+impl<Program, Payload, Value, GasLimit, ReservationId> SendBuilder<(Program, Payload, Value, (), GasLimit, ReservationId)> {
     #[allow(clippy::type_complexity)]
     pub fn for_reply_as<Decodable: Decode>(self) -> SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, (), DecodableW<Decodable>)> {
         let (program, payload, value, _, gas_limit, reservation_id) = self.fields;
         SendBuilderForReply {
             fields: (program, payload, value, gas_limit, reservation_id, (), DecodableW(PhantomData)),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+}
+
+impl<Program, Payload, Value, GasLimit, ReservationId, PayloadCodec: Codec> SendBuilder<(Program, Payload, Value, (), GasLimit, ReservationId), PayloadCodec> {
+    #[allow(clippy::type_complexity)]
+    pub fn for_reply_as<Decodable>(self) -> SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, (), DecodableW<Decodable>), PayloadCodec>
+    where
+        PayloadCodec: Decoder<Decodable>,
+    {
+        let (program, payload, value, _, gas_limit, reservation_id) = self.fields;
+        SendBuilderForReply {
+            fields: (program, payload, value, gas_limit, reservation_id, (), DecodableW(PhantomData)),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
@@ -91,112 +198,229 @@ impl<Program, Payload, Value, GasLimit, ReservationId> SendBuilder<(Program, Pay
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, (), (), ())> {
     pub fn execute(self) -> Result<MessageId> {
         let (ProgramW(program), PayloadBytesW(payload), value, _, _, _) = self.fields;
-        send_bytes(program, payload, value.into().0)
+        ExecuteLog::new("send_bytes", self.quiet).run(|log| Ok(ActiveBackend::send_bytes(log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?))?))
     }
 }
 
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, (), (), ReservationIdW)> {
     pub fn execute(self) -> Result<MessageId> {
         let (ProgramW(program), PayloadBytesW(payload), value, _, _, ReservationIdW(reservation_id)) = self.fields;
-        send_bytes_from_reservation(reservation_id, program, payload, value.into().0)
+        ExecuteLog::new("send_bytes_from_reservation", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_from_reservation(log.reservation_id(reservation_id), log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?))?))
     }
 }
 
-impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, (), GasLimitW, ())> {
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, (), GasLimit, ())> {
     pub fn execute(self) -> Result<MessageId> {
-        let (ProgramW(program), PayloadBytesW(payload), value, _, GasLimitW(gas_limit), _) = self.fields;
-        send_bytes_with_gas(program, payload, gas_limit, value.into().0)
+        let (ProgramW(program), PayloadBytesW(payload), value, _, gas_limit, _) = self.fields;
+        ExecuteLog::new("send_bytes_with_gas", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_bytes_with_gas(log.destination(program), payload, gas_limit, value)?) })
     }
 }
 
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, DelayW, (), ())> {
     pub fn execute(self) -> Result<MessageId> {
         let (ProgramW(program), PayloadBytesW(payload), value, DelayW(delay), _, _) = self.fields;
-        send_bytes_delayed(program, payload, value.into().0, delay)
+        ExecuteLog::new("send_bytes_delayed", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_delayed(log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?), log.delay(delay))?))
     }
 }
 
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, DelayW, (), ReservationIdW)> {
     pub fn execute(self) -> Result<MessageId> {
         let (ProgramW(program), PayloadBytesW(payload), value, DelayW(delay), _, ReservationIdW(reservation_id)) = self.fields;
-        send_bytes_delayed_from_reservation(reservation_id, program, payload, value.into().0, delay)
+        ExecuteLog::new("send_bytes_delayed_from_reservation", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_delayed_from_reservation(log.reservation_id(reservation_id), log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?), log.delay(delay))?))
     }
 }
 
-impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, DelayW, GasLimitW, ())> {
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, DelayW, GasLimit, ())> {
     pub fn execute(self) -> Result<MessageId> {
-        let (ProgramW(program), PayloadBytesW(payload), value, DelayW(delay), GasLimitW(gas_limit), _) = self.fields;
-        send_bytes_with_gas_delayed(program, payload, gas_limit, value.into().0, delay)
+        let (ProgramW(program), PayloadBytesW(payload), value, DelayW(delay), gas_limit, _) = self.fields;
+        ExecuteLog::new("send_bytes_with_gas_delayed", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_bytes_with_gas_delayed(log.destination(program), payload, gas_limit, value, log.delay(delay))?) })
     }
 }
 
 impl<Encodable: Encode, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, (), (), ())> {
     pub fn execute(self) -> Result<MessageId> {
         let (ProgramW(program), PayloadEncodableW(payload), value, _, _, _) = self.fields;
-        send(program, payload, value.into().0)
+        ExecuteLog::new("send", self.quiet).run(|log| Ok(ActiveBackend::send(log.destination(program), log.payload_encodable(validate_payload_encodable(payload)?), log.value(validate_value(value.into().0)?))?))
     }
 }
 
 impl<Encodable: Encode, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, (), (), ReservationIdW)> {
     pub fn execute(self) -> Result<MessageId> {
         let (ProgramW(program), PayloadEncodableW(payload), value, _, _, ReservationIdW(reservation_id)) = self.fields;
-        send_from_reservation(reservation_id, program, payload, value.into().0)
+        ExecuteLog::new("send_from_reservation", self.quiet).run(|log| Ok(ActiveBackend::send_from_reservation(log.reservation_id(reservation_id), log.destination(program), log.payload_encodable(validate_payload_encodable(payload)?), log.value(validate_value(value.into().0)?))?))
     }
 }
 
-impl<Encodable: Encode, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, (), GasLimitW, ())> {
+impl<Encodable: Encode, Value: Into<ValueW>, GasLimit: IntoGasLimit> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, (), GasLimit, ())> {
     pub fn execute(self) -> Result<MessageId> {
-        let (ProgramW(program), PayloadEncodableW(payload), value, _, GasLimitW(gas_limit), _) = self.fields;
-        send_with_gas(program, payload, gas_limit, value.into().0)
+        let (ProgramW(program), PayloadEncodableW(payload), value, _, gas_limit, _) = self.fields;
+        ExecuteLog::new("send_with_gas", self.quiet).run(|log| { let payload = log.payload_encodable(validate_payload_encodable(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_with_gas(log.destination(program), payload, gas_limit, value)?) })
     }
 }
 
 impl<Encodable: Encode, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, DelayW, (), ())> {
     pub fn execute(self) -> Result<MessageId> {
         let (ProgramW(program), PayloadEncodableW(payload), value, DelayW(delay), _, _) = self.fields;
-        send_delayed(program, payload, value.into().0, delay)
+        ExecuteLog::new("send_delayed", self.quiet).run(|log| Ok(ActiveBackend::send_delayed(log.destination(program), log.payload_encodable(validate_payload_encodable(payload)?), log.value(validate_value(value.into().0)?), log.delay(delay))?))
     }
 }
 
 impl<Encodable: Encode, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, DelayW, (), ReservationIdW)> {
     pub fn execute(self) -> Result<MessageId> {
         let (ProgramW(program), PayloadEncodableW(payload), value, DelayW(delay), _, ReservationIdW(reservation_id)) = self.fields;
-        send_delayed_from_reservation(reservation_id, program, payload, value.into().0, delay)
+        ExecuteLog::new("send_delayed_from_reservation", self.quiet).run(|log| Ok(ActiveBackend::send_delayed_from_reservation(log.reservation_id(reservation_id), log.destination(program), log.payload_encodable(validate_payload_encodable(payload)?), log.value(validate_value(value.into().0)?), log.delay(delay))?))
     }
 }
 
-impl<Encodable: Encode, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, DelayW, GasLimitW, ())> {
+impl<Encodable: Encode, Value: Into<ValueW>, GasLimit: IntoGasLimit> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, DelayW, GasLimit, ())> {
     pub fn execute(self) -> Result<MessageId> {
-        let (ProgramW(program), PayloadEncodableW(payload), value, DelayW(delay), GasLimitW(gas_limit), _) = self.fields;
-        send_with_gas_delayed(program, payload, gas_limit, value.into().0, delay)
+        let (ProgramW(program), PayloadEncodableW(payload), value, DelayW(delay), gas_limit, _) = self.fields;
+        ExecuteLog::new("send_with_gas_delayed", self.quiet).run(|log| { let payload = log.payload_encodable(validate_payload_encodable(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_with_gas_delayed(log.destination(program), payload, gas_limit, value, log.delay(delay))?) })
     }
 }
 
 impl<Range: RangeBounds<usize>, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadInputW<Range>, Value, (), (), ())> {
     pub fn execute(self) -> Result<MessageId> {
         let (ProgramW(program), PayloadInputW(payload), value, _, _, _) = self.fields;
-        send_input(program, value.into().0, payload)
+        ExecuteLog::new("send_input", self.quiet).run(|log| Ok(ActiveBackend::send_input(log.destination(program), log.value(validate_value(value.into().0)?), log.payload_input(validate_payload_input(payload)?))?))
     }
 }
 
-impl<Range: RangeBounds<usize>, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadInputW<Range>, Value, (), GasLimitW, ())> {
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, GasLimit: IntoGasLimit> SendBuilder<(ProgramW, PayloadInputW<Range>, Value, (), GasLimit, ())> {
     pub fn execute(self) -> Result<MessageId> {
-        let (ProgramW(program), PayloadInputW(payload), value, _, GasLimitW(gas_limit), _) = self.fields;
-        send_input_with_gas(program, gas_limit, value.into().0, payload)
+        let (ProgramW(program), PayloadInputW(payload), value, _, gas_limit, _) = self.fields;
+        ExecuteLog::new("send_input_with_gas", self.quiet).run(|log| { let value = log.value(validate_value(value.into().0)?); let payload = log.payload_input(validate_payload_input(payload)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_input_with_gas(log.destination(program), gas_limit, value, payload)?) })
     }
 }
 
 impl<Range: RangeBounds<usize>, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadInputW<Range>, Value, DelayW, (), ())> {
     pub fn execute(self) -> Result<MessageId> {
         let (ProgramW(program), PayloadInputW(payload), value, DelayW(delay), _, _) = self.fields;
-        send_input_delayed(program, value.into().0, payload, delay)
+        ExecuteLog::new("send_input_delayed", self.quiet).run(|log| Ok(ActiveBackend::send_input_delayed(log.destination(program), log.value(validate_value(value.into().0)?), log.payload_input(validate_payload_input(payload)?), log.delay(delay))?))
+    }
+}
+
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, GasLimit: IntoGasLimit> SendBuilder<(ProgramW, PayloadInputW<Range>, Value, DelayW, GasLimit, ())> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadInputW(payload), value, DelayW(delay), gas_limit, _) = self.fields;
+        ExecuteLog::new("send_input_with_gas_delayed", self.quiet).run(|log| { let value = log.value(validate_value(value.into().0)?); let payload = log.payload_input(validate_payload_input(payload)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_input_with_gas_delayed(log.destination(program), gas_limit, value, payload, log.delay(delay))?) })
+    }
+}
+
+// ---------------------------------------------------------------------------------------------- //
+// bindings for `send*` with `.with_codec::<PayloadCodec>()`
+// SendBuilder<(Program, Payload, Value, Delay, GasLimit, ReservationId), PayloadCodec>
+// ---------------------------------------------------------------------------------------------- //
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, PayloadCodec: Codec> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, (), (), ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadBytesW(payload), value, _, _, _) = self.fields;
+        ExecuteLog::new("send_bytes", self.quiet).run(|log| Ok(ActiveBackend::send_bytes(log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?))?))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, PayloadCodec: Codec> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, (), (), ReservationIdW), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadBytesW(payload), value, _, _, ReservationIdW(reservation_id)) = self.fields;
+        ExecuteLog::new("send_bytes_from_reservation", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_from_reservation(log.reservation_id(reservation_id), log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?))?))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, PayloadCodec: Codec> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, (), GasLimit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadBytesW(payload), value, _, gas_limit, _) = self.fields;
+        ExecuteLog::new("send_bytes_with_gas", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_bytes_with_gas(log.destination(program), payload, gas_limit, value)?) })
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, PayloadCodec: Codec> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, DelayW, (), ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadBytesW(payload), value, DelayW(delay), _, _) = self.fields;
+        ExecuteLog::new("send_bytes_delayed", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_delayed(log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?), log.delay(delay))?))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, PayloadCodec: Codec> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, DelayW, (), ReservationIdW), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadBytesW(payload), value, DelayW(delay), _, ReservationIdW(reservation_id)) = self.fields;
+        ExecuteLog::new("send_bytes_delayed_from_reservation", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_delayed_from_reservation(log.reservation_id(reservation_id), log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?), log.delay(delay))?))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, PayloadCodec: Codec> SendBuilder<(ProgramW, PayloadBytesW<Buffer>, Value, DelayW, GasLimit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadBytesW(payload), value, DelayW(delay), gas_limit, _) = self.fields;
+        ExecuteLog::new("send_bytes_with_gas_delayed", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_bytes_with_gas_delayed(log.destination(program), payload, gas_limit, value, log.delay(delay))?) })
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, PayloadCodec: Encoder<Encodable>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, (), (), ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadEncodableW(payload), value, _, _, _) = self.fields;
+        ExecuteLog::new("send_bytes", self.quiet).run(|log| Ok(ActiveBackend::send_bytes(log.destination(program), log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?), log.value(validate_value(value.into().0)?))?))
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, PayloadCodec: Encoder<Encodable>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, (), (), ReservationIdW), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadEncodableW(payload), value, _, _, ReservationIdW(reservation_id)) = self.fields;
+        ExecuteLog::new("send_bytes_from_reservation", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_from_reservation(log.reservation_id(reservation_id), log.destination(program), log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?), log.value(validate_value(value.into().0)?))?))
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, GasLimit: IntoGasLimit, PayloadCodec: Encoder<Encodable>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, (), GasLimit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadEncodableW(payload), value, _, gas_limit, _) = self.fields;
+        ExecuteLog::new("send_bytes_with_gas", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_bytes_with_gas(log.destination(program), payload, gas_limit, value)?) })
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, PayloadCodec: Encoder<Encodable>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, DelayW, (), ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadEncodableW(payload), value, DelayW(delay), _, _) = self.fields;
+        ExecuteLog::new("send_bytes_delayed", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_delayed(log.destination(program), log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?), log.value(validate_value(value.into().0)?), log.delay(delay))?))
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, PayloadCodec: Encoder<Encodable>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, DelayW, (), ReservationIdW), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadEncodableW(payload), value, DelayW(delay), _, ReservationIdW(reservation_id)) = self.fields;
+        ExecuteLog::new("send_bytes_delayed_from_reservation", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_delayed_from_reservation(log.reservation_id(reservation_id), log.destination(program), log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?), log.value(validate_value(value.into().0)?), log.delay(delay))?))
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, GasLimit: IntoGasLimit, PayloadCodec: Encoder<Encodable>> SendBuilder<(ProgramW, PayloadEncodableW<Encodable>, Value, DelayW, GasLimit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadEncodableW(payload), value, DelayW(delay), gas_limit, _) = self.fields;
+        ExecuteLog::new("send_bytes_with_gas_delayed", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_bytes_with_gas_delayed(log.destination(program), payload, gas_limit, value, log.delay(delay))?) })
+    }
+}
+
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, PayloadCodec: Codec> SendBuilder<(ProgramW, PayloadInputW<Range>, Value, (), (), ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadInputW(payload), value, _, _, _) = self.fields;
+        ExecuteLog::new("send_input", self.quiet).run(|log| Ok(ActiveBackend::send_input(log.destination(program), log.value(validate_value(value.into().0)?), log.payload_input(validate_payload_input(payload)?))?))
+    }
+}
+
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, GasLimit: IntoGasLimit, PayloadCodec: Codec> SendBuilder<(ProgramW, PayloadInputW<Range>, Value, (), GasLimit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadInputW(payload), value, _, gas_limit, _) = self.fields;
+        ExecuteLog::new("send_input_with_gas", self.quiet).run(|log| { let value = log.value(validate_value(value.into().0)?); let payload = log.payload_input(validate_payload_input(payload)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_input_with_gas(log.destination(program), gas_limit, value, payload)?) })
+    }
+}
+
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, PayloadCodec: Codec> SendBuilder<(ProgramW, PayloadInputW<Range>, Value, DelayW, (), ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(program), PayloadInputW(payload), value, DelayW(delay), _, _) = self.fields;
+        ExecuteLog::new("send_input_delayed", self.quiet).run(|log| Ok(ActiveBackend::send_input_delayed(log.destination(program), log.value(validate_value(value.into().0)?), log.payload_input(validate_payload_input(payload)?), log.delay(delay))?))
     }
 }
 
-impl<Range: RangeBounds<usize>, Value: Into<ValueW>> SendBuilder<(ProgramW, PayloadInputW<Range>, Value, DelayW, GasLimitW, ())> {
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, GasLimit: IntoGasLimit, PayloadCodec: Codec> SendBuilder<(ProgramW, PayloadInputW<Range>, Value, DelayW, GasLimit, ()), PayloadCodec> {
     pub fn execute(self) -> Result<MessageId> {
-        let (ProgramW(program), PayloadInputW(payload), value, DelayW(delay), GasLimitW(gas_limit), _) = self.fields;
-        send_input_with_gas_delayed(program, gas_limit, value.into().0, payload, delay)
+        let (ProgramW(program), PayloadInputW(payload), value, DelayW(delay), gas_limit, _) = self.fields;
+        ExecuteLog::new("send_input_with_gas_delayed", self.quiet).run(|log| { let value = log.value(validate_value(value.into().0)?); let payload = log.payload_input(validate_payload_input(payload)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_input_with_gas_delayed(log.destination(program), gas_limit, value, payload, log.delay(delay))?) })
     }
 }
 
@@ -205,15 +429,72 @@ impl<Range: RangeBounds<usize>, Value: Into<ValueW>> SendBuilder<(ProgramW, Payl
 // SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, ReplyDeposit, Decodable)>
 // ---------------------------------------------------------------------------------------------- //
 
-pub struct SendBuilderForReply<Fields = ((), (), (), (), (), ())> {
+pub struct SendBuilderForReply<Fields = ((), (), (), (), (), ()), PayloadCodec = ()> {
     fields: Fields,
+    quiet: bool,
+    codec: PhantomData<PayloadCodec>,
+}
+
+impl<Fields, PayloadCodec> SendBuilderForReply<Fields, PayloadCodec> {
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+}
+
+impl<Encodable: Encode, Value, GasLimit, ReservationId, ReplyDeposit, Decodable> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, GasLimit, ReservationId, ReplyDeposit, Decodable)> {
+    pub fn encoded_size(&self) -> usize {
+        self.fields.1.0.encoded_size()
+    }
 }
 
-impl<Program, Payload, Value, GasLimit, ReservationId, Decodable> SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, (), Decodable)> {
-    pub fn with_reply_deposit(self, reply_deposit: u64) -> SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, ReplyDepositW, Decodable)> {
+impl<Program, Payload, Value, GasLimit, ReservationId, Decodable, PayloadCodec> SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, (), Decodable), PayloadCodec> {
+    #[allow(clippy::type_complexity)]
+    pub fn with_reply_deposit(self, reply_deposit: u64) -> SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, ReplyDepositW, Decodable), PayloadCodec> {
         let (program, payload, value, gas_limit, reservation_id, _, decodable) = self.fields;
         SendBuilderForReply {
             fields: (program, payload, value, gas_limit, reservation_id, ReplyDepositW(reply_deposit), decodable),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+}
+
+impl<Program, Payload, Value, GasLimit, ReservationId, PayloadCodec> SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, (), ()), PayloadCodec> {
+    #[allow(clippy::type_complexity)]
+    pub fn with_reply_deposit_auto(self) -> SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, ReplyDepositW, ()), PayloadCodec> {
+        let (program, payload, value, gas_limit, reservation_id, _, decodable) = self.fields;
+        SendBuilderForReply {
+            fields: (program, payload, value, gas_limit, reservation_id, ReplyDepositW::auto(0), decodable),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+}
+
+impl<Program, Payload, Value, GasLimit, ReservationId, Decodable: Decode + MaxEncodedLen> SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, (), DecodableW<Decodable>)> {
+    #[allow(clippy::type_complexity)]
+    pub fn with_reply_deposit_auto(self) -> SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, ReplyDepositW, DecodableW<Decodable>)> {
+        let (program, payload, value, gas_limit, reservation_id, _, decodable) = self.fields;
+        SendBuilderForReply {
+            fields: (program, payload, value, gas_limit, reservation_id, ReplyDepositW::auto(Decodable::max_encoded_len()), decodable),
+            quiet: self.quiet,
+            codec: PhantomData,
+        }
+    }
+}
+
+impl<Program, Encodable, Value, GasLimit, ReservationId, Decodable, PayloadCodec: Codec + Encoder<Encodable>> SendBuilderForReply<(Program, PayloadEncodableW<Encodable>, Value, GasLimit, ReservationId, (), DecodableW<Decodable>), PayloadCodec> {
+    #[allow(clippy::type_complexity)]
+    pub fn with_reply_deposit_auto(self) -> SendBuilderForReply<(Program, PayloadEncodableW<Encodable>, Value, GasLimit, ReservationId, ReplyDepositW, DecodableW<Decodable>), PayloadCodec> {
+        // A codec can't tell the maximum size of the reply, so the deposit is sized from the
+        // encoded payload. `execute()` fails with the same error if it can't be encoded.
+        let reply_size = PayloadCodec::encode(&self.fields.1.0).map_or(0, |payload| payload.len());
+        let (program, payload, value, gas_limit, reservation_id, _, decodable) = self.fields;
+        SendBuilderForReply {
+            fields: (program, payload, value, gas_limit, reservation_id, ReplyDepositW::auto(reply_size), decodable),
+            quiet: self.quiet,
+            codec: PhantomData,
         }
     }
 }
@@ -221,111 +502,228 @@ impl<Program, Payload, Value, GasLimit, ReservationId, Decodable> SendBuilderFor
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, (), (), ReplyDeposit, ())> {
     pub fn execute(self) -> Result<MessageFuture> {
         let (ProgramW(program), PayloadBytesW(payload), value, _, _, reply_deposit, _) = self.fields;
-        send_bytes_for_reply(program, payload, value.into().0, reply_deposit.into().0)
+        ExecuteLog::new("send_bytes_for_reply", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_for_reply(log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?))
     }
 }
 
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, (), ReservationIdW, ReplyDeposit, ())> {
     pub fn execute(self) -> Result<MessageFuture> {
         let (ProgramW(program), PayloadBytesW(payload), value, _, ReservationIdW(reservation_id), reply_deposit, _) = self.fields;
-        send_bytes_from_reservation_for_reply(reservation_id, program, payload, value.into().0, reply_deposit.into().0)
+        ExecuteLog::new("send_bytes_from_reservation_for_reply", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_from_reservation_for_reply(log.reservation_id(reservation_id), log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?))
     }
 }
 
-impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, GasLimitW, (), ReplyDeposit, ())> {
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, GasLimit, (), ReplyDeposit, ())> {
     pub fn execute(self) -> Result<MessageFuture> {
-        let (ProgramW(program), PayloadBytesW(payload), value, GasLimitW(gas_limit), _, reply_deposit, _) = self.fields;
-        send_bytes_with_gas_for_reply(program, payload, gas_limit, value.into().0, reply_deposit.into().0)
+        let (ProgramW(program), PayloadBytesW(payload), value, gas_limit, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_with_gas_for_reply", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_bytes_with_gas_for_reply(log.destination(program), payload, gas_limit, value, log.reply_deposit(reply_deposit.into().0))?) })
     }
 }
 
 impl<Encodable: Encode, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, (), (), ReplyDeposit, ())> {
     pub fn execute(self) -> Result<MessageFuture> {
         let (ProgramW(program), PayloadEncodableW(payload), value, _, _, reply_deposit, _) = self.fields;
-        send_for_reply(program, payload, value.into().0, reply_deposit.into().0)
+        ExecuteLog::new("send_for_reply", self.quiet).run(|log| Ok(ActiveBackend::send_for_reply(log.destination(program), log.payload_encodable(validate_payload_encodable(payload)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?))
     }
 }
 
 impl<Encodable: Encode, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, (), ReservationIdW, ReplyDeposit, ())> {
     pub fn execute(self) -> Result<MessageFuture> {
         let (ProgramW(program), PayloadEncodableW(payload), value, _, ReservationIdW(reservation_id), reply_deposit, _) = self.fields;
-        send_from_reservation_for_reply(reservation_id, program, payload, value.into().0, reply_deposit.into().0)
+        ExecuteLog::new("send_from_reservation_for_reply", self.quiet).run(|log| Ok(ActiveBackend::send_from_reservation_for_reply(log.reservation_id(reservation_id), log.destination(program), log.payload_encodable(validate_payload_encodable(payload)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?))
     }
 }
 
-impl<Encodable: Encode, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, GasLimitW, (), ReplyDeposit, ())> {
+impl<Encodable: Encode, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, GasLimit, (), ReplyDeposit, ())> {
     pub fn execute(self) -> Result<MessageFuture> {
-        let (ProgramW(program), PayloadEncodableW(payload), value, GasLimitW(gas_limit), _, reply_deposit, _) = self.fields;
-        send_with_gas_for_reply(program, payload, gas_limit, value.into().0, reply_deposit.into().0)
+        let (ProgramW(program), PayloadEncodableW(payload), value, gas_limit, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_with_gas_for_reply", self.quiet).run(|log| { let payload = log.payload_encodable(validate_payload_encodable(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_with_gas_for_reply(log.destination(program), payload, gas_limit, value, log.reply_deposit(reply_deposit.into().0))?) })
     }
 }
 
 impl<Range: RangeBounds<usize>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>> SendBuilderForReply<(ProgramW, PayloadInputW<Range>, Value, (), (), ReplyDeposit, ())> {
     pub fn execute(self) -> Result<MessageFuture> {
         let (ProgramW(program), PayloadInputW(payload), value, _, _, reply_deposit, _) = self.fields;
-        send_input_for_reply(program, value.into().0, payload, reply_deposit.into().0)
+        ExecuteLog::new("send_input_for_reply", self.quiet).run(|log| Ok(ActiveBackend::send_input_for_reply(log.destination(program), log.value(validate_value(value.into().0)?), log.payload_input(validate_payload_input(payload)?), log.reply_deposit(reply_deposit.into().0))?))
     }
 }
 
-impl<Range: RangeBounds<usize>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>> SendBuilderForReply<(ProgramW, PayloadInputW<Range>, Value, GasLimitW, (), ReplyDeposit, ())> {
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>> SendBuilderForReply<(ProgramW, PayloadInputW<Range>, Value, GasLimit, (), ReplyDeposit, ())> {
     pub fn execute(self) -> Result<MessageFuture> {
-        let (ProgramW(program), PayloadInputW(payload), value, GasLimitW(gas_limit), _, reply_deposit, _) = self.fields;
-        send_input_with_gas_for_reply(program, gas_limit, value.into().0, payload, reply_deposit.into().0)
+        let (ProgramW(program), PayloadInputW(payload), value, gas_limit, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_input_with_gas_for_reply", self.quiet).run(|log| { let value = log.value(validate_value(value.into().0)?); let payload = log.payload_input(validate_payload_input(payload)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_input_with_gas_for_reply(log.destination(program), gas_limit, value, payload, log.reply_deposit(reply_deposit.into().0))?) })
     }
 }
 
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, (), (), ReplyDeposit, DecodableW<Decodable>)> {
     pub fn execute(self) -> Result<CodecMessageFuture<Decodable>> {
         let (ProgramW(program), PayloadBytesW(payload), value, _, _, reply_deposit, _) = self.fields;
-        send_bytes_for_reply_as(program, payload, value.into().0, reply_deposit.into().0)
+        ExecuteLog::new("send_bytes_for_reply_as", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_for_reply_as(log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?))
     }
 }
 
 impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, (), ReservationIdW, ReplyDeposit, DecodableW<Decodable>)> {
     pub fn execute(self) -> Result<CodecMessageFuture<Decodable>> {
         let (ProgramW(program), PayloadBytesW(payload), value, _, ReservationIdW(reservation_id), reply_deposit, _) = self.fields;
-        send_bytes_from_reservation_for_reply_as(reservation_id, program, payload, value.into().0, reply_deposit.into().0)
+        ExecuteLog::new("send_bytes_from_reservation_for_reply_as", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_from_reservation_for_reply_as(log.reservation_id(reservation_id), log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?))
     }
 }
 
-impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, GasLimitW, (), ReplyDeposit, DecodableW<Decodable>)> {
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, GasLimit, (), ReplyDeposit, DecodableW<Decodable>)> {
     pub fn execute(self) -> Result<CodecMessageFuture<Decodable>> {
-        let (ProgramW(program), PayloadBytesW(payload), value, GasLimitW(gas_limit), _, reply_deposit, _) = self.fields;
-        send_bytes_with_gas_for_reply_as(program, payload, gas_limit, value.into().0, reply_deposit.into().0)
+        let (ProgramW(program), PayloadBytesW(payload), value, gas_limit, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_with_gas_for_reply_as", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_bytes_with_gas_for_reply_as(log.destination(program), payload, gas_limit, value, log.reply_deposit(reply_deposit.into().0))?) })
     }
 }
 
 impl<Encodable: Encode, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, (), (), ReplyDeposit, DecodableW<Decodable>)> {
     pub fn execute(self) -> Result<CodecMessageFuture<Decodable>> {
         let (ProgramW(program), PayloadEncodableW(payload), value, _, _, reply_deposit, _) = self.fields;
-        send_for_reply_as(program, payload, value.into().0, reply_deposit.into().0)
+        ExecuteLog::new("send_for_reply_as", self.quiet).run(|log| Ok(ActiveBackend::send_for_reply_as(log.destination(program), log.payload_encodable(validate_payload_encodable(payload)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?))
     }
 }
 
 impl<Encodable: Encode, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, (), ReservationIdW, ReplyDeposit, DecodableW<Decodable>)> {
     pub fn execute(self) -> Result<CodecMessageFuture<Decodable>> {
         let (ProgramW(program), PayloadEncodableW(payload), value, _, ReservationIdW(reservation_id), reply_deposit, _) = self.fields;
-        send_from_reservation_for_reply_as(reservation_id, program, payload, value.into().0, reply_deposit.into().0)
+        ExecuteLog::new("send_from_reservation_for_reply_as", self.quiet).run(|log| Ok(ActiveBackend::send_from_reservation_for_reply_as(log.reservation_id(reservation_id), log.destination(program), log.payload_encodable(validate_payload_encodable(payload)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?))
     }
 }
 
-impl<Encodable: Encode, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, GasLimitW, (), ReplyDeposit, DecodableW<Decodable>)> {
+impl<Encodable: Encode, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, GasLimit, (), ReplyDeposit, DecodableW<Decodable>)> {
     pub fn execute(self) -> Result<CodecMessageFuture<Decodable>> {
-        let (ProgramW(program), PayloadEncodableW(payload), value, GasLimitW(gas_limit), _, reply_deposit, _) = self.fields;
-        send_with_gas_for_reply_as(program, payload, gas_limit, value.into().0, reply_deposit.into().0)
+        let (ProgramW(program), PayloadEncodableW(payload), value, gas_limit, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_with_gas_for_reply_as", self.quiet).run(|log| { let payload = log.payload_encodable(validate_payload_encodable(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_with_gas_for_reply_as(log.destination(program), payload, gas_limit, value, log.reply_deposit(reply_deposit.into().0))?) })
     }
 }
 
 impl<Range: RangeBounds<usize>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> SendBuilderForReply<(ProgramW, PayloadInputW<Range>, Value, (), (), ReplyDeposit, DecodableW<Decodable>)> {
     pub fn execute(self) -> Result<CodecMessageFuture<Decodable>> {
         let (ProgramW(program), PayloadInputW(payload), value, _, _, reply_deposit, _) = self.fields;
-        send_input_for_reply_as(program, value.into().0, payload, reply_deposit.into().0)
+        ExecuteLog::new("send_input_for_reply_as", self.quiet).run(|log| Ok(ActiveBackend::send_input_for_reply_as(log.destination(program), log.value(validate_value(value.into().0)?), log.payload_input(validate_payload_input(payload)?), log.reply_deposit(reply_deposit.into().0))?))
     }
 }
 
-impl<Range: RangeBounds<usize>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> SendBuilderForReply<(ProgramW, PayloadInputW<Range>, Value, GasLimitW, (), ReplyDeposit, DecodableW<Decodable>)> {
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>, Decodable: Decode> SendBuilderForReply<(ProgramW, PayloadInputW<Range>, Value, GasLimit, (), ReplyDeposit, DecodableW<Decodable>)> {
     pub fn execute(self) -> Result<CodecMessageFuture<Decodable>> {
-        let (ProgramW(program), PayloadInputW(payload), value, GasLimitW(gas_limit), _, reply_deposit, _) = self.fields;
-        send_input_with_gas_for_reply_as(program, gas_limit, value.into().0, payload, reply_deposit.into().0)
+        let (ProgramW(program), PayloadInputW(payload), value, gas_limit, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_input_with_gas_for_reply_as", self.quiet).run(|log| { let value = log.value(validate_value(value.into().0)?); let payload = log.payload_input(validate_payload_input(payload)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_input_with_gas_for_reply_as(log.destination(program), gas_limit, value, payload, log.reply_deposit(reply_deposit.into().0))?) })
+    }
+}
+
+// ---------------------------------------------------------------------------------------------- //
+// bindings for `send*`: for_reply(), for_reply_as::<Decodable>() with `.with_codec::<PayloadCodec>()`
+// SendBuilderForReply<(Program, Payload, Value, GasLimit, ReservationId, ReplyDeposit, Decodable), PayloadCodec>
+// ---------------------------------------------------------------------------------------------- //
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, PayloadCodec: Codec> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, (), (), ReplyDeposit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageFuture> {
+        let (ProgramW(program), PayloadBytesW(payload), value, _, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_for_reply", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_for_reply(log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, PayloadCodec: Codec> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, (), ReservationIdW, ReplyDeposit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageFuture> {
+        let (ProgramW(program), PayloadBytesW(payload), value, _, ReservationIdW(reservation_id), reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_from_reservation_for_reply", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_from_reservation_for_reply(log.reservation_id(reservation_id), log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>, PayloadCodec: Codec> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, GasLimit, (), ReplyDeposit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageFuture> {
+        let (ProgramW(program), PayloadBytesW(payload), value, gas_limit, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_with_gas_for_reply", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_bytes_with_gas_for_reply(log.destination(program), payload, gas_limit, value, log.reply_deposit(reply_deposit.into().0))?) })
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, PayloadCodec: Encoder<Encodable>> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, (), (), ReplyDeposit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageFuture> {
+        let (ProgramW(program), PayloadEncodableW(payload), value, _, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_for_reply", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_for_reply(log.destination(program), log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?))
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, PayloadCodec: Encoder<Encodable>> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, (), ReservationIdW, ReplyDeposit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageFuture> {
+        let (ProgramW(program), PayloadEncodableW(payload), value, _, ReservationIdW(reservation_id), reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_from_reservation_for_reply", self.quiet).run(|log| Ok(ActiveBackend::send_bytes_from_reservation_for_reply(log.reservation_id(reservation_id), log.destination(program), log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?))
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>, PayloadCodec: Encoder<Encodable>> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, GasLimit, (), ReplyDeposit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageFuture> {
+        let (ProgramW(program), PayloadEncodableW(payload), value, gas_limit, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_with_gas_for_reply", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_bytes_with_gas_for_reply(log.destination(program), payload, gas_limit, value, log.reply_deposit(reply_deposit.into().0))?) })
+    }
+}
+
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, PayloadCodec: Codec> SendBuilderForReply<(ProgramW, PayloadInputW<Range>, Value, (), (), ReplyDeposit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageFuture> {
+        let (ProgramW(program), PayloadInputW(payload), value, _, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_input_for_reply", self.quiet).run(|log| Ok(ActiveBackend::send_input_for_reply(log.destination(program), log.value(validate_value(value.into().0)?), log.payload_input(validate_payload_input(payload)?), log.reply_deposit(reply_deposit.into().0))?))
+    }
+}
+
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>, PayloadCodec: Codec> SendBuilderForReply<(ProgramW, PayloadInputW<Range>, Value, GasLimit, (), ReplyDeposit, ()), PayloadCodec> {
+    pub fn execute(self) -> Result<MessageFuture> {
+        let (ProgramW(program), PayloadInputW(payload), value, gas_limit, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_input_with_gas_for_reply", self.quiet).run(|log| { let value = log.value(validate_value(value.into().0)?); let payload = log.payload_input(validate_payload_input(payload)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(ActiveBackend::send_input_with_gas_for_reply(log.destination(program), gas_limit, value, payload, log.reply_deposit(reply_deposit.into().0))?) })
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable, PayloadCodec: Decoder<Decodable>> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, (), (), ReplyDeposit, DecodableW<Decodable>), PayloadCodec> {
+    pub fn execute(self) -> Result<CodecReplyFuture<MessageFuture, Decodable, PayloadCodec>> {
+        let (ProgramW(program), PayloadBytesW(payload), value, _, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_for_reply", self.quiet).run(|log| Ok(CodecReplyFuture::new(ActiveBackend::send_bytes_for_reply(log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?)))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable, PayloadCodec: Decoder<Decodable>> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, (), ReservationIdW, ReplyDeposit, DecodableW<Decodable>), PayloadCodec> {
+    pub fn execute(self) -> Result<CodecReplyFuture<MessageFuture, Decodable, PayloadCodec>> {
+        let (ProgramW(program), PayloadBytesW(payload), value, _, ReservationIdW(reservation_id), reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_from_reservation_for_reply", self.quiet).run(|log| Ok(CodecReplyFuture::new(ActiveBackend::send_bytes_from_reservation_for_reply(log.reservation_id(reservation_id), log.destination(program), log.payload_bytes(validate_payload_bytes(payload)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?)))
+    }
+}
+
+impl<Buffer: AsRef<[u8]>, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>, Decodable, PayloadCodec: Decoder<Decodable>> SendBuilderForReply<(ProgramW, PayloadBytesW<Buffer>, Value, GasLimit, (), ReplyDeposit, DecodableW<Decodable>), PayloadCodec> {
+    pub fn execute(self) -> Result<CodecReplyFuture<MessageFuture, Decodable, PayloadCodec>> {
+        let (ProgramW(program), PayloadBytesW(payload), value, gas_limit, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_with_gas_for_reply", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(payload)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(CodecReplyFuture::new(ActiveBackend::send_bytes_with_gas_for_reply(log.destination(program), payload, gas_limit, value, log.reply_deposit(reply_deposit.into().0))?)) })
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable, PayloadCodec: Encoder<Encodable> + Decoder<Decodable>> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, (), (), ReplyDeposit, DecodableW<Decodable>), PayloadCodec> {
+    pub fn execute(self) -> Result<CodecReplyFuture<MessageFuture, Decodable, PayloadCodec>> {
+        let (ProgramW(program), PayloadEncodableW(payload), value, _, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_for_reply", self.quiet).run(|log| Ok(CodecReplyFuture::new(ActiveBackend::send_bytes_for_reply(log.destination(program), log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?)))
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable, PayloadCodec: Encoder<Encodable> + Decoder<Decodable>> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, (), ReservationIdW, ReplyDeposit, DecodableW<Decodable>), PayloadCodec> {
+    pub fn execute(self) -> Result<CodecReplyFuture<MessageFuture, Decodable, PayloadCodec>> {
+        let (ProgramW(program), PayloadEncodableW(payload), value, _, ReservationIdW(reservation_id), reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_from_reservation_for_reply", self.quiet).run(|log| Ok(CodecReplyFuture::new(ActiveBackend::send_bytes_from_reservation_for_reply(log.reservation_id(reservation_id), log.destination(program), log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?), log.value(validate_value(value.into().0)?), log.reply_deposit(reply_deposit.into().0))?)))
+    }
+}
+
+impl<Encodable, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>, Decodable, PayloadCodec: Encoder<Encodable> + Decoder<Decodable>> SendBuilderForReply<(ProgramW, PayloadEncodableW<Encodable>, Value, GasLimit, (), ReplyDeposit, DecodableW<Decodable>), PayloadCodec> {
+    pub fn execute(self) -> Result<CodecReplyFuture<MessageFuture, Decodable, PayloadCodec>> {
+        let (ProgramW(program), PayloadEncodableW(payload), value, gas_limit, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_bytes_with_gas_for_reply", self.quiet).run(|log| { let payload = log.payload_bytes(validate_payload_bytes(PayloadCodec::encode(&payload)?)?); let value = log.value(validate_value(value.into().0)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(CodecReplyFuture::new(ActiveBackend::send_bytes_with_gas_for_reply(log.destination(program), payload, gas_limit, value, log.reply_deposit(reply_deposit.into().0))?)) })
+    }
+}
+
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, ReplyDeposit: Into<ReplyDepositW>, Decodable, PayloadCodec: Decoder<Decodable>> SendBuilderForReply<(ProgramW, PayloadInputW<Range>, Value, (), (), ReplyDeposit, DecodableW<Decodable>), PayloadCodec> {
+    pub fn execute(self) -> Result<CodecReplyFuture<MessageFuture, Decodable, PayloadCodec>> {
+        let (ProgramW(program), PayloadInputW(payload), value, _, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_input_for_reply", self.quiet).run(|log| Ok(CodecReplyFuture::new(ActiveBackend::send_input_for_reply(log.destination(program), log.value(validate_value(value.into().0)?), log.payload_input(validate_payload_input(payload)?), log.reply_deposit(reply_deposit.into().0))?)))
+    }
+}
+
+impl<Range: RangeBounds<usize>, Value: Into<ValueW>, GasLimit: IntoGasLimit, ReplyDeposit: Into<ReplyDepositW>, Decodable, PayloadCodec: Decoder<Decodable>> SendBuilderForReply<(ProgramW, PayloadInputW<Range>, Value, GasLimit, (), ReplyDeposit, DecodableW<Decodable>), PayloadCodec> {
+    pub fn execute(self) -> Result<CodecReplyFuture<MessageFuture, Decodable, PayloadCodec>> {
+        let (ProgramW(program), PayloadInputW(payload), value, gas_limit, _, reply_deposit, _) = self.fields;
+        ExecuteLog::new("send_input_with_gas_for_reply", self.quiet).run(|log| { let value = log.value(validate_value(value.into().0)?); let payload = log.payload_input(validate_payload_input(payload)?); let gas_limit = log.gas_limit(gas_limit.into_validated_gas_limit()?); Ok(CodecReplyFuture::new(ActiveBackend::send_input_with_gas_for_reply(log.destination(program), gas_limit, value, payload, log.reply_deposit(reply_deposit.into().0))?)) })
     }
 }
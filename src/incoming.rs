@@ -0,0 +1,147 @@
+//! A typed view of the message being processed.
+//!
+//! `builder::incoming()` reads the message through the active backend, so the same handler can be
+//! tested with the `mock` feature:
+//!
+//! ```ignore
+//! #[no_mangle]
+//! extern "C" fn handle() {
+//!     let action: Action = builder::incoming()
+//!         .require_source(owner)
+//!         .require_min_value(1_000)
+//!         .require_max_len(256)
+//!         .decode()
+//!         .expect("Invalid message");
+//! }
+//! ```
+//!
+//! The guards are checked in the order they are added, and the first one that fails is returned by
+//! `.decode()` or `.bytes()` as [`Error::Guard`] without loading the payload.
+
+use crate::{
+    access::{Access, Unauthorized},
+    backend::{ActiveBackend, Backend},
+    error_reply::ErrorReply,
+    errors::{Error, GuardError, Result},
+    functions::reply_result,
+};
+use gstd::{errors::ContractError, prelude::*, ActorId, Decode, MessageId};
+
+/// The message being processed, with the guards it must pass before it is loaded.
+#[must_use]
+pub struct Incoming {
+    error: Option<GuardError>,
+}
+
+impl Incoming {
+    pub(crate) fn new() -> Self {
+        Self { error: None }
+    }
+
+    /// Returns the id of the message.
+    pub fn id(&self) -> MessageId {
+        ActiveBackend::message_id()
+    }
+
+    /// Returns the sender of the message.
+    pub fn source(&self) -> ActorId {
+        ActiveBackend::message_source()
+    }
+
+    /// Returns the value attached to the message.
+    pub fn value(&self) -> u128 {
+        ActiveBackend::message_value()
+    }
+
+    /// Requires the message to be sent by `actor`.
+    pub fn require_source(self, actor: ActorId) -> Self {
+        self.guard(|| {
+            let source = ActiveBackend::message_source();
+            if source != actor {
+                return Err(GuardError::UnexpectedSource {
+                    expected: actor,
+                    actual: source,
+                });
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Requires the value attached to the message to be at least `min`.
+    pub fn require_min_value(self, min: u128) -> Self {
+        self.guard(|| {
+            let value = ActiveBackend::message_value();
+            if value < min {
+                return Err(GuardError::ValueBelowMinimum { value, min });
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Requires the payload to be at most `max` bytes long.
+    pub fn require_max_len(self, max: usize) -> Self {
+        self.guard(|| {
+            let len = ActiveBackend::message_size();
+            if len > max {
+                return Err(GuardError::PayloadTooLong { len, max });
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Requires the message to be sent by the owner in `access`.
+    pub fn require_owner<Role: Ord>(self, access: &Access<Role>) -> Self {
+        self.guard(|| {
+            access
+                .check_owner(ActiveBackend::message_source())
+                .map_err(|Unauthorized { actor }| GuardError::Unauthorized { actor })
+        })
+    }
+
+    /// Requires the message to be sent by the owner in `access` or an actor with the role.
+    pub fn require_role<Role: Ord>(self, access: &Access<Role>, role: Role) -> Self {
+        self.guard(|| {
+            access
+                .check_role(ActiveBackend::message_source(), &role)
+                .map_err(|Unauthorized { actor }| GuardError::Unauthorized { actor })
+        })
+    }
+
+    /// If `.require_owner(...)` or `.require_role(...)` failed, replies with `Err(ErrorReply)`
+    /// converted from [`Unauthorized`] and returns the guard error. Other guards are checked by
+    /// `.decode()` or `.bytes()`.
+    pub fn or_reply_unauthorized(self) -> Result<Self> {
+        let Some(GuardError::Unauthorized { actor }) = self.error else {
+            return Ok(self);
+        };
+
+        reply_result(Err::<(), ErrorReply>(Unauthorized { actor }.into())).execute()?;
+        Err(Error::Guard(GuardError::Unauthorized { actor }))
+    }
+
+    /// Checks the guards and decodes the payload with SCALE.
+    pub fn decode<Decodable: Decode>(self) -> Result<Decodable> {
+        let bytes = self.bytes()?;
+        Decodable::decode(&mut bytes.as_slice()).map_err(|err| ContractError::Decode(err).into())
+    }
+
+    /// Checks the guards and loads the payload.
+    pub fn bytes(self) -> Result<Vec<u8>> {
+        if let Some(err) = self.error {
+            return Err(Error::Guard(err));
+        }
+
+        Ok(ActiveBackend::load_bytes()?)
+    }
+
+    fn guard(mut self, check: impl FnOnce() -> Result<(), GuardError>) -> Self {
+        if self.error.is_none() {
+            self.error = check().err();
+        }
+
+        self
+    }
+}
@@ -71,16 +71,26 @@
 //!         // Possible methods (stage 1):
 //!             .with_value(1) // Value is 0 by default
 //!             .with_delay(2) // Delay in block count
-//!             // You can use `.with_gas_limit(...)` or `.with_gas_from_reservation(...)`
-//!             // Both are not supported yet
+//!             // You can use one of `.with_gas_limit(...)`, `.with_gas_fraction(...)`,
+//!             // `.with_gas_remaining_minus(...)`, `.with_all_remaining_gas()`,
+//!             // `.with_gas_from_budget(...)` or `.with_gas_from_reservation(...)`
 //!             .with_gas_limit(3) // Explicit gas limit
+//!             .with_gas_fraction(1, 2) // Half of `exec::gas_available()` at execution time
+//!             .with_gas_remaining_minus(4) // `exec::gas_available()` minus 4 at execution time
+//!             .with_all_remaining_gas() // `exec::gas_available()` at execution time
+//!             .with_gas_from_budget(&mut budget, 1) // Weighted share of a `GasBudget`
 //!             .with_gas_from_reservation(ReservationId::from([0; 32])) // Gas from reservation
 //!         // Possible methods (stage 2, called after stage 1):
 //!             // You can use `.for_reply()` or `.for_reply_as::<T>()`
 //!             // Both are not supported
 //!             .for_reply() // Wait for reply (output - bytes)
 //!             .for_reply_as::<T>() // Wait for reply (output - T)
+//!             .for_reply_as_compressed::<T>() // Wait for a compressed reply (output - T)
+//!             .for_reply_result::<T, E>() // Wait for a `reply_result` (output - Result<T, E>)
 //!                 .with_reply_deposit(1) // Reply deposit is 0 by default
+//!                 .with_reply_deposit_auto() // Reply deposit from the cost model in `config`
+//!         .compressed() // Compresses the encoded payload, enabled by the `compression` feature
+//!         .quiet() // Skips the log of `.execute()` enabled by the `debug` feature
 //!         .execute()
 //!         // ^ Don't forget about `.execute()`
 //!         .expect("failed to send msg");
@@ -105,19 +115,38 @@
 //!         .expect("failed to get output");
 //! }
 //!
+//! // The payload and the reply are encoded with a codec from `gstd_fluent::codec` instead of SCALE.
+//! // Also available as `.with_codec::<Json>()` on the `send`, `reply` and `create_program` builders
+//! async fn how_to_use_send_with() {
+//!     let output: Output = builder::send_with::<Json, _>(ActorId::zero(), Input { a: 1 })
+//!         .for_reply_as::<Output>()
+//!         .execute()
+//!         .expect("failed to send msg")
+//!         .await
+//!         .expect("failed to get output");
+//! }
+//!
 //! // This is pseudo-code to show all possible methods
 //! fn how_to_use_reply() {
 //!     // Supported functions:
 //!     // - `builder::reply(...)`
 //!     // - `builder::reply_bytes(...)`
 //!     // - `builder::reply_input(...)`
+//!     // - `builder::reply_result(...)`
 //!     builder::reply(String::from("payload"))
 //!         // Possible methods:
 //!             .with_value(1) // Value is 0 by default
-//!             // You can use `.with_gas_limit(...)` or `.with_gas_from_reservation(...)`
-//!             // Both are not supported yet
+//!             // You can use one of `.with_gas_limit(...)`, `.with_gas_fraction(...)`,
+//!             // `.with_gas_remaining_minus(...)`, `.with_all_remaining_gas()`,
+//!             // `.with_gas_from_budget(...)` or `.with_gas_from_reservation(...)`
 //!             .with_gas_limit(3) // Explicit gas limit
+//!             .with_gas_fraction(1, 2) // Half of `exec::gas_available()` at execution time
+//!             .with_gas_remaining_minus(4) // `exec::gas_available()` minus 4 at execution time
+//!             .with_all_remaining_gas() // `exec::gas_available()` at execution time
+//!             .with_gas_from_budget(&mut budget, 1) // Weighted share of a `GasBudget`
 //!             .with_gas_from_reservation(ReservationId::from([0; 32])) // Gas from reservation
+//!         .compressed() // Compresses the encoded payload, enabled by the `compression` feature
+//!         .quiet() // Skips the log of `.execute()` enabled by the `debug` feature
 //!         .execute()
 //!         // ^ Don't forget about `.execute()`
 //!         .expect("failed to reply");
@@ -129,25 +158,196 @@
 //!         // Possible methods (stage 1):
 //!             .with_value(1)
 //!             .with_delay(2)
+//!             // You can use one of `.with_gas_limit(...)`, `.with_gas_fraction(...)`,
+//!             // `.with_gas_remaining_minus(...)`, `.with_all_remaining_gas()`
+//!             // or `.with_gas_from_budget(...)`
 //!             .with_gas_limit(3)
+//!             .with_gas_fraction(1, 2)
+//!             .with_gas_remaining_minus(4)
+//!             .with_all_remaining_gas()
+//!             .with_gas_from_budget(&mut budget, 1)
 //!         // Possible methods (stage 2, called after stage 1):
 //!             // You can use `.for_reply()` or `.for_reply_as::<T>()`
 //!             // Both are not supported
-//!             .for_reply() // Wait for reply (output - bytes)
-//!             .for_reply_as::<T>() // Wait for reply (output - T)
+//!             .for_reply() // Wait for reply (output - `Created<bytes>`)
+//!             .for_reply_as::<T>() // Wait for reply (output - `Created<T>`)
 //!                 .with_reply_deposit(1) // Reply deposit is 0 by default
+//!                 .with_reply_deposit_auto() // Reply deposit from the cost model in `config`
+//!         .quiet() // Skips the log of `.execute()` enabled by the `debug` feature
 //!         .execute()
 //!         // ^ Don't forget about `.execute()`
 //!         .expect("failed to create program");
+//!     // A failed init message is `Error::InitFailed`, a program that was not created is
+//!     // `Error::CreationRejected`
+//! }
+//!
+//! // This is pseudo-code to show all possible methods
+//! fn how_to_use_incoming() {
+//!     let action: Action = builder::incoming()
+//!         // Possible guards, checked in order:
+//!             .require_source(ActorId::zero()) // Sent by this actor
+//!             .require_min_value(1) // With at least this value attached
+//!             .require_max_len(2) // With at most this many payload bytes
+//!             .require_owner(&access) // Sent by the owner in an `Access`
+//!             .require_role(&access, Role::Admin) // Sent by the owner or an actor with the role
+//!         .or_reply_unauthorized()? // Replies with `Err(ErrorReply)` with code 401 if denied
+//!         // Possible outputs:
+//!             .decode() // Decode the payload (output - T)
+//!             .bytes() // Load the payload (output - bytes)
+//!         .expect("invalid message");
+//!     // `.id()`, `.source()` and `.value()` return the message fields without checking the guards
+//! }
+//!
+//! // This is pseudo-code to show all possible methods
+//! fn how_to_use_transfer() {
+//!     builder::transfer(ActorId::zero(), 1) // Sends an empty message with the value
+//!         // Possible methods:
+//!             .with_delay(2) // Delay in block count
+//!             // You can use `.with_gas_limit(...)` or `.with_gas_from_reservation(...)`
+//!             .with_gas_limit(3)
+//!             .with_gas_from_reservation(ReservationId::from([0; 32]))
+//!             .keep_alive() // Leaves at least the existential deposit from `config`
+//!         .execute()
+//!         .expect("failed to transfer value");
+//! }
+//!
+//! // This is pseudo-code to show all possible methods
+//! fn how_to_use_exit() {
+//!     builder::exit()
+//!         .with_inheritor(ActorId::zero()) // Receives the value left on the program
+//!         // Possible methods:
+//!             .after_notifying([ActorId::zero()], String::from("bye")) // Sent before exiting
+//!         .execute()
+//!         // ^ Calls `exec::exit(inheritor)`, only returns if a message can't be sent
+//!         .expect("failed to exit");
+//! }
+//!
+//! // This is pseudo-code to show all possible methods
+//! fn how_to_use_wait() {
+//!     builder::wait()
+//!         // You can use `.for_blocks(...)` or `.up_to(...)`, by default waits until woken
+//!             .for_blocks(1) // Same as `exec::wait_for(1)`
+//!             .up_to(2) // Same as `exec::wait_up_to(2)`
+//!         .execute(); // Never returns
+//!
+//!     builder::wake(MessageId::default())
+//!         // Possible methods:
+//!             .with_delay(3) // Same as `exec::wake_delayed(message_id, 3)`
+//!         .execute()
+//!         .expect("failed to wake");
+//! }
+//!
+//! // This is pseudo-code to show all possible methods
+//! fn how_to_use_emit() {
+//!     // Supported functions:
+//!     // - `builder::emit(...)`
+//!     // - `builder::emit_bytes(...)`
+//!     builder::emit(Event::Transferred { amount: 1 })
+//!         // Possible methods:
+//!             .with_topic(b"token") // Prepends the SCALE-encoded topic to the payload
+//!             .with_version(1) // Prepends the version byte, after the topic
+//!         .execute()
+//!         // ^ Sends the event to `ActorId::zero()` with zero value
+//!         .expect("failed to emit event");
+//! }
+//!
+//! // This is pseudo-code to show all possible methods
+//! fn how_to_use_scheduler(scheduler: &mut Scheduler) {
+//!     scheduler
+//!         .schedule(Action::Rebalance, 1) // Sends the payload to itself every block
+//!         // You can use `.with_gas_limit(...)` or `.with_gas_from_reservations(...)`
+//!             .with_gas_limit(2) // Explicit gas limit of every run
+//!             .with_gas_from_reservations([ReservationId::from([0; 32])]) // One per run
+//!         .execute()
+//!         // ^ Returns the id of the job with the same payload if there is one
+//!         .expect("failed to schedule");
+//!
+//!     scheduler.run().expect("failed to schedule the next run"); // Call it in `handle()`
+//!     // ^ Returns `Run::Last` for the run sent with the last reservation
+//!     scheduler.resume(0).expect("failed to schedule"); // Retries a run that failed to be sent
+//!     scheduler.cancel(0); // Its pending run is reported as `Run::Stale`
+//! }
+//!
+//! // This is pseudo-code to show all possible methods
+//! async fn how_to_use_factory() {
+//!     let mut factory = Factory::new(CodeId::from([0; 32]))
+//!         // Possible methods:
+//!             .with_value(1) // Value is 0 by default
+//!             .with_gas_limit(2); // Init messages are sent without a gas limit by default
+//!
+//!     factory
+//!         .spawn(String::from("init")) // Waits for the reply to the init message
+//!         .await
+//!         .expect("failed to spawn");
+//!     factory
+//!         .spawn_batch([String::from("first"), String::from("second")]) // Sends all, then waits
+//!         .await; // Returns the result of every init payload
+//!     factory.upgrade(CodeId::from([1; 32])); // The spawned programs are listed by `.outdated()`
+//! }
+//!
+//! // This is pseudo-code to show all possible methods
+//! async fn how_to_use_router() {
+//!     builder::router::<Action, Reply, Failure>()
+//!         // Handlers return `Result<Reply, Failure>`, the first matching route is used
+//!             .on(route!(Action::Add(a, b) => (a, b)), |(a, b)| add(a, b))
+//!             .on_async(route!(Action::Fetch(id) => id), |id| fetch(id)) // Handler returns a future
+//!         // Possible methods:
+//!             .with_reply_value(1) // Value is 0 by default
+//!             .with_reply_gas_limit(2) // The reply is sent without a gas limit by default
+//!         // You can use `.handle()` if there are no async handlers, or `.handle_async().await`
+//!         .handle_async()
+//!         .await
+//!         // ^ Replies with `Result<Reply, ErrorReply>`
+//!         .expect("failed to reply");
 //! }
 //! ```
 
 #![no_std]
 
+extern crate alloc;
+#[cfg(feature = "mock")]
+extern crate std;
+
 pub extern crate gstd;
 pub use functions::*;
+/// Turns a function returning `Result<Reply, Failure>` into the `handle()` entry point that
+/// replies with `builder::reply_result(...)`.
+///
+/// ```ignore
+/// #[gstd_fluent::handle]
+/// fn handle() -> Result<u64, ErrorReply> {
+///     let amount: u64 = builder::incoming().decode().map_err(|_| "Invalid amount")?;
+///     Ok(amount * 2)
+/// }
+/// ```
+///
+/// The crate path can be changed with `#[handle(crate = fluent)]`. Async functions are not
+/// supported, use `builder::router()` with `.on_async(...)` instead.
+pub use gstd_fluent_macros::handle;
 
 mod common;
 mod functions;
+mod log;
 
+pub mod access;
+pub mod backend;
+pub mod chunked;
+pub mod codec;
+#[cfg(feature = "compression")]
+pub mod compression;
+pub mod config;
+pub mod created;
+pub mod error_reply;
+pub mod errors;
+pub mod event;
+pub mod exit;
+pub mod factory;
+pub mod gas;
 pub mod generated;
+pub mod incoming;
+pub mod router;
+pub mod scheduler;
+#[cfg(feature = "trace")]
+pub mod trace;
+pub mod transfer;
+pub mod wait;
@@ -0,0 +1,246 @@
+//! Logging of the executed builders, enabled by the `debug` feature.
+//!
+//! The arguments passed to the `gstd` function are recorded by [`ExecuteLog`] on the way, and a
+//! single line is emitted with `gstd::debug!` once the function returns. Without the `debug`
+//! feature, the recording methods return their argument as is.
+
+use crate::errors::Result;
+use core::ops::RangeBounds;
+use gstd::{ActorId, CodeId, Encode, ReservationId};
+#[cfg(feature = "debug")]
+use {
+    crate::backend::WaitingReply,
+    core::{fmt::Write, ops::Bound},
+    gstd::{prelude::*, MessageId},
+};
+
+/// The value returned by `execute()` that identifies the sent message.
+#[cfg(feature = "debug")]
+pub(crate) trait ExecuteOutput {
+    fn message_id(&self) -> MessageId;
+}
+
+#[cfg(feature = "debug")]
+impl ExecuteOutput for MessageId {
+    fn message_id(&self) -> MessageId {
+        *self
+    }
+}
+
+#[cfg(feature = "debug")]
+impl ExecuteOutput for (MessageId, ActorId) {
+    fn message_id(&self) -> MessageId {
+        self.0
+    }
+}
+
+#[cfg(feature = "debug")]
+impl<Future: WaitingReply> ExecuteOutput for Future {
+    fn message_id(&self) -> MessageId {
+        self.waiting_reply_to()
+    }
+}
+
+/// The arguments of the `gstd` function called by `execute()`.
+#[cfg(feature = "debug")]
+pub(crate) struct ExecuteLog {
+    function: &'static str,
+    quiet: bool,
+    destination: Option<ActorId>,
+    code_id: Option<CodeId>,
+    value: Option<u128>,
+    gas_limit: Option<u64>,
+    reservation_id: Option<ReservationId>,
+    delay: Option<u32>,
+    reply_deposit: Option<u64>,
+    payload_len: Option<usize>,
+}
+
+#[cfg(feature = "debug")]
+impl ExecuteLog {
+    pub(crate) fn new(function: &'static str, quiet: bool) -> Self {
+        Self {
+            function,
+            quiet,
+            destination: None,
+            code_id: None,
+            value: None,
+            gas_limit: None,
+            reservation_id: None,
+            delay: None,
+            reply_deposit: None,
+            payload_len: None,
+        }
+    }
+
+    pub(crate) fn run<Output: ExecuteOutput>(
+        mut self,
+        execute: impl FnOnce(&mut Self) -> Result<Output>,
+    ) -> Result<Output> {
+        let result = execute(&mut self);
+        if !self.quiet {
+            self.emit(&result);
+        }
+        result
+    }
+
+    fn emit<Output: ExecuteOutput>(&self, result: &Result<Output>) {
+        let mut line = format!("gstd-fluent: {}", self.function);
+        // Writing to a `String` never fails.
+        if let Some(destination) = self.destination {
+            let _ = write!(line, " destination={destination:?}");
+        }
+        if let Some(code_id) = self.code_id {
+            let _ = write!(line, " code_id={code_id:?}");
+        }
+        if let Some(value) = self.value {
+            let _ = write!(line, " value={value}");
+        }
+        if let Some(gas_limit) = self.gas_limit {
+            let _ = write!(line, " gas_limit={gas_limit}");
+        }
+        if let Some(reservation_id) = self.reservation_id {
+            let _ = write!(line, " reservation_id={reservation_id:?}");
+        }
+        if let Some(delay) = self.delay {
+            let _ = write!(line, " delay={delay}");
+        }
+        if let Some(reply_deposit) = self.reply_deposit {
+            let _ = write!(line, " reply_deposit={reply_deposit}");
+        }
+        match self.payload_len {
+            Some(payload_len) => {
+                let _ = write!(line, " payload_len={payload_len}");
+            }
+            None => line.push_str(" payload_len=unknown"),
+        }
+        match result {
+            Ok(output) => {
+                let _ = write!(line, " message_id={:?}", output.message_id());
+            }
+            Err(err) => {
+                let _ = write!(line, " error=\"{err}\"");
+            }
+        }
+
+        gstd::debug!("{}", line);
+    }
+
+    pub(crate) fn destination(&mut self, destination: ActorId) -> ActorId {
+        self.destination = Some(destination);
+        destination
+    }
+
+    pub(crate) fn code_id(&mut self, code_id: CodeId) -> CodeId {
+        self.code_id = Some(code_id);
+        code_id
+    }
+
+    pub(crate) fn value(&mut self, value: u128) -> u128 {
+        self.value = Some(value);
+        value
+    }
+
+    pub(crate) fn gas_limit(&mut self, gas_limit: u64) -> u64 {
+        self.gas_limit = Some(gas_limit);
+        gas_limit
+    }
+
+    pub(crate) fn reservation_id(&mut self, reservation_id: ReservationId) -> ReservationId {
+        self.reservation_id = Some(reservation_id);
+        reservation_id
+    }
+
+    pub(crate) fn delay(&mut self, delay: u32) -> u32 {
+        self.delay = Some(delay);
+        delay
+    }
+
+    pub(crate) fn reply_deposit(&mut self, reply_deposit: u64) -> u64 {
+        self.reply_deposit = Some(reply_deposit);
+        reply_deposit
+    }
+
+    pub(crate) fn payload_bytes<Buffer: AsRef<[u8]>>(&mut self, payload: Buffer) -> Buffer {
+        self.payload_len = Some(payload.as_ref().len());
+        payload
+    }
+
+    pub(crate) fn payload_encodable<Encodable: Encode>(&mut self, payload: Encodable) -> Encodable {
+        self.payload_len = Some(payload.encoded_size());
+        payload
+    }
+
+    /// The length of the incoming message is unknown if the range is not bounded from above.
+    pub(crate) fn payload_input<Range: RangeBounds<usize>>(&mut self, payload: Range) -> Range {
+        let start = match payload.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        self.payload_len = match payload.end_bound() {
+            Bound::Included(&end) => Some(end.saturating_add(1).saturating_sub(start)),
+            Bound::Excluded(&end) => Some(end.saturating_sub(start)),
+            Bound::Unbounded => None,
+        };
+        payload
+    }
+}
+
+/// The arguments of the `gstd` function called by `execute()`.
+#[cfg(not(feature = "debug"))]
+pub(crate) struct ExecuteLog;
+
+#[cfg(not(feature = "debug"))]
+impl ExecuteLog {
+    pub(crate) fn new(_function: &'static str, _quiet: bool) -> Self {
+        Self
+    }
+
+    pub(crate) fn run<Output>(
+        mut self,
+        execute: impl FnOnce(&mut Self) -> Result<Output>,
+    ) -> Result<Output> {
+        execute(&mut self)
+    }
+
+    pub(crate) fn destination(&mut self, destination: ActorId) -> ActorId {
+        destination
+    }
+
+    pub(crate) fn code_id(&mut self, code_id: CodeId) -> CodeId {
+        code_id
+    }
+
+    pub(crate) fn value(&mut self, value: u128) -> u128 {
+        value
+    }
+
+    pub(crate) fn gas_limit(&mut self, gas_limit: u64) -> u64 {
+        gas_limit
+    }
+
+    pub(crate) fn reservation_id(&mut self, reservation_id: ReservationId) -> ReservationId {
+        reservation_id
+    }
+
+    pub(crate) fn delay(&mut self, delay: u32) -> u32 {
+        delay
+    }
+
+    pub(crate) fn reply_deposit(&mut self, reply_deposit: u64) -> u64 {
+        reply_deposit
+    }
+
+    pub(crate) fn payload_bytes<Buffer: AsRef<[u8]>>(&mut self, payload: Buffer) -> Buffer {
+        payload
+    }
+
+    pub(crate) fn payload_encodable<Encodable: Encode>(&mut self, payload: Encodable) -> Encodable {
+        payload
+    }
+
+    pub(crate) fn payload_input<Range: RangeBounds<usize>>(&mut self, payload: Range) -> Range {
+        payload
+    }
+}
@@ -0,0 +1,222 @@
+//! Dispatch of the message being processed to a handler per action.
+//!
+//! A [`Router`] decodes the message as `Action`, passes it to the first route that matches and
+//! replies with the value returned by the handler:
+//!
+//! ```ignore
+//! #[no_mangle]
+//! extern "C" fn handle() {
+//!     let counter = unsafe { &mut COUNTER };
+//!     builder::router::<Action, u64, CounterError>()
+//!         .on(route!(Action::Increment(n) => n), |n| {
+//!             *counter = counter.checked_add(n).ok_or(CounterError::Overflow)?;
+//!             Ok(*counter)
+//!         })
+//!         .on(route!(Action::Get => ()), |()| Ok(*counter))
+//!         .with_reply_gas_limit(1_000_000)
+//!         .handle()
+//!         .expect("Unable to reply");
+//! }
+//! ```
+//!
+//! The reply is the SCALE-encoded `Result<Reply, ErrorReply>`, the same as the one sent by
+//! `builder::reply_result(...)`, so the sender decodes it with `.for_reply_result::<Reply, Failure>()`.
+//! A [`RouteError`] is converted into an [`ErrorReply`] before replying: the handler's failure keeps
+//! its own code, a message that can't be decoded or isn't routed gets [`DECODE_CODE`] or
+//! [`UNHANDLED_CODE`].
+//!
+//! Handlers added with `.on_async(...)` return a future, e.g. one that waits for a reply with
+//! `.for_reply()`. Such a router is dispatched with `.handle_async().await`.
+
+use crate::{error_reply::ErrorReply, errors::Result, generated::ReplyBuilder, incoming::Incoming};
+use core::{future::Future, marker::PhantomData, pin::Pin};
+use gstd::{prelude::*, Decode, Encode, MessageId};
+
+/// Creates a route for [`Router::on()`] that matches a variant of the action enum and extracts
+/// the input of the handler from it.
+///
+/// ```ignore
+/// route!(Action::Transfer { to, amount } => (to, amount))
+/// ```
+#[macro_export]
+macro_rules! route {
+    ($variant:pat => $input:expr) => {
+        |action| match action {
+            $variant => ::core::result::Result::Ok($input),
+            action => ::core::result::Result::Err(action),
+        }
+    };
+}
+
+/// The code of the [`ErrorReply`] sent when the message can't be decoded as the action.
+pub const DECODE_CODE: u32 = 400;
+
+/// The code of the [`ErrorReply`] sent when no route matches the action.
+pub const UNHANDLED_CODE: u32 = 404;
+
+/// The reason a [`Router`] fails to handle the message, replied as an [`ErrorReply`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub enum RouteError<Failure> {
+    /// The message can't be decoded as the action.
+    Decode,
+    /// No route matches the action.
+    Unhandled,
+    /// The handler returned an error.
+    Handler(Failure),
+}
+
+impl<Failure: Into<ErrorReply>> From<RouteError<Failure>> for ErrorReply {
+    fn from(err: RouteError<Failure>) -> Self {
+        match err {
+            RouteError::Decode => ErrorReply::new(DECODE_CODE, "Unable to decode the action"),
+            RouteError::Unhandled => ErrorReply::new(UNHANDLED_CODE, "No route for the action"),
+            RouteError::Handler(failure) => failure.into(),
+        }
+    }
+}
+
+/// The marker of a router whose handlers all return immediately.
+pub struct SyncHandlers;
+
+/// The marker of a router with at least one handler added by `.on_async(...)`.
+pub struct AsyncHandlers;
+
+type HandlerFuture<'a, Reply, Failure> = Pin<Box<dyn Future<Output = Result<Reply, Failure>> + 'a>>;
+
+enum Handled<'a, Reply, Failure> {
+    Ready(Result<Reply, Failure>),
+    Pending(HandlerFuture<'a, Reply, Failure>),
+}
+
+type Route<'a, Action, Reply, Failure> =
+    Box<dyn FnOnce(Action) -> Result<Handled<'a, Reply, Failure>, Action> + 'a>;
+
+/// A dispatcher of the message being processed to the handler of its action.
+pub struct Router<'a, Action, Reply, Failure, Handlers = SyncHandlers> {
+    routes: Vec<Route<'a, Action, Reply, Failure>>,
+    value: u128,
+    gas_limit: Option<u64>,
+    handlers: PhantomData<Handlers>,
+}
+
+impl<'a, Action, Reply, Failure> Router<'a, Action, Reply, Failure> {
+    pub fn new() -> Self {
+        Self {
+            routes: Vec::new(),
+            value: 0,
+            gas_limit: None,
+            handlers: PhantomData,
+        }
+    }
+}
+
+impl<'a, Action, Reply, Failure> Default for Router<'a, Action, Reply, Failure> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Action: 'a, Reply: 'a, Failure: 'a, Handlers>
+    Router<'a, Action, Reply, Failure, Handlers>
+{
+    /// Adds a handler for the actions matched by `route`, see [`route!`](crate::route).
+    pub fn on<Input>(
+        mut self,
+        route: impl FnOnce(Action) -> Result<Input, Action> + 'a,
+        handler: impl FnOnce(Input) -> Result<Reply, Failure> + 'a,
+    ) -> Self {
+        self.routes.push(Box::new(move |action| {
+            route(action).map(|input| Handled::Ready(handler(input)))
+        }));
+        self
+    }
+
+    /// Adds an async handler for the actions matched by `route`, see [`route!`](crate::route).
+    pub fn on_async<Input, ReplyFuture>(
+        mut self,
+        route: impl FnOnce(Action) -> Result<Input, Action> + 'a,
+        handler: impl FnOnce(Input) -> ReplyFuture + 'a,
+    ) -> Router<'a, Action, Reply, Failure, AsyncHandlers>
+    where
+        ReplyFuture: Future<Output = Result<Reply, Failure>> + 'a,
+    {
+        self.routes.push(Box::new(move |action| {
+            route(action).map(|input| Handled::Pending(Box::pin(handler(input))))
+        }));
+        Router {
+            routes: self.routes,
+            value: self.value,
+            gas_limit: self.gas_limit,
+            handlers: PhantomData,
+        }
+    }
+
+    /// Sets the value sent with the reply, 0 by default.
+    pub fn with_reply_value(mut self, value: u128) -> Self {
+        self.value = value;
+        self
+    }
+
+    /// Sets the gas limit of the reply. By default, the reply is sent without a gas limit.
+    pub fn with_reply_gas_limit(mut self, gas_limit: u64) -> Self {
+        self.gas_limit = Some(gas_limit);
+        self
+    }
+}
+
+impl<'a, Action: Decode, Reply: Encode, Failure: Into<ErrorReply>, Handlers>
+    Router<'a, Action, Reply, Failure, Handlers>
+{
+    fn dispatch(
+        routes: Vec<Route<'a, Action, Reply, Failure>>,
+    ) -> Result<Handled<'a, Reply, Failure>, RouteError<Failure>> {
+        let Ok(mut action) = Incoming::new().decode::<Action>() else {
+            return Err(RouteError::Decode);
+        };
+        for route in routes {
+            match route(action) {
+                Ok(handled) => return Ok(handled),
+                Err(unmatched) => action = unmatched,
+            }
+        }
+
+        Err(RouteError::Unhandled)
+    }
+
+    fn reply(
+        value: u128,
+        gas_limit: Option<u64>,
+        reply: Result<Reply, RouteError<Failure>>,
+    ) -> Result<MessageId> {
+        let builder = ReplyBuilder::encode(reply.map_err(ErrorReply::from)).with_value(value);
+        match gas_limit {
+            Some(gas_limit) => builder.with_gas_limit(gas_limit).execute(),
+            None => builder.execute(),
+        }
+    }
+
+    /// Dispatches the message being processed and replies with the result of the handler.
+    pub async fn handle_async(self) -> Result<MessageId> {
+        let reply = match Self::dispatch(self.routes) {
+            Ok(Handled::Ready(reply)) => reply.map_err(RouteError::Handler),
+            Ok(Handled::Pending(future)) => future.await.map_err(RouteError::Handler),
+            Err(err) => Err(err),
+        };
+        Self::reply(self.value, self.gas_limit, reply)
+    }
+}
+
+impl<'a, Action: Decode, Reply: Encode, Failure: Into<ErrorReply>>
+    Router<'a, Action, Reply, Failure, SyncHandlers>
+{
+    /// Dispatches the message being processed and replies with the result of the handler.
+    pub fn handle(self) -> Result<MessageId> {
+        let reply = match Self::dispatch(self.routes) {
+            Ok(Handled::Ready(reply)) => reply.map_err(RouteError::Handler),
+            Ok(Handled::Pending(_)) => unreachable!("async handlers change the router type"),
+            Err(err) => Err(err),
+        };
+        Self::reply(self.value, self.gas_limit, reply)
+    }
+}
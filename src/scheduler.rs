@@ -0,0 +1,326 @@
+//! Recurring jobs run by delayed messages to the program itself.
+//!
+//! A [`Scheduler`] in the program state sends the payload of a job to `exec::program_id()` with
+//! `.with_delay(interval)`, and sends the next run each time the program processes the previous
+//! one:
+//!
+//! ```ignore
+//! static mut SCHEDULER: Scheduler = Scheduler::new();
+//!
+//! #[no_mangle]
+//! extern "C" fn handle() {
+//!     let scheduler = unsafe { &mut SCHEDULER };
+//!     match scheduler.run().expect("Unable to schedule the next run") {
+//!         Run::Job(_) | Run::Last(_) => rebalance(),
+//!         Run::Stale => {}
+//!         Run::NotScheduled => match msg::load().expect("Invalid action") {
+//!             Action::Start => {
+//!                 scheduler
+//!                     .schedule(Action::Rebalance, 100)
+//!                     .with_gas_limit(5_000_000)
+//!                     .execute()
+//!                     .expect("Unable to schedule");
+//!             }
+//!             Action::Stop(job_id) => {
+//!                 scheduler.cancel(job_id);
+//!             }
+//!             _ => {}
+//!         },
+//!     }
+//! }
+//! ```
+//!
+//! A run is recognized by the id of the message, so the payload of a job is delivered as is.
+
+use crate::{
+    backend::{ActiveBackend, Backend},
+    common::GasLimitW,
+    errors::{Error, Result},
+    generated::SendBuilder,
+};
+use alloc::collections::{BTreeMap, VecDeque};
+use gstd::{prelude::*, Decode, Encode, MessageId, ReservationId};
+
+/// The id of a job, unique within its [`Scheduler`].
+pub type JobId = u64;
+
+/// The number of pending runs of cancelled jobs a [`Scheduler`] remembers. The run of a job
+/// cancelled earlier is reported as [`Run::NotScheduled`].
+pub const MAX_STALE_RUNS: usize = 32;
+
+pub struct ReservationsW(pub(crate) VecDeque<ReservationId>);
+
+/// Where the gas of the runs of a job comes from.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub enum GasSource {
+    /// The runs are sent without a gas limit.
+    Unspecified,
+    /// The runs are sent with the gas limit.
+    Limit(u64),
+    /// Each run is sent with gas from the next reservation. The run sent with the last one is
+    /// reported as [`Run::Last`].
+    Reservations(VecDeque<ReservationId>),
+}
+
+impl From<()> for GasSource {
+    fn from(_: ()) -> Self {
+        Self::Unspecified
+    }
+}
+
+impl From<GasLimitW> for GasSource {
+    fn from(GasLimitW(gas_limit): GasLimitW) -> Self {
+        Self::Limit(gas_limit)
+    }
+}
+
+impl From<ReservationsW> for GasSource {
+    fn from(ReservationsW(reservations): ReservationsW) -> Self {
+        Self::Reservations(reservations)
+    }
+}
+
+/// A job registered in a [`Scheduler`].
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct Job {
+    payload: Vec<u8>,
+    interval: u32,
+    gas: GasSource,
+    pending: Option<MessageId>,
+}
+
+impl Job {
+    /// Returns the payload sent with every run.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Returns the number of blocks between the runs.
+    pub fn interval(&self) -> u32 {
+        self.interval
+    }
+
+    /// Returns where the gas of the runs comes from.
+    pub fn gas(&self) -> &GasSource {
+        &self.gas
+    }
+
+    /// Returns the id of the message of the next run, or `None` if it failed to be sent.
+    pub fn pending(&self) -> Option<MessageId> {
+        self.pending
+    }
+}
+
+/// What the message being processed is to a [`Scheduler`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Run {
+    /// A run of the job. The next run is already sent.
+    Job(JobId),
+    /// The last run of the job, which has no reservation left for the next run and was removed.
+    Last(JobId),
+    /// A run of a cancelled job, which should be ignored.
+    Stale,
+    /// Not a message sent by the scheduler.
+    NotScheduled,
+}
+
+/// The table of recurring jobs, kept in the program state.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct Scheduler {
+    jobs: BTreeMap<JobId, Job>,
+    stale: VecDeque<MessageId>,
+    next_job_id: JobId,
+}
+
+impl Scheduler {
+    pub const fn new() -> Self {
+        Self {
+            jobs: BTreeMap::new(),
+            stale: VecDeque::new(),
+            next_job_id: 0,
+        }
+    }
+
+    /// Returns a builder that registers a job sending the encoded payload every `interval` blocks.
+    pub fn schedule<Encodable: Encode>(
+        &mut self,
+        payload: Encodable,
+        interval: u32,
+    ) -> JobBuilder<'_> {
+        JobBuilder {
+            scheduler: self,
+            payload: payload.encode(),
+            interval,
+            gas: (),
+        }
+    }
+
+    /// Returns the job, or `None` if it was cancelled.
+    pub fn job(&self, job_id: JobId) -> Option<&Job> {
+        self.jobs.get(&job_id)
+    }
+
+    /// Returns the registered jobs in the order they were registered.
+    pub fn jobs(&self) -> impl Iterator<Item = (JobId, &Job)> {
+        self.jobs.iter().map(|(&job_id, job)| (job_id, job))
+    }
+
+    /// Returns `true` if no job is registered.
+    pub fn is_empty(&self) -> bool {
+        self.jobs.is_empty()
+    }
+
+    /// Removes the job. Its pending run is reported as [`Run::Stale`], as long as it is one of the
+    /// last [`MAX_STALE_RUNS`] cancelled. Returns `false` if the job is not registered.
+    pub fn cancel(&mut self, job_id: JobId) -> bool {
+        let Some(job) = self.jobs.remove(&job_id) else {
+            return false;
+        };
+
+        if let Some(pending) = job.pending {
+            if self.stale.len() == MAX_STALE_RUNS {
+                self.stale.pop_front();
+            }
+            self.stale.push_back(pending);
+        }
+        true
+    }
+
+    /// Checks if the message being processed is a run, and sends the next run of its job.
+    ///
+    /// If the next run can't be sent, returns the error and keeps the job without a pending run,
+    /// see [`Scheduler::resume()`]. Running out of reservations is not an error, the run is
+    /// reported as [`Run::Last`] instead.
+    pub fn run(&mut self) -> Result<Run> {
+        let message_id = ActiveBackend::message_id();
+        if let Some(index) = self.stale.iter().position(|&stale| stale == message_id) {
+            self.stale.remove(index);
+            return Ok(Run::Stale);
+        }
+
+        let Some((&job_id, _)) = self
+            .jobs
+            .iter()
+            .find(|(_, job)| job.pending == Some(message_id))
+        else {
+            return Ok(Run::NotScheduled);
+        };
+
+        let job = self.jobs.get_mut(&job_id).expect("Job is registered");
+        if matches!(&job.gas, GasSource::Reservations(reservations) if reservations.is_empty()) {
+            self.jobs.remove(&job_id);
+            return Ok(Run::Last(job_id));
+        }
+
+        // The job is left without a pending run if the next one can't be sent.
+        job.pending = None;
+        job.pending = Some(send(job_id, job)?);
+        Ok(Run::Job(job_id))
+    }
+
+    /// Sends the next run of a job whose previous send failed. The reservation it failed to use
+    /// is tried again. Returns `false` if the job is not registered or already has a pending run.
+    pub fn resume(&mut self, job_id: JobId) -> Result<bool> {
+        let Some(job) = self.jobs.get_mut(&job_id) else {
+            return Ok(false);
+        };
+        if job.pending.is_some() {
+            return Ok(false);
+        }
+
+        job.pending = Some(send(job_id, job)?);
+        Ok(true)
+    }
+}
+
+/// A builder that registers a job in a [`Scheduler`].
+#[must_use]
+pub struct JobBuilder<'a, Gas = ()> {
+    scheduler: &'a mut Scheduler,
+    payload: Vec<u8>,
+    interval: u32,
+    gas: Gas,
+}
+
+impl<'a> JobBuilder<'a> {
+    /// Sends every run with the gas limit.
+    pub fn with_gas_limit(self, gas_limit: u64) -> JobBuilder<'a, GasLimitW> {
+        self.with_gas(GasLimitW(gas_limit))
+    }
+
+    /// Sends every run with gas from the next of the reservations, which are used in order.
+    pub fn with_gas_from_reservations(
+        self,
+        reservations: impl IntoIterator<Item = ReservationId>,
+    ) -> JobBuilder<'a, ReservationsW> {
+        self.with_gas(ReservationsW(reservations.into_iter().collect()))
+    }
+
+    fn with_gas<Gas>(self, gas: Gas) -> JobBuilder<'a, Gas> {
+        JobBuilder {
+            scheduler: self.scheduler,
+            payload: self.payload,
+            interval: self.interval,
+            gas,
+        }
+    }
+}
+
+impl<Gas: Into<GasSource>> JobBuilder<'_, Gas> {
+    /// Sends the first run and registers the job.
+    ///
+    /// A job is identified by its payload: if a job with the same payload is registered, returns
+    /// its id without sending anything, so the runs of the same job never overlap. The interval
+    /// and gas of the registered job are kept, cancel it first to change them.
+    pub fn execute(self) -> Result<JobId> {
+        let Self {
+            scheduler,
+            payload,
+            interval,
+            gas,
+        } = self;
+        if let Some((&job_id, _)) = scheduler
+            .jobs
+            .iter()
+            .find(|(_, job)| job.payload == payload)
+        {
+            return Ok(job_id);
+        }
+
+        let job_id = scheduler.next_job_id;
+        let mut job = Job {
+            payload,
+            interval,
+            gas: gas.into(),
+            pending: None,
+        };
+        job.pending = Some(send(job_id, &mut job)?);
+        scheduler.jobs.insert(job_id, job);
+        scheduler.next_job_id += 1;
+
+        Ok(job_id)
+    }
+}
+
+fn send(job_id: JobId, job: &mut Job) -> Result<MessageId> {
+    let builder = SendBuilder::bytes(ActiveBackend::program_id(), job.payload.as_slice())
+        .with_delay(job.interval);
+    match &mut job.gas {
+        GasSource::Unspecified => builder.execute(),
+        GasSource::Limit(gas_limit) => builder.with_gas_limit(*gas_limit).execute(),
+        GasSource::Reservations(reservations) => {
+            let reservation_id = *reservations
+                .front()
+                .ok_or(Error::ReservationsExhausted { job_id })?;
+            let pending = builder
+                .with_gas_from_reservation(reservation_id)
+                .execute()?;
+            // The reservation is only used up once the run is sent.
+            reservations.pop_front();
+            Ok(pending)
+        }
+    }
+}
@@ -0,0 +1,260 @@
+//! Recording of the messages sent by the builders, enabled by the `trace` feature.
+//!
+//! Every `execute()` appends a [`TraceEntry`] to a ring buffer stored in the program memory. The
+//! buffer keeps the last [`Config::trace_capacity()`](crate::config::Config::trace_capacity)
+//! entries, and can be shared through the program state:
+//!
+//! ```ignore
+//! #[no_mangle]
+//! extern "C" fn state() {
+//!     msg::reply(gstd_fluent::trace::entries(), 0).expect("Unable to share the state");
+//! }
+//! ```
+//!
+//! Off-chain, the state can be decoded and rendered as a timeline:
+//!
+//! ```ignore
+//! let timeline = Timeline::decode(&mut state.as_slice())?;
+//! println!("{timeline}");
+//! ```
+
+use alloc::collections::VecDeque;
+use blake2::{digest::consts::U32, Blake2b, Digest};
+use core::{
+    fmt,
+    ops::{Bound, RangeBounds},
+    ptr,
+};
+use gstd::{codec, prelude::*, ActorId, CodeId, Decode, Encode, MessageId, ReservationId};
+
+/// The builder that sent a message.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub enum BuilderKind {
+    Send,
+    SendForReply,
+    Reply,
+    CreateProgram,
+    CreateProgramForReply,
+}
+
+/// The options passed to the `gstd` function.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct TraceOptions {
+    pub value: u128,
+    pub gas_limit: Option<u64>,
+    pub delay: Option<u32>,
+    pub reservation_id: Option<ReservationId>,
+    pub reply_deposit: Option<u64>,
+}
+
+/// The fingerprint of a payload.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub enum PayloadDigest {
+    /// The BLAKE2b-256 hash of the payload bytes.
+    Blake2b256([u8; 32]),
+    /// The range of the incoming message forwarded by `*_input` builders, `end` is exclusive.
+    Input { start: u32, end: Option<u32> },
+}
+
+/// A message sent by a builder.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct TraceEntry {
+    /// The sequence number of the entry, starting from 0.
+    pub index: u64,
+    pub block_height: u32,
+    pub kind: BuilderKind,
+    /// The receiver of the message or the created program. `None` for replies.
+    pub destination: Option<ActorId>,
+    pub code_id: Option<CodeId>,
+    pub options: TraceOptions,
+    pub payload: PayloadDigest,
+    /// The id of the sent message. `None` if the `gstd` function returned an error.
+    pub message_id: Option<MessageId>,
+}
+
+impl PayloadDigest {
+    pub(crate) fn bytes(payload: &[u8]) -> Self {
+        Self::Blake2b256(Blake2b::<U32>::digest(payload).into())
+    }
+
+    pub(crate) fn input<Range: RangeBounds<usize>>(range: &Range) -> Self {
+        // Offsets past `u32::MAX` are out of any message, so they are clamped.
+        let offset = |offset: usize| u32::try_from(offset).unwrap_or(u32::MAX);
+        let start = match range.start_bound() {
+            Bound::Included(&start) => offset(start),
+            Bound::Excluded(&start) => offset(start).saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => Some(offset(end).saturating_add(1)),
+            Bound::Excluded(&end) => Some(offset(end)),
+            Bound::Unbounded => None,
+        };
+
+        Self::Input { start, end }
+    }
+}
+
+struct Trace {
+    entries: VecDeque<TraceEntry>,
+    next_index: u64,
+}
+
+static mut TRACE: Trace = Trace {
+    entries: VecDeque::new(),
+    next_index: 0,
+};
+
+fn trace() -> &'static mut Trace {
+    // SAFETY: Gear programs are single-threaded.
+    unsafe { &mut *ptr::addr_of_mut!(TRACE) }
+}
+
+pub(crate) fn push(mut entry: TraceEntry) {
+    let capacity = crate::config::get().trace_capacity();
+    if capacity == 0 {
+        return;
+    }
+
+    let trace = trace();
+    entry.index = trace.next_index;
+    trace.next_index += 1;
+
+    while trace.entries.len() >= capacity {
+        trace.entries.pop_front();
+    }
+    trace.entries.push_back(entry);
+}
+
+/// Returns the recorded entries, from the oldest to the newest.
+pub fn entries() -> Vec<TraceEntry> {
+    trace().entries.iter().copied().collect()
+}
+
+/// Removes the recorded entries. The sequence numbers are not reset.
+pub fn clear() {
+    trace().entries.clear();
+}
+
+/// The recorded entries rendered one per line.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Timeline {
+    pub entries: Vec<TraceEntry>,
+}
+
+impl Timeline {
+    /// Decodes the entries returned by [`entries()`].
+    pub fn decode(input: &mut &[u8]) -> Result<Self, codec::Error> {
+        Vec::<TraceEntry>::decode(input).map(Self::from)
+    }
+}
+
+impl From<Vec<TraceEntry>> for Timeline {
+    fn from(entries: Vec<TraceEntry>) -> Self {
+        Self { entries }
+    }
+}
+
+/// Renders bytes as `0x01234567..89abcdef`.
+struct Hex<'a>(&'a [u8]);
+
+impl fmt::Display for Hex<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "0x")?;
+        if self.0.len() <= 8 {
+            return self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"));
+        }
+
+        let (head, tail) = (&self.0[..4], &self.0[self.0.len() - 4..]);
+        head.iter().try_for_each(|byte| write!(f, "{byte:02x}"))?;
+        write!(f, "..")?;
+        tail.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl fmt::Display for BuilderKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Send => "send",
+            Self::SendForReply => "send for reply",
+            Self::Reply => "reply",
+            Self::CreateProgram => "create program",
+            Self::CreateProgramForReply => "create program for reply",
+        })
+    }
+}
+
+impl fmt::Display for TraceOptions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "value {}", self.value)?;
+        if let Some(gas_limit) = self.gas_limit {
+            write!(f, " gas {gas_limit}")?;
+        }
+        if let Some(delay) = self.delay {
+            write!(f, " delay {delay}")?;
+        }
+        if let Some(reservation_id) = self.reservation_id {
+            write!(f, " reservation {}", Hex(reservation_id.as_ref()))?;
+        }
+        if let Some(reply_deposit) = self.reply_deposit {
+            write!(f, " reply deposit {reply_deposit}")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for PayloadDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Blake2b256(hash) => write!(f, "{}", Hex(hash)),
+            Self::Input {
+                start,
+                end: Some(end),
+            } => write!(f, "input {start}..{end}"),
+            Self::Input { start, end: None } => write!(f, "input {start}.."),
+        }
+    }
+}
+
+impl fmt::Display for TraceEntry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "#{:<6} block {:<10} {:<24}",
+            self.index, self.block_height, self.kind
+        )?;
+        match self.destination {
+            Some(destination) => write!(f, " to {}", Hex(destination.as_ref()))?,
+            None if self.kind == BuilderKind::Reply => write!(f, " to source")?,
+            None => write!(f, " to ?")?,
+        }
+        if let Some(code_id) = self.code_id {
+            write!(f, " code {}", Hex(code_id.as_ref()))?;
+        }
+        match self.message_id {
+            Some(message_id) => write!(f, " msg {}", Hex(message_id.as_ref()))?,
+            None => write!(f, " failed")?,
+        }
+        write!(f, " {} payload {}", self.options, self.payload)
+    }
+}
+
+impl fmt::Display for Timeline {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut next_index = 0;
+        for entry in &self.entries {
+            match entry.index.saturating_sub(next_index) {
+                0 => {}
+                1 => writeln!(f, "... 1 entry dropped")?,
+                dropped => writeln!(f, "... {dropped} entries dropped")?,
+            }
+            writeln!(f, "{entry}")?;
+            next_index = entry.index + 1;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,172 @@
+//! Transfer of value without a payload.
+//!
+//! `builder::transfer(...)` sends an empty message with the value through the `send_bytes*`
+//! functions. With `.keep_alive()`, the transfer is rejected if it would leave the program with
+//! less than the existential deposit set in [`Config`](crate::config::Config):
+//!
+//! ```ignore
+//! builder::transfer(user, 1_000_000_000_000)
+//!     .with_delay(10)
+//!     .keep_alive()
+//!     .execute()?;
+//! ```
+
+use crate::{
+    backend::{ActiveBackend, Backend},
+    common::{DelayW, GasLimitW, PayloadBytesW, ProgramW, ReservationIdW, ValueW},
+    errors::{Error, Result},
+    generated::SendBuilder,
+};
+use gstd::{ActorId, MessageId, ReservationId};
+
+type EmptySendBuilder = SendBuilder<(ProgramW, PayloadBytesW<[u8; 0]>, ValueW, (), (), ())>;
+
+/// Sends the empty message with the options set by the stages of [`TransferBuilder`].
+pub trait TransferOptions {
+    fn send(self, builder: EmptySendBuilder) -> Result<MessageId>;
+}
+
+impl TransferOptions for ((), (), ()) {
+    fn send(self, builder: EmptySendBuilder) -> Result<MessageId> {
+        builder.execute()
+    }
+}
+
+impl TransferOptions for (DelayW, (), ()) {
+    fn send(self, builder: EmptySendBuilder) -> Result<MessageId> {
+        let (DelayW(delay), _, _) = self;
+        builder.with_delay(delay).execute()
+    }
+}
+
+impl TransferOptions for ((), GasLimitW, ()) {
+    fn send(self, builder: EmptySendBuilder) -> Result<MessageId> {
+        let (_, GasLimitW(gas_limit), _) = self;
+        builder.with_gas_limit(gas_limit).execute()
+    }
+}
+
+impl TransferOptions for (DelayW, GasLimitW, ()) {
+    fn send(self, builder: EmptySendBuilder) -> Result<MessageId> {
+        let (DelayW(delay), GasLimitW(gas_limit), _) = self;
+        builder
+            .with_delay(delay)
+            .with_gas_limit(gas_limit)
+            .execute()
+    }
+}
+
+impl TransferOptions for ((), (), ReservationIdW) {
+    fn send(self, builder: EmptySendBuilder) -> Result<MessageId> {
+        let (_, _, ReservationIdW(reservation_id)) = self;
+        builder.with_gas_from_reservation(reservation_id).execute()
+    }
+}
+
+impl TransferOptions for (DelayW, (), ReservationIdW) {
+    fn send(self, builder: EmptySendBuilder) -> Result<MessageId> {
+        let (DelayW(delay), _, ReservationIdW(reservation_id)) = self;
+        builder
+            .with_delay(delay)
+            .with_gas_from_reservation(reservation_id)
+            .execute()
+    }
+}
+
+/// A builder that transfers value to a program or user.
+pub struct TransferBuilder<Fields = ((), (), (), (), ())> {
+    fields: Fields,
+    keep_alive: bool,
+}
+
+impl TransferBuilder<(ProgramW, ValueW, (), (), ())> {
+    pub fn new(to: ActorId, amount: u128) -> Self {
+        Self {
+            fields: (ProgramW(to), ValueW(amount), (), (), ()),
+            keep_alive: false,
+        }
+    }
+}
+
+impl<Program, Value, GasLimit, ReservationId>
+    TransferBuilder<(Program, Value, (), GasLimit, ReservationId)>
+{
+    pub fn with_delay(
+        self,
+        delay: u32,
+    ) -> TransferBuilder<(Program, Value, DelayW, GasLimit, ReservationId)> {
+        let (program, value, _, gas_limit, reservation_id) = self.fields;
+        TransferBuilder {
+            fields: (program, value, DelayW(delay), gas_limit, reservation_id),
+            keep_alive: self.keep_alive,
+        }
+    }
+}
+
+impl<Program, Value, Delay> TransferBuilder<(Program, Value, Delay, (), ())> {
+    pub fn with_gas_limit(
+        self,
+        gas_limit: u64,
+    ) -> TransferBuilder<(Program, Value, Delay, GasLimitW, ())> {
+        let (program, value, delay, _, _) = self.fields;
+        TransferBuilder {
+            fields: (program, value, delay, GasLimitW(gas_limit), ()),
+            keep_alive: self.keep_alive,
+        }
+    }
+
+    pub fn with_gas_from_reservation(
+        self,
+        reservation_id: ReservationId,
+    ) -> TransferBuilder<(Program, Value, Delay, (), ReservationIdW)> {
+        let (program, value, delay, _, _) = self.fields;
+        TransferBuilder {
+            fields: (program, value, delay, (), ReservationIdW(reservation_id)),
+            keep_alive: self.keep_alive,
+        }
+    }
+}
+
+impl<Fields> TransferBuilder<Fields> {
+    /// Rejects the transfer if it would leave the program with less than the existential deposit.
+    ///
+    /// If the existential deposit is not set in [`Config`](crate::config::Config), only checks that
+    /// `exec::value_available()` covers the amount.
+    pub fn keep_alive(mut self) -> Self {
+        self.keep_alive = true;
+        self
+    }
+}
+
+impl<Delay, GasLimit, ReservationId>
+    TransferBuilder<(ProgramW, ValueW, Delay, GasLimit, ReservationId)>
+where
+    (Delay, GasLimit, ReservationId): TransferOptions,
+{
+    pub fn execute(self) -> Result<MessageId> {
+        let (ProgramW(to), ValueW(amount), delay, gas_limit, reservation_id) = self.fields;
+        if amount == 0 {
+            return Err(Error::ZeroTransfer);
+        }
+        if self.keep_alive {
+            check_keep_alive(amount)?;
+        }
+
+        let builder = SendBuilder::bytes(to, []).with_value(amount);
+        (delay, gas_limit, reservation_id).send(builder)
+    }
+}
+
+fn check_keep_alive(amount: u128) -> Result<()> {
+    let value_available = ActiveBackend::value_available();
+    let existential_deposit = crate::config::get().existential_deposit().unwrap_or(0);
+    if value_available.saturating_sub(amount) < existential_deposit || amount > value_available {
+        return Err(Error::KeepAliveViolated {
+            amount,
+            value_available,
+            existential_deposit,
+        });
+    }
+
+    Ok(())
+}
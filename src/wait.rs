@@ -0,0 +1,229 @@
+//! Suspension and resumption of messages.
+//!
+//! `builder::wait()` suspends the message being processed, and `builder::wake(message_id)` resumes
+//! a suspended message, which is then processed again from the start of the handler. A [`Waitlist`]
+//! in the program state remembers which messages wait for which key, so a program without async
+//! handlers can use it as a condition variable:
+//!
+//! ```ignore
+//! static mut WAITLIST: Waitlist<OrderId> = Waitlist::new();
+//!
+//! #[no_mangle]
+//! extern "C" fn handle() {
+//!     let waitlist = unsafe { &mut WAITLIST };
+//!     match msg::load().expect("Invalid action") {
+//!         Action::Await(order_id) if !is_filled(order_id) => {
+//!             waitlist.wait(order_id).up_to(100).execute()
+//!         }
+//!         Action::Await(order_id) => {
+//!             waitlist.remove(msg::id());
+//!             builder::reply(order_id).execute().expect("Unable to reply");
+//!         }
+//!         Action::Fill(order_id) => {
+//!             fill(order_id);
+//!             waitlist.notify_all(&order_id).expect("Unable to wake");
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::{
+    backend::{ActiveBackend, Backend},
+    common::DelayW,
+    errors::Result,
+};
+use alloc::collections::BTreeMap;
+use gstd::{prelude::*, Decode, Encode, MessageId};
+
+pub struct WaitForW(pub(crate) u32);
+pub struct WaitUpToW(pub(crate) u32);
+pub struct MessageIdW(pub(crate) MessageId);
+
+pub trait IntoWait {
+    fn wait(self) -> !;
+}
+
+impl IntoWait for () {
+    fn wait(self) -> ! {
+        ActiveBackend::wait()
+    }
+}
+
+impl IntoWait for WaitForW {
+    fn wait(self) -> ! {
+        ActiveBackend::wait_for(self.0)
+    }
+}
+
+impl IntoWait for WaitUpToW {
+    fn wait(self) -> ! {
+        ActiveBackend::wait_up_to(self.0)
+    }
+}
+
+/// A builder that suspends the message being processed.
+pub struct WaitBuilder<Duration = ()> {
+    duration: Duration,
+}
+
+impl WaitBuilder {
+    pub fn new() -> Self {
+        Self { duration: () }
+    }
+
+    /// Suspends the message for exactly `duration` blocks, unless it is woken earlier.
+    pub fn for_blocks(self, duration: u32) -> WaitBuilder<WaitForW> {
+        WaitBuilder {
+            duration: WaitForW(duration),
+        }
+    }
+
+    /// Suspends the message for at most `duration` blocks, or as long as the gas allows.
+    pub fn up_to(self, duration: u32) -> WaitBuilder<WaitUpToW> {
+        WaitBuilder {
+            duration: WaitUpToW(duration),
+        }
+    }
+}
+
+impl Default for WaitBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<Duration: IntoWait> WaitBuilder<Duration> {
+    /// Suspends the message. The handler is run again from the start when the message is woken.
+    pub fn execute(self) -> ! {
+        self.duration.wait()
+    }
+}
+
+/// A builder that resumes a suspended message.
+pub struct WakeBuilder<Fields = ((), ())> {
+    fields: Fields,
+}
+
+impl WakeBuilder<(MessageIdW, ())> {
+    pub fn new(message_id: MessageId) -> Self {
+        Self {
+            fields: (MessageIdW(message_id), ()),
+        }
+    }
+}
+
+impl<Message> WakeBuilder<(Message, ())> {
+    /// Resumes the message after `delay` blocks.
+    pub fn with_delay(self, delay: u32) -> WakeBuilder<(Message, DelayW)> {
+        let (message_id, _) = self.fields;
+        WakeBuilder {
+            fields: (message_id, DelayW(delay)),
+        }
+    }
+}
+
+impl WakeBuilder<(MessageIdW, ())> {
+    pub fn execute(self) -> Result<()> {
+        let (MessageIdW(message_id), _) = self.fields;
+        Ok(ActiveBackend::wake(message_id)?)
+    }
+}
+
+impl WakeBuilder<(MessageIdW, DelayW)> {
+    pub fn execute(self) -> Result<()> {
+        let (MessageIdW(message_id), DelayW(delay)) = self.fields;
+        Ok(ActiveBackend::wake_delayed(message_id, delay)?)
+    }
+}
+
+/// The messages suspended until something happens to a key.
+///
+/// The messages of a key are woken in the order they started waiting.
+#[derive(Clone, Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+pub struct Waitlist<Key> {
+    waiting: BTreeMap<Key, Vec<MessageId>>,
+}
+
+impl<Key: Ord> Waitlist<Key> {
+    pub const fn new() -> Self {
+        Self {
+            waiting: BTreeMap::new(),
+        }
+    }
+
+    /// Adds the message being processed to the messages waiting for the key, and returns a
+    /// builder that suspends it.
+    pub fn wait(&mut self, key: Key) -> WaitBuilder {
+        self.register(key, ActiveBackend::message_id());
+        WaitBuilder::new()
+    }
+
+    /// Adds the message to the messages waiting for the key, if it is not there yet.
+    pub fn register(&mut self, key: Key, message_id: MessageId) {
+        let waiting = self.waiting.entry(key).or_default();
+        if !waiting.contains(&message_id) {
+            waiting.push(message_id);
+        }
+    }
+
+    /// Removes the message, e.g. after it was resumed by the timeout of `.up_to(...)`. Returns
+    /// `false` if the message was not waiting.
+    pub fn remove(&mut self, message_id: MessageId) -> bool {
+        let mut removed = false;
+        self.waiting.retain(|_, waiting| {
+            if let Some(position) = waiting.iter().position(|&id| id == message_id) {
+                waiting.remove(position);
+                removed = true;
+            }
+            !waiting.is_empty()
+        });
+
+        removed
+    }
+
+    /// Returns the messages waiting for the key.
+    pub fn waiting(&self, key: &Key) -> &[MessageId] {
+        self.waiting.get(key).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns `true` if no message is waiting.
+    pub fn is_empty(&self) -> bool {
+        self.waiting.is_empty()
+    }
+
+    /// Wakes the message that has waited for the key the longest. Returns its id, or `None` if no
+    /// message is waiting for the key.
+    pub fn notify_one(&mut self, key: &Key) -> Result<Option<MessageId>> {
+        let Some(waiting) = self.waiting.get_mut(key) else {
+            return Ok(None);
+        };
+
+        let message_id = waiting[0];
+        WakeBuilder::new(message_id).execute()?;
+        waiting.remove(0);
+        if waiting.is_empty() {
+            self.waiting.remove(key);
+        }
+
+        Ok(Some(message_id))
+    }
+
+    /// Wakes all messages waiting for the key and returns their number.
+    ///
+    /// If a message can't be woken, returns the error and keeps the messages that were not woken.
+    pub fn notify_all(&mut self, key: &Key) -> Result<usize> {
+        let mut woken = 0;
+        while self.notify_one(key)?.is_some() {
+            woken += 1;
+        }
+
+        Ok(woken)
+    }
+}
+
+impl<Key: Ord> Default for Waitlist<Key> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,108 @@
+//! Checks `Access`, its `incoming()` guards and the `only_owner`/`only_role` handlers.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::{Decode, Encode};
+use gstd_fluent::{
+    self as builder,
+    access::{only_owner, only_role, Access, Unauthorized},
+    backend::mock::{self, Payload},
+    error_reply::ErrorReply,
+    errors::{Error, GuardError},
+    route,
+};
+
+use common::*;
+
+#[derive(Encode, Decode)]
+#[codec(crate = gstd::codec)]
+enum Action {
+    Add(u64, u64),
+    Ping,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord)]
+enum Role {
+    Admin,
+    Minter,
+}
+
+#[test]
+fn access() {
+    let mut access = Access::new(program());
+    assert!(access.grant(created_program(), Role::Minter));
+    assert!(!access.grant(created_program(), Role::Minter));
+    assert!(access.has_role(created_program(), &Role::Minter));
+    assert!(!access.has_role(created_program(), &Role::Admin));
+    assert!(access.has_role(program(), &Role::Admin));
+    assert_eq!(access.check_owner(created_program()), Err(Unauthorized { actor: created_program() }));
+
+    assert!(access.revoke(created_program(), &Role::Minter));
+    assert!(!access.revoke(created_program(), &Role::Minter));
+    assert_eq!(access.check_role(created_program(), &Role::Minter), Err(Unauthorized { actor: created_program() }));
+
+    access.transfer_ownership(created_program());
+    assert_eq!(access.owner(), created_program());
+    assert_eq!(access.check_owner(created_program()), Ok(()));
+}
+
+#[test]
+fn access__incoming() {
+    let mut access = Access::new(created_program());
+    setup();
+    set_incoming(ENCODABLE.encode());
+    assert!(matches!(builder::incoming().require_owner(&access).bytes(), Err(Error::Guard(GuardError::Unauthorized { actor })) if actor == program()));
+    assert!(matches!(builder::incoming().require_role(&access, Role::Admin).bytes(), Err(Error::Guard(GuardError::Unauthorized { .. }))));
+
+    access.grant(program(), Role::Admin);
+    assert_eq!(builder::incoming().require_role(&access, Role::Admin).decode::<String>().unwrap(), ENCODABLE);
+}
+
+#[test]
+fn access__only_owner() {
+    let mut access = Access::new(created_program());
+    access.grant(program(), Role::Minter);
+    let handle = |access: &Access<Role>| {
+        builder::router::<Action, u64, ErrorReply>()
+            .on(route!(Action::Add(a, b) => (a, b)), only_role(access, Role::Minter, |(a, b)| Ok(a + b)))
+            .on(route!(Action::Ping => ()), only_owner(access, |()| Ok(0)))
+            .handle()
+    };
+
+    setup();
+    set_incoming(Action::Add(2, 3).encode());
+    handle(&access).expect("handle() failed");
+    assert_eq!(recorded_call(), call("reply", Payload::Bytes(Ok::<u64, ErrorReply>(5).encode())));
+
+    set_incoming(Action::Ping.encode());
+    handle(&access).expect("handle() failed");
+    let denied = ErrorReply::new(Unauthorized::CODE, Unauthorized { actor: program() }.to_string());
+    assert_eq!(recorded_call(), call("reply", Payload::Bytes(Err::<u64, _>(denied).encode())));
+
+    access.revoke(program(), &Role::Minter);
+    set_incoming(Action::Add(2, 3).encode());
+    handle(&access).expect("handle() failed");
+    assert!(matches!(recorded_call().payload, Payload::Bytes(bytes) if matches!(Result::<u64, ErrorReply>::decode(&mut &bytes[..]), Ok(Err(err)) if err.code == Unauthorized::CODE)));
+}
+
+#[test]
+fn access__or_reply_unauthorized() {
+    let mut access = Access::new(created_program());
+    setup();
+    set_incoming(ENCODABLE.encode());
+    let denied = builder::incoming().require_role(&access, Role::Minter).or_reply_unauthorized();
+    assert!(matches!(denied, Err(Error::Guard(GuardError::Unauthorized { actor })) if actor == program()));
+    let Payload::Bytes(bytes) = recorded_call().payload else { panic!("the reply is not encoded") };
+    let reply = Result::<(), ErrorReply>::decode(&mut bytes.as_slice()).unwrap().unwrap_err();
+    assert_eq!(reply, ErrorReply::new(Unauthorized::CODE, Unauthorized { actor: program() }.to_string()));
+    assert_eq!(reply.code, 401);
+
+    // Other guards are left to `.decode()`, nothing is replied.
+    access.grant(program(), Role::Minter);
+    let incoming = builder::incoming().require_role(&access, Role::Minter).require_max_len(1).or_reply_unauthorized().unwrap();
+    assert!(matches!(incoming.bytes(), Err(Error::Guard(GuardError::PayloadTooLong { .. }))));
+    assert!(mock::take_calls().is_empty());
+}
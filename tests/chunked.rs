@@ -0,0 +1,157 @@
+//! Checks `send_chunked(...)` and the reassembly of its chunks by `ChunkAssembler`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::{Decode, Encode};
+use gstd_fluent::{
+    self as builder,
+    backend::mock::{self, Payload},
+    chunked::{Chunk, ChunkAssembler, ChunkError, ChunkSender},
+    errors::Error,
+};
+
+use common::*;
+
+/// The chunks recorded by `send_chunked(...)`, all sent with `send` to `program()`.
+fn recorded_chunks() -> Vec<Chunk> {
+    mock::take_calls().into_iter().map(|call| {
+        assert_eq!((call.function, call.destination), ("send", Some(program())));
+        let Payload::Bytes(bytes) = call.payload else { panic!("unexpected payload {:?}", call.payload) };
+        Chunk::decode(&mut bytes.as_slice()).expect("not a chunk")
+    }).collect()
+}
+
+#[test]
+fn send_chunked() {
+    let mut sender = ChunkSender::new();
+
+    setup();
+    let message_ids = builder::send_chunked(&mut sender, program(), ENCODABLE).with_chunk_size(2).execute().expect("execute() failed");
+    let chunks = recorded_chunks();
+    assert_eq!(message_ids.len(), 3);
+    assert_eq!(chunks.iter().map(|chunk| (chunk.index, chunk.count)).collect::<Vec<_>>(), [(0, 3), (1, 3), (2, 3)]);
+
+    let mut assembler = ChunkAssembler::<String>::new();
+    assert_eq!(assembler.push(program(), chunks[0].clone()).unwrap(), None);
+    assert!(matches!(assembler.push(program(), chunks[0].clone()), Err(ChunkError::Duplicate { index: 0, .. })));
+    assert_eq!(assembler.push(program(), chunks[1].clone()).unwrap(), None);
+    assert_eq!(assembler.push(program(), chunks[2].clone()).unwrap().as_deref(), Some(ENCODABLE));
+    assert_eq!(assembler.pending(), 0);
+
+    // An empty payload is sent as one empty chunk, the default chunk size fits into one message.
+    builder::send_chunked(&mut sender, program(), ()).execute().expect("execute() failed");
+    let chunks = recorded_chunks();
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(ChunkAssembler::<()>::new().push(program(), chunks[0].clone()).unwrap(), Some(()));
+}
+
+#[test]
+fn send_chunked__rejected_chunks() {
+    let mut sender = ChunkSender::new();
+
+    setup();
+    builder::send_chunked(&mut sender, program(), ENCODABLE).with_chunk_size(2).execute().expect("execute() failed");
+    let chunks = recorded_chunks();
+
+    let mut assembler = ChunkAssembler::<String>::new();
+    assert!(matches!(assembler.push(program(), chunks[1].clone()), Err(ChunkError::Gap { expected: 0, index: 1, .. })));
+    assert_eq!(assembler.pending(), 0);
+
+    let mut corrupted = chunks[0].clone();
+    corrupted.data[0] ^= 1;
+    assert!(matches!(assembler.push(program(), corrupted), Err(ChunkError::Checksum { index: 0, .. })));
+    assert_eq!(assembler.pending(), 0);
+
+    // Chunks of the same transfer from another sender belong to another transfer.
+    assert_eq!(assembler.push(program(), chunks[0].clone()).unwrap(), None);
+    assert!(matches!(assembler.push(created_program(), chunks[1].clone()), Err(ChunkError::Gap { .. })));
+    assert_eq!(assembler.pending(), 1);
+
+    assert!(matches!(builder::send_chunked(&mut sender, program(), ENCODABLE).with_chunk_size(0).execute(), Err(Error::InvalidChunkSize { chunk_size: 0 })));
+}
+
+#[test]
+fn send_chunked__replayed_transfer() {
+    let mut sender = ChunkSender::new();
+
+    setup();
+    builder::send_chunked(&mut sender, program(), ENCODABLE).execute().expect("execute() failed");
+    let single = recorded_chunks();
+    builder::send_chunked(&mut sender, program(), ENCODABLE).with_chunk_size(2).execute().expect("execute() failed");
+    let chunks = recorded_chunks();
+
+    let mut assembler = ChunkAssembler::<String>::new();
+    assert_eq!(assembler.push(program(), single[0].clone()).unwrap().as_deref(), Some(ENCODABLE));
+    assert!(matches!(assembler.push(program(), single[0].clone()), Err(ChunkError::Duplicate { index: 0, .. })));
+    for chunk in &chunks {
+        assembler.push(program(), chunk.clone()).unwrap();
+    }
+    assert!(matches!(assembler.push(program(), chunks[0].clone()), Err(ChunkError::Duplicate { index: 0, .. })));
+    assert_eq!(assembler.pending(), 0);
+}
+
+#[test]
+fn send_chunked__receive() {
+    let mut sender = ChunkSender::new();
+
+    setup();
+    builder::send_chunked(&mut sender, program(), ENCODABLE).execute().expect("execute() failed");
+    let chunks = recorded_chunks();
+
+    let mut assembler = ChunkAssembler::<String>::new();
+    set_incoming(chunks[0].encode());
+    assert_eq!(assembler.receive().unwrap().as_deref(), Some(ENCODABLE));
+    assert!(matches!(assembler.receive(), Err(ChunkError::Duplicate { .. })));
+
+    set_incoming(BYTES.to_vec());
+    assert!(matches!(assembler.receive(), Err(ChunkError::Decode(_))));
+}
+
+#[test]
+fn send_chunked__transfer_ids() {
+    let (mut sender, mut other_sender) = (ChunkSender::new(), ChunkSender::new());
+
+    setup();
+    builder::send_chunked(&mut sender, program(), ENCODABLE).execute().expect("execute() failed");
+    builder::send_chunked(&mut sender, program(), ENCODABLE).execute().expect("execute() failed");
+    builder::send_chunked(&mut other_sender, program(), ENCODABLE).execute().expect("execute() failed");
+    assert_eq!(recorded_chunks().iter().map(|chunk| chunk.transfer_id).collect::<Vec<_>>(), [0, 1, 0]);
+    assert_eq!(sender.next_transfer_id(), 2);
+
+    // A chunk size that is rejected doesn't use up a transfer id.
+    assert!(builder::send_chunked(&mut sender, program(), ENCODABLE).with_chunk_size(0).execute().is_err());
+    assert_eq!(sender.next_transfer_id(), 2);
+
+    let mut exhausted = ChunkSender::decode(&mut u64::MAX.encode().as_slice()).unwrap();
+    assert!(matches!(builder::send_chunked(&mut exhausted, program(), ENCODABLE).execute(), Err(Error::TransferIdsExhausted)));
+    assert!(mock::take_calls().is_empty());
+}
+
+#[test]
+fn send_chunked__interleaved_transfers() {
+    let mut sender = ChunkSender::new();
+
+    setup();
+    builder::send_chunked(&mut sender, program(), ENCODABLE).with_chunk_size(2).execute().expect("execute() failed");
+    let first = recorded_chunks();
+    builder::send_chunked(&mut sender, program(), ENCODABLE).execute().expect("execute() failed");
+    let second = recorded_chunks();
+
+    // A transfer in progress is completed after a later one, a replayed one is rejected.
+    let mut assembler = ChunkAssembler::<String>::new();
+    assert_eq!(assembler.push(program(), first[0].clone()).unwrap(), None);
+    assert_eq!(assembler.push(program(), second[0].clone()).unwrap().as_deref(), Some(ENCODABLE));
+    assert_eq!(assembler.push(program(), first[1].clone()).unwrap(), None);
+    assert_eq!(assembler.push(program(), first[2].clone()).unwrap().as_deref(), Some(ENCODABLE));
+    assert!(matches!(assembler.push(program(), first[0].clone()), Err(ChunkError::Duplicate { transfer_id: 0, .. })));
+    assert!(matches!(assembler.push(program(), second[0].clone()), Err(ChunkError::Duplicate { transfer_id: 1, .. })));
+
+    // A transfer that starts after a later one was reassembled is rejected.
+    builder::send_chunked(&mut ChunkSender::new(), program(), ENCODABLE).execute().expect("execute() failed");
+    assert!(matches!(assembler.push(program(), recorded_chunks()[0].clone()), Err(ChunkError::Duplicate { transfer_id: 0, .. })));
+    assert_eq!(assembler.push(created_program(), second[0].clone()).unwrap().as_deref(), Some(ENCODABLE));
+    assert_eq!(assembler.pending(), 0);
+}
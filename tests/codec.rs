@@ -0,0 +1,44 @@
+//! Checks `.with_codec::<PayloadCodec>()` on payloads that only the codec can encode.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::errors::Result;
+use gstd_fluent::{
+    self as builder,
+    backend::mock::Call,
+    codec::{Codec, Decoder, Encoder},
+};
+
+use common::*;
+
+/// A payload without `Encode`.
+struct Text(&'static str);
+
+/// Sends a `Text` as its UTF-8 bytes.
+struct Utf8;
+
+impl Codec for Utf8 {}
+
+impl Encoder<Text> for Utf8 {
+    fn encode(value: &Text) -> Result<Vec<u8>> {
+        Ok(value.0.as_bytes().to_vec())
+    }
+}
+
+impl Decoder<String> for Utf8 {
+    fn decode(bytes: &[u8]) -> Result<String> {
+        String::from_utf8(bytes.to_vec()).map_err(|_| gstd::errors::ContractError::Convert("Invalid UTF-8"))
+    }
+}
+
+case!(send__with_codec__no_value__no_delay__no_gas, builder::send(program(), Text(ENCODABLE)).with_codec::<Utf8>().execute(), Call { destination: Some(program()), value: 0, ..call("send_bytes", bytes()) });
+case!(send__with_codec__value__delay__gas_limit, builder::send(program(), Text(ENCODABLE)).with_codec::<Utf8>().with_value(VALUE).with_delay(DELAY).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: VALUE, delay: Some(DELAY), gas_limit: Some(GAS_LIMIT), ..call("send_bytes_with_gas_delayed", bytes()) });
+case!(send__with_codec__for_reply__reply_deposit_auto, builder::send(program(), Text(ENCODABLE)).with_codec::<Utf8>().for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_for_reply", bytes()) });
+case!(reply__with_codec__value__no_gas, builder::reply(Text(ENCODABLE)).with_codec::<Utf8>().with_value(VALUE).execute(), Call { value: VALUE, ..call("reply_bytes", bytes()) });
+
+// Under a codec, `.with_reply_deposit_auto()` sizes the deposit from the encoded payload.
+case!(send__with_codec__for_reply_as__reply_deposit_auto, builder::send(program(), Text(ENCODABLE)).with_codec::<Utf8>().for_reply_as::<String>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT_BASE + BYTES.len() as u64 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_for_reply", bytes()) });
+case!(create_program__with_codec__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_codec::<Utf8>().for_reply_as::<String>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, reply_deposit: Some(REPLY_DEPOSIT_BASE + BYTES.len() as u64 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_for_reply", bytes()) });
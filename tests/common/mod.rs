@@ -0,0 +1,112 @@
+//! Fixtures shared by the tests that run the builders against the mock backend.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(dead_code, unused_macros, unused_imports)]
+
+use std::ops::Bound;
+
+use gstd::{ActorId, CodeId, Encode, MessageId, ReservationId};
+use gstd_fluent::{
+    backend::mock::{self, Call, IncomingMessage, Payload},
+    config::{self, Config},
+};
+
+pub const BYTES: &[u8] = b"PING";
+pub const ENCODABLE: &str = "PING";
+pub const INPUT: core::ops::Range<usize> = 1..9;
+
+pub const VALUE: u128 = 10_000;
+pub const DELAY: u32 = 3;
+pub const GAS_LIMIT: u64 = 5_000_000_000;
+pub const GAS_AVAILABLE: u64 = 80_000_000_000;
+pub const GAS_RESERVE: u64 = 1_000_000_000;
+pub const GAS_BUDGET: u64 = 40_000_000_000;
+pub const REPLY_DEPOSIT: u64 = 2_000_000_000;
+pub const REPLY_DEPOSIT_BASE: u64 = 1_000_000;
+pub const REPLY_DEPOSIT_PER_BYTE: u64 = 1_000;
+
+pub fn program() -> ActorId {
+    ActorId::from([1; 32])
+}
+
+pub fn code_id() -> CodeId {
+    CodeId::from([2; 32])
+}
+
+pub fn reservation_id() -> ReservationId {
+    ReservationId::from([3; 32])
+}
+
+/// The id of the program created first after `mock::reset()`.
+pub fn created_program() -> ActorId {
+    let mut id = [0; 32];
+    id[0] = 1;
+    ActorId::from(id)
+}
+
+pub fn bytes() -> Payload {
+    Payload::Bytes(BYTES.to_vec())
+}
+
+pub fn encodable() -> Payload {
+    Payload::Bytes(ENCODABLE.encode())
+}
+
+pub fn input() -> Payload {
+    Payload::Input(Bound::Included(INPUT.start), Bound::Excluded(INPUT.end))
+}
+
+pub fn empty() -> Payload {
+    Payload::Bytes(Vec::new())
+}
+
+pub fn call(function: &'static str, payload: Payload) -> Call {
+    Call {
+        function,
+        message_id: MessageId::default(),
+        destination: None,
+        code_id: None,
+        payload,
+        value: 0,
+        gas_limit: None,
+        delay: None,
+        reservation_id: None,
+        reply_deposit: None,
+        woken: None,
+    }
+}
+
+pub fn setup() {
+    mock::reset();
+    mock::set_gas_available(GAS_AVAILABLE);
+    config::set(Config::new().with_reply_deposit_cost(REPLY_DEPOSIT_BASE, REPLY_DEPOSIT_PER_BYTE));
+}
+
+/// Recorded message ids depend on the order of calls, so they are not compared.
+pub fn recorded_call() -> Call {
+    let mut calls = mock::take_calls();
+    assert_eq!(calls.len(), 1, "expected exactly one call, got {calls:?}");
+
+    let mut call = calls.remove(0);
+    call.message_id = MessageId::default();
+    call
+}
+
+/// Makes a message from `program()` with `VALUE` attached the message being processed.
+pub fn set_incoming(payload: Vec<u8>) {
+    mock::set_message(IncomingMessage { id: MessageId::from([4; 32]), source: program(), value: VALUE, payload });
+}
+
+macro_rules! case {
+    ($name:ident, $execute:expr, $expected:expr) => {
+        #[test]
+        fn $name() {
+            setup();
+            // The `for_reply*` futures are never polled, only the call is checked.
+            let _output = $execute.expect("execute() failed");
+            assert_eq!(recorded_call(), $expected);
+        }
+    };
+}
+
+pub(crate) use case;
@@ -0,0 +1,7 @@
+//! Checks that the builders reject forbidden transitions at compile time.
+
+#[test]
+fn compile_fail() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/ui/*/*.rs");
+}
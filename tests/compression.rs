@@ -0,0 +1,45 @@
+//! Checks `.compressed()`, `.for_reply_as_compressed::<Decodable>()` and the `compression` codec.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::Encode;
+use gstd_fluent::{
+    self as builder,
+    backend::mock::{Call, Payload},
+    compression,
+};
+
+use common::*;
+
+case!(send__compressed__no_value__no_delay__no_gas, builder::send(program(), ENCODABLE).compressed().execute(), Call { destination: Some(program()), value: 0, ..call("send_bytes", Payload::Bytes(compression::compress(&ENCODABLE.encode()))) });
+case!(send__compressed__value__gas_limit__for_reply_as_compressed__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).compressed().with_gas_limit(GAS_LIMIT).for_reply_as_compressed::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", Payload::Bytes(compression::compress(&ENCODABLE.encode()))) });
+case!(send__no_value__reservation__for_reply_as_compressed__no_reply_deposit, builder::send(program(), ENCODABLE).with_gas_from_reservation(reservation_id()).for_reply_as_compressed::<u64>().execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(0), ..call("send_bytes_from_reservation_for_reply", encodable()) });
+case!(reply__compressed__value__no_gas, builder::reply(ENCODABLE).with_value(VALUE).compressed().execute(), Call { value: VALUE, ..call("reply_bytes", Payload::Bytes(compression::compress(&ENCODABLE.encode()))) });
+
+#[test]
+fn compression__round_trip() {
+    let payload = vec![7u64; 256];
+    let compressed = compression::compress(&payload.encode());
+    assert!(compressed.starts_with(&compression::MAGIC));
+    assert!(compressed.len() < payload.encode().len());
+    assert_eq!(compression::decode::<Vec<u64>>(&compressed).unwrap(), payload);
+    // Payloads without the header are rejected, even if they decode.
+    assert!(compression::decode::<Vec<u64>>(&payload.encode()).is_err());
+    // Truncated or corrupted payloads are rejected.
+    assert!(compression::decompress(&compressed[..compression::HEADER_LEN - 1]).is_err());
+    assert!(compression::decompress(&compressed[..compressed.len() - 1]).is_err());
+}
+
+#[test]
+fn compression__load() {
+    setup();
+    set_incoming(compression::compress(&ENCODABLE.encode()));
+    assert_eq!(compression::load::<String>().unwrap(), ENCODABLE);
+
+    // The receiver expects a compressed payload, so an uncompressed one is not decoded as is.
+    set_incoming(ENCODABLE.encode());
+    assert!(compression::load::<String>().is_err());
+}
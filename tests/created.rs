@@ -0,0 +1,43 @@
+//! Checks `Created` and the errors of failed init messages.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::errors::{ErrorReplyPayload, ErrorReplyReason, SimpleExecutionError, SimpleProgramCreationError};
+use gstd_fluent::{
+    self as builder,
+    backend::mock,
+    created::Created,
+    errors::{ContractError, Error, InitFailure},
+};
+
+use common::*;
+
+#[test]
+fn created() {
+    setup();
+    mock::push_reply_encoded(ENCODABLE);
+    let future = builder::create_program(code_id(), BYTES).for_reply_as::<String>().execute().unwrap();
+    let init_message = mock::last_call().unwrap().message_id;
+    assert_eq!(mock::block_on(future).unwrap(), Created { program: created_program(), init_message, reply: ENCODABLE.to_string() });
+}
+
+#[test]
+fn created__init_failed() {
+    let create = || mock::block_on(builder::create_program(code_id(), BYTES).for_reply().execute().unwrap());
+    let error_reply = |reason| mock::push_reply_error(ContractError::ErrorReply(ErrorReplyPayload(Vec::new()), reason));
+
+    setup();
+    error_reply(ErrorReplyReason::Execution(SimpleExecutionError::RanOutOfGas));
+    assert!(matches!(create(), Err(Error::InitFailed { program, failure: InitFailure::OutOfGas }) if program == created_program()));
+    error_reply(ErrorReplyReason::Execution(SimpleExecutionError::UserspacePanic));
+    assert!(matches!(create(), Err(Error::InitFailed { failure: InitFailure::Trap(SimpleExecutionError::UserspacePanic), .. })));
+    error_reply(ErrorReplyReason::RemovedFromWaitlist);
+    assert!(matches!(create(), Err(Error::InitFailed { failure: InitFailure::ErrorReply(ErrorReplyReason::RemovedFromWaitlist), .. })));
+    error_reply(ErrorReplyReason::FailedToCreateProgram(SimpleProgramCreationError::CodeNotExists));
+    assert!(matches!(create(), Err(Error::CreationRejected { .. })));
+    mock::push_reply_error(ContractError::Timeout(0, 0));
+    assert!(matches!(create(), Err(Error::Contract(ContractError::Timeout(0, 0)))));
+}
@@ -0,0 +1,65 @@
+//! Checks `reply_result(...)`, `#[handle]` and `.for_reply_result::<Reply, Failure>()`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::Encode;
+use gstd_fluent::{
+    self as builder,
+    backend::mock::{self, Call, Payload},
+    error_reply::ErrorReply,
+};
+
+use common::*;
+
+#[gstd_fluent::handle]
+fn handle() -> Result<u64, ErrorReply> {
+    let amount: u64 = builder::incoming().decode().map_err(|_| ErrorReply::new(1, "invalid amount"))?;
+    Ok(amount.checked_mul(2).ok_or("overflow")?)
+}
+
+fn result_reply(reply: Result<u64, ErrorReply>) -> Payload {
+    Payload::Bytes(reply.encode())
+}
+
+#[test]
+fn handle_attribute() {
+    setup();
+    set_incoming(21u64.encode());
+    handle();
+    assert_eq!(recorded_call(), call("reply", result_reply(Ok(42))));
+
+    set_incoming(u64::MAX.encode());
+    handle();
+    assert_eq!(recorded_call(), call("reply", result_reply(Err(ErrorReply::new(0, "overflow")))));
+
+    set_incoming(Vec::new());
+    handle();
+    assert_eq!(recorded_call(), call("reply", result_reply(Err(ErrorReply::new(1, "invalid amount")))));
+}
+
+#[test]
+fn reply_result() {
+    setup();
+    builder::reply_result(Err::<u64, _>("denied")).with_value(VALUE).execute().expect("execute() failed");
+    assert_eq!(recorded_call(), Call { value: VALUE, ..call("reply", result_reply(Err(ErrorReply::new(0, "denied")))) });
+}
+
+case!(send__no_value__gas_limit__for_reply_result__reply_deposit, builder::send(program(), ENCODABLE).with_gas_limit(GAS_LIMIT).for_reply_result::<u64, ErrorReply>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", encodable()) });
+
+#[test]
+fn for_reply_result() {
+    let send = || mock::block_on(builder::send(program(), ENCODABLE).for_reply_result::<u64, ErrorReply>().execute().expect("execute() failed"));
+
+    setup();
+    mock::push_reply_encoded(Ok::<u64, ErrorReply>(42));
+    assert_eq!(send().unwrap(), Ok(42));
+
+    mock::push_reply_encoded(Err::<u64, _>(ErrorReply::new(3, "denied")));
+    assert_eq!(send().unwrap(), Err(ErrorReply::new(3, "denied")));
+
+    mock::push_reply_encoded(42u64);
+    assert!(send().is_err());
+}
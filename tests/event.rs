@@ -0,0 +1,58 @@
+//! Checks `builder::emit(...)`, `builder::emit_bytes(...)` and `EventDecoder`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::{ActorId, Decode, Encode};
+use gstd_fluent::{self as builder, backend::mock::Payload, event::EventDecoder};
+
+use common::*;
+
+#[derive(Debug, PartialEq, Eq, Encode, Decode)]
+#[codec(crate = gstd::codec)]
+enum Event {
+    Transferred { to: ActorId, amount: u128 },
+}
+
+#[test]
+fn emit() {
+    let event = Event::Transferred { to: program(), amount: VALUE };
+    let emitted = || {
+        let call = recorded_call();
+        assert_eq!((call.function, call.destination, call.value), ("send_bytes", Some(ActorId::zero()), 0));
+        let Payload::Bytes(bytes) = call.payload else { panic!("unexpected payload {:?}", call.payload) };
+        bytes
+    };
+
+    setup();
+    builder::emit(&event).execute().expect("execute() failed");
+    assert_eq!(EventDecoder::new().decode::<Event>(&emitted()).unwrap(), event);
+
+    builder::emit(&event).with_topic(b"token").with_version(2).execute().expect("execute() failed");
+    let bytes = emitted();
+    assert!(bytes.starts_with(b"\x14token\x02"));
+    let decoder = EventDecoder::new().with_topic(b"token").with_version(2);
+    assert!(decoder.matches(&bytes));
+    assert_eq!(decoder.decode::<Event>(&bytes).unwrap(), event);
+    assert!(EventDecoder::new().with_topic(b"token").with_version(1).decode::<Event>(&bytes).is_err());
+    // The order of the decoder methods doesn't matter.
+    assert_eq!(EventDecoder::new().with_version(2).with_topic(b"token"), decoder);
+
+    builder::emit_bytes(BYTES).with_version(1).execute().expect("execute() failed");
+    assert_eq!(emitted(), b"\x01PING");
+}
+
+#[test]
+fn emit__topic_prefix_of_another() {
+    setup();
+    builder::emit(BYTES).with_topic(b"token").execute().expect("execute() failed");
+    let Payload::Bytes(bytes) = recorded_call().payload else { panic!("the event is not encoded") };
+
+    // "tok" is a prefix of "token", but not of its encoding.
+    let decoder = EventDecoder::new().with_topic(b"tok");
+    assert!(!decoder.matches(&bytes));
+    assert!(decoder.decode::<Vec<u8>>(&bytes).is_err());
+    assert_eq!(EventDecoder::new().with_topic(b"token").decode::<Vec<u8>>(&bytes).unwrap(), BYTES);
+}
@@ -0,0 +1,48 @@
+//! Checks `builder::exit()`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::errors::ContractError;
+use gstd_fluent::{
+    self as builder,
+    backend::mock::{self, Call},
+    errors::Error,
+};
+
+use common::*;
+
+/// Runs the builder and checks that it reached `exec::exit()`.
+fn exited(execute: impl FnOnce() -> builder::errors::Result<core::convert::Infallible> + std::panic::UnwindSafe) -> Vec<Call> {
+    let panic = std::panic::catch_unwind(execute).expect_err("exec::exit() was not called");
+    assert_eq!(panic.downcast_ref::<String>().map(String::as_str), Some(mock::EXIT_MESSAGE));
+    mock::take_calls()
+}
+
+#[test]
+fn exit() {
+    setup();
+    let calls = exited(|| builder::exit().with_inheritor(program()).execute());
+    assert_eq!(calls, [Call { message_id: calls[0].message_id, destination: Some(program()), ..call("exit", empty()) }]);
+
+    let calls = exited(|| builder::exit().after_notifying([program(), created_program()], ENCODABLE).with_inheritor(program()).execute());
+    let calls = calls.into_iter().map(|call| (call.function, call.destination, call.payload)).collect::<Vec<_>>();
+    assert_eq!(calls, [
+        ("send_bytes", Some(program()), encodable()),
+        ("send_bytes", Some(created_program()), encodable()),
+        ("exit", Some(program()), empty()),
+    ]);
+}
+
+#[test]
+fn exit__failed_notification() {
+    setup();
+    mock::fail_send_after(1, ContractError::Convert("Outgoing messages limit exceeded"));
+    let result = builder::exit().after_notifying([program(), created_program()], ENCODABLE).with_inheritor(program()).execute();
+    assert!(matches!(result, Err(Error::Contract(ContractError::Convert(_)))));
+
+    // The notification sent before the failed one stays sent, the program doesn't exit.
+    assert_eq!(recorded_call(), Call { destination: Some(program()), ..call("send_bytes", encodable()) });
+}
@@ -0,0 +1,67 @@
+//! Checks `Factory`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::CodeId;
+use gstd_fluent::{
+    backend::mock::{self, Call},
+    config::MAX_PAYLOAD_SIZE,
+    errors::{ContractError, Error},
+    factory::Factory,
+};
+
+use common::*;
+
+#[test]
+fn factory() {
+    let upgraded_code_id = CodeId::from([4; 32]);
+    let mut factory = Factory::new(code_id()).with_value(VALUE).with_gas_limit(GAS_LIMIT);
+
+    setup();
+    mock::push_reply([]);
+    let program = mock::block_on(factory.spawn(ENCODABLE)).unwrap();
+    assert_eq!(program, created_program());
+    assert_eq!(recorded_call(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply", encodable()) });
+
+    mock::push_reply_error(ContractError::Timeout(0, 0));
+    assert!(mock::block_on(factory.spawn(ENCODABLE)).is_err());
+    mock::take_calls();
+    assert_eq!(factory.children().collect::<Vec<_>>(), [program]);
+
+    assert_eq!(factory.upgrade(upgraded_code_id), code_id());
+    mock::push_reply([]);
+    mock::push_reply_error(ContractError::Timeout(0, 0));
+    mock::push_reply([]);
+    let programs = mock::block_on(factory.spawn_batch([ENCODABLE; 3]));
+    let calls = mock::take_calls();
+    assert_eq!(calls.iter().map(|call| call.code_id).collect::<Vec<_>>(), [Some(upgraded_code_id); 3]);
+    let spawned = programs.iter().map(|program| program.as_ref().ok().copied()).collect::<Vec<_>>();
+    assert_eq!(spawned, [calls[0].destination, None, calls[2].destination]);
+
+    assert_eq!(factory.code_of(program), Some(code_id()));
+    assert_eq!(factory.code_of(calls[0].destination.unwrap()), Some(upgraded_code_id));
+    assert_eq!(factory.outdated().collect::<Vec<_>>(), [program]);
+    assert!(factory.remove(program));
+    assert!(!factory.remove(program));
+    assert_eq!(factory.children().count(), 2);
+}
+
+#[test]
+fn factory__spawn_batch_rejected_init() {
+    let mut factory = Factory::new(code_id());
+    let init = |len| vec![0u8; len];
+
+    setup();
+    mock::push_reply([]);
+    mock::push_reply([]);
+    let programs = mock::block_on(factory.spawn_batch([init(1), init(MAX_PAYLOAD_SIZE), init(1)]));
+    let calls = mock::take_calls();
+    assert_eq!(calls.len(), 2);
+    assert_eq!(programs[0].as_ref().ok(), calls[0].destination.as_ref());
+    assert!(matches!(programs[1], Err(Error::PayloadTooLarge { .. })));
+    assert_eq!(programs[2].as_ref().ok(), calls[1].destination.as_ref());
+    assert_eq!(factory.children().count(), 2);
+}
@@ -0,0 +1,62 @@
+//! Checks when `.with_gas_from_budget(...)` charges a `GasBudget`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+mod common;
+
+use gstd_fluent::{
+    self as builder,
+    backend::mock,
+    config::{self, MAX_PAYLOAD_SIZE},
+    errors::{Error, ValidationError},
+    gas::GasBudget,
+};
+
+use common::*;
+
+#[test]
+fn gas_budget() {
+    let mut budget = GasBudget::from_gas(GAS_BUDGET, 4);
+
+    setup();
+    // The budget is charged by `execute()`, not by the builder.
+    let _ = builder::send_bytes(program(), BYTES).with_gas_from_budget(&mut budget, 1);
+    assert_eq!((budget.remaining(), budget.remaining_weight()), (GAS_BUDGET, 4));
+
+    // A builder that fails validation doesn't charge the budget.
+    let payload = vec![0; MAX_PAYLOAD_SIZE + 1];
+    assert!(matches!(builder::send_bytes(program(), &payload).with_gas_from_budget(&mut budget, 1).execute(), Err(Error::PayloadTooLarge { .. })));
+    assert_eq!((budget.remaining(), budget.remaining_weight()), (GAS_BUDGET, 4));
+
+    builder::send_bytes(program(), BYTES).with_gas_from_budget(&mut budget, 1).execute().expect("execute() failed");
+    assert_eq!(recorded_call().gas_limit, Some(GAS_BUDGET / 4));
+
+    config::set(config::get().with_mailbox_threshold(GAS_BUDGET));
+    assert!(matches!(builder::send_bytes(program(), BYTES).with_gas_from_budget(&mut budget, 1).execute(), Err(Error::Validation(ValidationError::GasBelowMailboxThreshold { .. }))));
+    assert_eq!(budget.remaining_weight(), 3);
+
+    // A weight of 0 gets no gas, even once the rest of the budget is allotted.
+    assert_eq!(budget.allot(0).unwrap(), 0);
+    assert_eq!(budget.allot(3).unwrap(), GAS_BUDGET - GAS_BUDGET / 4);
+    assert_eq!(budget.allot(0).unwrap(), 0);
+    assert!(matches!(budget.allot(1), Err(Error::GasBudgetExceeded { remaining: 0, .. })));
+    assert_eq!(GasBudget::from_gas(GAS_BUDGET, 0).allot(0).unwrap(), 0);
+}
+
+#[test]
+fn mailbox_threshold() {
+    setup();
+    config::set(config::get().with_mailbox_threshold(GAS_LIMIT));
+
+    // The check doesn't depend on the destination: a program is treated like a user.
+    assert!(matches!(builder::send_bytes(program(), BYTES).with_gas_limit(GAS_LIMIT - 1).execute(), Err(Error::Validation(ValidationError::GasBelowMailboxThreshold { gas_limit, mailbox_threshold: GAS_LIMIT })) if gas_limit == GAS_LIMIT - 1));
+    assert!(matches!(builder::reply_bytes(BYTES).with_gas_limit(GAS_LIMIT - 1).execute(), Err(Error::Validation(ValidationError::GasBelowMailboxThreshold { gas_limit, mailbox_threshold: GAS_LIMIT })) if gas_limit == GAS_LIMIT - 1));
+    assert!(mock::take_calls().is_empty());
+
+    builder::send_bytes(program(), BYTES).with_gas_limit(GAS_LIMIT).execute().expect("execute() failed");
+    assert_eq!(recorded_call().gas_limit, Some(GAS_LIMIT));
+
+    // A message sent without a gas limit is not checked.
+    builder::send_bytes(program(), BYTES).execute().expect("execute() failed");
+    assert_eq!(recorded_call().gas_limit, None);
+}
@@ -0,0 +1,37 @@
+//! Checks `builder::incoming()` and its guards.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::{Encode, MessageId};
+use gstd_fluent::{
+    self as builder,
+    errors::{Error, GuardError},
+};
+
+use common::*;
+
+#[test]
+fn incoming() {
+    setup();
+    set_incoming(ENCODABLE.encode());
+    let incoming = builder::incoming();
+    assert_eq!((incoming.id(), incoming.source(), incoming.value()), (MessageId::from([4; 32]), program(), VALUE));
+    assert_eq!(builder::incoming().bytes().unwrap(), ENCODABLE.encode());
+    assert_eq!(builder::incoming().require_source(program()).require_min_value(VALUE).require_max_len(ENCODABLE.encoded_size()).decode::<String>().unwrap(), ENCODABLE);
+    assert!(matches!(builder::incoming().decode::<u64>(), Err(Error::Contract(_))));
+}
+
+#[test]
+fn incoming__rejected() {
+    setup();
+    set_incoming(ENCODABLE.encode());
+    assert!(matches!(builder::incoming().require_source(created_program()).bytes(), Err(Error::Guard(GuardError::UnexpectedSource { expected, actual })) if expected == created_program() && actual == program()));
+    assert!(matches!(builder::incoming().require_min_value(VALUE + 1).decode::<String>(), Err(Error::Guard(GuardError::ValueBelowMinimum { value: VALUE, min })) if min == VALUE + 1));
+    assert!(matches!(builder::incoming().require_max_len(1).bytes(), Err(Error::Guard(GuardError::PayloadTooLong { len: 5, max: 1 }))));
+
+    // The first failed guard is returned.
+    assert!(matches!(builder::incoming().require_max_len(1).require_min_value(VALUE + 1).bytes(), Err(Error::Guard(GuardError::PayloadTooLong { .. }))));
+}
@@ -0,0 +1,542 @@
+//! Checks that every reachable state of the builders calls the matching `gstd` function
+//! with the matching arguments.
+//!
+//! There is one case per combination of payload, value, delay, gas and reply settings, and
+//! `mapping__reachable_states` checks that no reachable combination is missing or repeated.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+#[cfg(feature = "scale")]
+use gstd_fluent::codec::Scale;
+use gstd_fluent::{
+    self as builder,
+    backend::mock::Call,
+    gas::GasBudget,
+};
+
+use common::*;
+
+// SendBuilder
+case!(send_bytes__no_value__no_delay__no_gas, builder::send_bytes(program(), BYTES).execute(), Call { destination: Some(program()), value: 0, ..call("send_bytes", bytes()) });
+case!(send_bytes__no_value__no_delay__gas_limit, builder::send_bytes(program(), BYTES).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), ..call("send_bytes_with_gas", bytes()) });
+case!(send_bytes__no_value__no_delay__gas_fraction, builder::send_bytes(program(), BYTES).with_gas_fraction(1, 4).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), ..call("send_bytes_with_gas", bytes()) });
+case!(send_bytes__no_value__no_delay__gas_remaining_minus, builder::send_bytes(program(), BYTES).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("send_bytes_with_gas", bytes()) });
+case!(send_bytes__no_value__no_delay__all_remaining_gas, builder::send_bytes(program(), BYTES).with_all_remaining_gas().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), ..call("send_bytes_with_gas", bytes()) });
+case!(send_bytes__no_value__no_delay__gas_from_budget, builder::send_bytes(program(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), ..call("send_bytes_with_gas", bytes()) });
+case!(send_bytes__no_value__no_delay__reservation, builder::send_bytes(program(), BYTES).with_gas_from_reservation(reservation_id()).execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), ..call("send_bytes_from_reservation", bytes()) });
+case!(send_bytes__no_value__delay__no_gas, builder::send_bytes(program(), BYTES).with_delay(DELAY).execute(), Call { destination: Some(program()), value: 0, delay: Some(DELAY), ..call("send_bytes_delayed", bytes()) });
+case!(send_bytes__no_value__delay__gas_limit, builder::send_bytes(program(), BYTES).with_delay(DELAY).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), delay: Some(DELAY), ..call("send_bytes_with_gas_delayed", bytes()) });
+case!(send_bytes__no_value__delay__gas_fraction, builder::send_bytes(program(), BYTES).with_delay(DELAY).with_gas_fraction(1, 4).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), delay: Some(DELAY), ..call("send_bytes_with_gas_delayed", bytes()) });
+case!(send_bytes__no_value__delay__gas_remaining_minus, builder::send_bytes(program(), BYTES).with_delay(DELAY).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), delay: Some(DELAY), ..call("send_bytes_with_gas_delayed", bytes()) });
+case!(send_bytes__no_value__delay__all_remaining_gas, builder::send_bytes(program(), BYTES).with_delay(DELAY).with_all_remaining_gas().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), delay: Some(DELAY), ..call("send_bytes_with_gas_delayed", bytes()) });
+case!(send_bytes__no_value__delay__gas_from_budget, builder::send_bytes(program(), BYTES).with_delay(DELAY).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), delay: Some(DELAY), ..call("send_bytes_with_gas_delayed", bytes()) });
+case!(send_bytes__no_value__delay__reservation, builder::send_bytes(program(), BYTES).with_delay(DELAY).with_gas_from_reservation(reservation_id()).execute(), Call { destination: Some(program()), value: 0, delay: Some(DELAY), reservation_id: Some(reservation_id()), ..call("send_bytes_delayed_from_reservation", bytes()) });
+case!(send_bytes__value__no_delay__no_gas, builder::send_bytes(program(), BYTES).with_value(VALUE).execute(), Call { destination: Some(program()), value: VALUE, ..call("send_bytes", bytes()) });
+case!(send_bytes__value__no_delay__gas_limit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), ..call("send_bytes_with_gas", bytes()) });
+case!(send_bytes__value__no_delay__gas_fraction, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), ..call("send_bytes_with_gas", bytes()) });
+case!(send_bytes__value__no_delay__gas_remaining_minus, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("send_bytes_with_gas", bytes()) });
+case!(send_bytes__value__no_delay__all_remaining_gas, builder::send_bytes(program(), BYTES).with_value(VALUE).with_all_remaining_gas().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), ..call("send_bytes_with_gas", bytes()) });
+case!(send_bytes__value__no_delay__gas_from_budget, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), ..call("send_bytes_with_gas", bytes()) });
+case!(send_bytes__value__no_delay__reservation, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_reservation(reservation_id()).execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), ..call("send_bytes_from_reservation", bytes()) });
+case!(send_bytes__value__delay__no_gas, builder::send_bytes(program(), BYTES).with_value(VALUE).with_delay(DELAY).execute(), Call { destination: Some(program()), value: VALUE, delay: Some(DELAY), ..call("send_bytes_delayed", bytes()) });
+case!(send_bytes__value__delay__gas_limit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_delay(DELAY).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), delay: Some(DELAY), ..call("send_bytes_with_gas_delayed", bytes()) });
+case!(send_bytes__value__delay__gas_fraction, builder::send_bytes(program(), BYTES).with_value(VALUE).with_delay(DELAY).with_gas_fraction(1, 4).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), delay: Some(DELAY), ..call("send_bytes_with_gas_delayed", bytes()) });
+case!(send_bytes__value__delay__gas_remaining_minus, builder::send_bytes(program(), BYTES).with_value(VALUE).with_delay(DELAY).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), delay: Some(DELAY), ..call("send_bytes_with_gas_delayed", bytes()) });
+case!(send_bytes__value__delay__all_remaining_gas, builder::send_bytes(program(), BYTES).with_value(VALUE).with_delay(DELAY).with_all_remaining_gas().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), delay: Some(DELAY), ..call("send_bytes_with_gas_delayed", bytes()) });
+case!(send_bytes__value__delay__gas_from_budget, builder::send_bytes(program(), BYTES).with_value(VALUE).with_delay(DELAY).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), delay: Some(DELAY), ..call("send_bytes_with_gas_delayed", bytes()) });
+case!(send_bytes__value__delay__reservation, builder::send_bytes(program(), BYTES).with_value(VALUE).with_delay(DELAY).with_gas_from_reservation(reservation_id()).execute(), Call { destination: Some(program()), value: VALUE, delay: Some(DELAY), reservation_id: Some(reservation_id()), ..call("send_bytes_delayed_from_reservation", bytes()) });
+case!(send__no_value__no_delay__no_gas, builder::send(program(), ENCODABLE).execute(), Call { destination: Some(program()), value: 0, ..call("send", encodable()) });
+case!(send__no_value__no_delay__gas_limit, builder::send(program(), ENCODABLE).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), ..call("send_with_gas", encodable()) });
+case!(send__no_value__no_delay__gas_fraction, builder::send(program(), ENCODABLE).with_gas_fraction(1, 4).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), ..call("send_with_gas", encodable()) });
+case!(send__no_value__no_delay__gas_remaining_minus, builder::send(program(), ENCODABLE).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("send_with_gas", encodable()) });
+case!(send__no_value__no_delay__all_remaining_gas, builder::send(program(), ENCODABLE).with_all_remaining_gas().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), ..call("send_with_gas", encodable()) });
+case!(send__no_value__no_delay__gas_from_budget, builder::send(program(), ENCODABLE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), ..call("send_with_gas", encodable()) });
+case!(send__no_value__no_delay__reservation, builder::send(program(), ENCODABLE).with_gas_from_reservation(reservation_id()).execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), ..call("send_from_reservation", encodable()) });
+case!(send__no_value__delay__no_gas, builder::send(program(), ENCODABLE).with_delay(DELAY).execute(), Call { destination: Some(program()), value: 0, delay: Some(DELAY), ..call("send_delayed", encodable()) });
+case!(send__no_value__delay__gas_limit, builder::send(program(), ENCODABLE).with_delay(DELAY).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), delay: Some(DELAY), ..call("send_with_gas_delayed", encodable()) });
+case!(send__no_value__delay__gas_fraction, builder::send(program(), ENCODABLE).with_delay(DELAY).with_gas_fraction(1, 4).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), delay: Some(DELAY), ..call("send_with_gas_delayed", encodable()) });
+case!(send__no_value__delay__gas_remaining_minus, builder::send(program(), ENCODABLE).with_delay(DELAY).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), delay: Some(DELAY), ..call("send_with_gas_delayed", encodable()) });
+case!(send__no_value__delay__all_remaining_gas, builder::send(program(), ENCODABLE).with_delay(DELAY).with_all_remaining_gas().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), delay: Some(DELAY), ..call("send_with_gas_delayed", encodable()) });
+case!(send__no_value__delay__gas_from_budget, builder::send(program(), ENCODABLE).with_delay(DELAY).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), delay: Some(DELAY), ..call("send_with_gas_delayed", encodable()) });
+case!(send__no_value__delay__reservation, builder::send(program(), ENCODABLE).with_delay(DELAY).with_gas_from_reservation(reservation_id()).execute(), Call { destination: Some(program()), value: 0, delay: Some(DELAY), reservation_id: Some(reservation_id()), ..call("send_delayed_from_reservation", encodable()) });
+case!(send__value__no_delay__no_gas, builder::send(program(), ENCODABLE).with_value(VALUE).execute(), Call { destination: Some(program()), value: VALUE, ..call("send", encodable()) });
+case!(send__value__no_delay__gas_limit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), ..call("send_with_gas", encodable()) });
+case!(send__value__no_delay__gas_fraction, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_fraction(1, 4).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), ..call("send_with_gas", encodable()) });
+case!(send__value__no_delay__gas_remaining_minus, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("send_with_gas", encodable()) });
+case!(send__value__no_delay__all_remaining_gas, builder::send(program(), ENCODABLE).with_value(VALUE).with_all_remaining_gas().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), ..call("send_with_gas", encodable()) });
+case!(send__value__no_delay__gas_from_budget, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), ..call("send_with_gas", encodable()) });
+case!(send__value__no_delay__reservation, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_reservation(reservation_id()).execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), ..call("send_from_reservation", encodable()) });
+case!(send__value__delay__no_gas, builder::send(program(), ENCODABLE).with_value(VALUE).with_delay(DELAY).execute(), Call { destination: Some(program()), value: VALUE, delay: Some(DELAY), ..call("send_delayed", encodable()) });
+case!(send__value__delay__gas_limit, builder::send(program(), ENCODABLE).with_value(VALUE).with_delay(DELAY).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), delay: Some(DELAY), ..call("send_with_gas_delayed", encodable()) });
+case!(send__value__delay__gas_fraction, builder::send(program(), ENCODABLE).with_value(VALUE).with_delay(DELAY).with_gas_fraction(1, 4).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), delay: Some(DELAY), ..call("send_with_gas_delayed", encodable()) });
+case!(send__value__delay__gas_remaining_minus, builder::send(program(), ENCODABLE).with_value(VALUE).with_delay(DELAY).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), delay: Some(DELAY), ..call("send_with_gas_delayed", encodable()) });
+case!(send__value__delay__all_remaining_gas, builder::send(program(), ENCODABLE).with_value(VALUE).with_delay(DELAY).with_all_remaining_gas().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), delay: Some(DELAY), ..call("send_with_gas_delayed", encodable()) });
+case!(send__value__delay__gas_from_budget, builder::send(program(), ENCODABLE).with_value(VALUE).with_delay(DELAY).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), delay: Some(DELAY), ..call("send_with_gas_delayed", encodable()) });
+case!(send__value__delay__reservation, builder::send(program(), ENCODABLE).with_value(VALUE).with_delay(DELAY).with_gas_from_reservation(reservation_id()).execute(), Call { destination: Some(program()), value: VALUE, delay: Some(DELAY), reservation_id: Some(reservation_id()), ..call("send_delayed_from_reservation", encodable()) });
+case!(send_input__no_value__no_delay__no_gas, builder::send_input(program(), INPUT).execute(), Call { destination: Some(program()), value: 0, ..call("send_input", input()) });
+case!(send_input__no_value__no_delay__gas_limit, builder::send_input(program(), INPUT).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), ..call("send_input_with_gas", input()) });
+case!(send_input__no_value__no_delay__gas_fraction, builder::send_input(program(), INPUT).with_gas_fraction(1, 4).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), ..call("send_input_with_gas", input()) });
+case!(send_input__no_value__no_delay__gas_remaining_minus, builder::send_input(program(), INPUT).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("send_input_with_gas", input()) });
+case!(send_input__no_value__no_delay__all_remaining_gas, builder::send_input(program(), INPUT).with_all_remaining_gas().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), ..call("send_input_with_gas", input()) });
+case!(send_input__no_value__no_delay__gas_from_budget, builder::send_input(program(), INPUT).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), ..call("send_input_with_gas", input()) });
+case!(send_input__no_value__delay__no_gas, builder::send_input(program(), INPUT).with_delay(DELAY).execute(), Call { destination: Some(program()), value: 0, delay: Some(DELAY), ..call("send_input_delayed", input()) });
+case!(send_input__no_value__delay__gas_limit, builder::send_input(program(), INPUT).with_delay(DELAY).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), delay: Some(DELAY), ..call("send_input_with_gas_delayed", input()) });
+case!(send_input__no_value__delay__gas_fraction, builder::send_input(program(), INPUT).with_delay(DELAY).with_gas_fraction(1, 4).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), delay: Some(DELAY), ..call("send_input_with_gas_delayed", input()) });
+case!(send_input__no_value__delay__gas_remaining_minus, builder::send_input(program(), INPUT).with_delay(DELAY).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), delay: Some(DELAY), ..call("send_input_with_gas_delayed", input()) });
+case!(send_input__no_value__delay__all_remaining_gas, builder::send_input(program(), INPUT).with_delay(DELAY).with_all_remaining_gas().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), delay: Some(DELAY), ..call("send_input_with_gas_delayed", input()) });
+case!(send_input__no_value__delay__gas_from_budget, builder::send_input(program(), INPUT).with_delay(DELAY).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), delay: Some(DELAY), ..call("send_input_with_gas_delayed", input()) });
+case!(send_input__value__no_delay__no_gas, builder::send_input(program(), INPUT).with_value(VALUE).execute(), Call { destination: Some(program()), value: VALUE, ..call("send_input", input()) });
+case!(send_input__value__no_delay__gas_limit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), ..call("send_input_with_gas", input()) });
+case!(send_input__value__no_delay__gas_fraction, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_fraction(1, 4).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), ..call("send_input_with_gas", input()) });
+case!(send_input__value__no_delay__gas_remaining_minus, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("send_input_with_gas", input()) });
+case!(send_input__value__no_delay__all_remaining_gas, builder::send_input(program(), INPUT).with_value(VALUE).with_all_remaining_gas().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), ..call("send_input_with_gas", input()) });
+case!(send_input__value__no_delay__gas_from_budget, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), ..call("send_input_with_gas", input()) });
+case!(send_input__value__delay__no_gas, builder::send_input(program(), INPUT).with_value(VALUE).with_delay(DELAY).execute(), Call { destination: Some(program()), value: VALUE, delay: Some(DELAY), ..call("send_input_delayed", input()) });
+case!(send_input__value__delay__gas_limit, builder::send_input(program(), INPUT).with_value(VALUE).with_delay(DELAY).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), delay: Some(DELAY), ..call("send_input_with_gas_delayed", input()) });
+case!(send_input__value__delay__gas_fraction, builder::send_input(program(), INPUT).with_value(VALUE).with_delay(DELAY).with_gas_fraction(1, 4).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), delay: Some(DELAY), ..call("send_input_with_gas_delayed", input()) });
+case!(send_input__value__delay__gas_remaining_minus, builder::send_input(program(), INPUT).with_value(VALUE).with_delay(DELAY).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), delay: Some(DELAY), ..call("send_input_with_gas_delayed", input()) });
+case!(send_input__value__delay__all_remaining_gas, builder::send_input(program(), INPUT).with_value(VALUE).with_delay(DELAY).with_all_remaining_gas().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), delay: Some(DELAY), ..call("send_input_with_gas_delayed", input()) });
+case!(send_input__value__delay__gas_from_budget, builder::send_input(program(), INPUT).with_value(VALUE).with_delay(DELAY).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), delay: Some(DELAY), ..call("send_input_with_gas_delayed", input()) });
+
+// SendBuilderForReply
+case!(send_bytes__no_value__no_gas__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).for_reply().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(0), ..call("send_bytes_for_reply", bytes()) });
+case!(send_bytes__no_value__no_gas__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_for_reply", bytes()) });
+case!(send_bytes__no_value__no_gas__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_for_reply", bytes()) });
+case!(send_bytes__no_value__no_gas__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(0), ..call("send_bytes_for_reply_as", bytes()) });
+case!(send_bytes__no_value__no_gas__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_for_reply_as", bytes()) });
+case!(send_bytes__no_value__no_gas__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_for_reply_as", bytes()) });
+case!(send_bytes__no_value__gas_limit__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_gas_limit(GAS_LIMIT).for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__gas_limit__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__gas_limit__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__gas_limit__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__gas_limit__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__gas_limit__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__gas_fraction__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_gas_fraction(1, 4).for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__gas_fraction__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_gas_fraction(1, 4).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__gas_fraction__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_gas_fraction(1, 4).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__gas_fraction__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_gas_fraction(1, 4).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__gas_fraction__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__gas_fraction__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__gas_remaining_minus__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_gas_remaining_minus(GAS_RESERVE).for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__gas_remaining_minus__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__gas_remaining_minus__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__gas_remaining_minus__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__gas_remaining_minus__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__gas_remaining_minus__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__all_remaining_gas__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_all_remaining_gas().for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__all_remaining_gas__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_all_remaining_gas().for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__all_remaining_gas__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_all_remaining_gas().for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__all_remaining_gas__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_all_remaining_gas().for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__all_remaining_gas__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__all_remaining_gas__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__gas_from_budget__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__gas_from_budget__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__gas_from_budget__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__no_value__gas_from_budget__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__gas_from_budget__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__gas_from_budget__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__no_value__reservation__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_gas_from_reservation(reservation_id()).for_reply().execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(0), ..call("send_bytes_from_reservation_for_reply", bytes()) });
+case!(send_bytes__no_value__reservation__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_gas_from_reservation(reservation_id()).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_from_reservation_for_reply", bytes()) });
+case!(send_bytes__no_value__reservation__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_gas_from_reservation(reservation_id()).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_from_reservation_for_reply", bytes()) });
+case!(send_bytes__no_value__reservation__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_gas_from_reservation(reservation_id()).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(0), ..call("send_bytes_from_reservation_for_reply_as", bytes()) });
+case!(send_bytes__no_value__reservation__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_gas_from_reservation(reservation_id()).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_from_reservation_for_reply_as", bytes()) });
+case!(send_bytes__no_value__reservation__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_gas_from_reservation(reservation_id()).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_from_reservation_for_reply_as", bytes()) });
+case!(send_bytes__value__no_gas__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).for_reply().execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(0), ..call("send_bytes_for_reply", bytes()) });
+case!(send_bytes__value__no_gas__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_for_reply", bytes()) });
+case!(send_bytes__value__no_gas__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_for_reply", bytes()) });
+case!(send_bytes__value__no_gas__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(0), ..call("send_bytes_for_reply_as", bytes()) });
+case!(send_bytes__value__no_gas__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_for_reply_as", bytes()) });
+case!(send_bytes__value__no_gas__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_for_reply_as", bytes()) });
+case!(send_bytes__value__gas_limit__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__gas_limit__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__gas_limit__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__gas_limit__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__gas_limit__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__gas_limit__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__gas_fraction__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__gas_fraction__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__gas_fraction__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__gas_fraction__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__gas_fraction__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__gas_fraction__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__gas_remaining_minus__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__gas_remaining_minus__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__gas_remaining_minus__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__gas_remaining_minus__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__gas_remaining_minus__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__gas_remaining_minus__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__all_remaining_gas__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_all_remaining_gas().for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__all_remaining_gas__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_all_remaining_gas().for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__all_remaining_gas__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).with_all_remaining_gas().for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__all_remaining_gas__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_all_remaining_gas().for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__all_remaining_gas__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__all_remaining_gas__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__gas_from_budget__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__gas_from_budget__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__gas_from_budget__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_with_gas_for_reply", bytes()) });
+case!(send_bytes__value__gas_from_budget__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__gas_from_budget__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__gas_from_budget__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_with_gas_for_reply_as", bytes()) });
+case!(send_bytes__value__reservation__for_reply__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_reservation(reservation_id()).for_reply().execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), reply_deposit: Some(0), ..call("send_bytes_from_reservation_for_reply", bytes()) });
+case!(send_bytes__value__reservation__for_reply__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_reservation(reservation_id()).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_from_reservation_for_reply", bytes()) });
+case!(send_bytes__value__reservation__for_reply__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_reservation(reservation_id()).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_bytes_from_reservation_for_reply", bytes()) });
+case!(send_bytes__value__reservation__for_reply_as__no_reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_reservation(reservation_id()).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), reply_deposit: Some(0), ..call("send_bytes_from_reservation_for_reply_as", bytes()) });
+case!(send_bytes__value__reservation__for_reply_as__reply_deposit, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_reservation(reservation_id()).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_from_reservation_for_reply_as", bytes()) });
+case!(send_bytes__value__reservation__for_reply_as__reply_deposit_auto, builder::send_bytes(program(), BYTES).with_value(VALUE).with_gas_from_reservation(reservation_id()).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_bytes_from_reservation_for_reply_as", bytes()) });
+case!(send__no_value__no_gas__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).for_reply().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(0), ..call("send_for_reply", encodable()) });
+case!(send__no_value__no_gas__for_reply__reply_deposit, builder::send(program(), ENCODABLE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT), ..call("send_for_reply", encodable()) });
+case!(send__no_value__no_gas__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_for_reply", encodable()) });
+case!(send__no_value__no_gas__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(0), ..call("send_for_reply_as", encodable()) });
+case!(send__no_value__no_gas__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT), ..call("send_for_reply_as", encodable()) });
+case!(send__no_value__no_gas__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_for_reply_as", encodable()) });
+case!(send__no_value__gas_limit__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_gas_limit(GAS_LIMIT).for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__gas_limit__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__gas_limit__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__gas_limit__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__gas_limit__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__gas_limit__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__gas_fraction__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_gas_fraction(1, 4).for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__gas_fraction__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_gas_fraction(1, 4).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__gas_fraction__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_gas_fraction(1, 4).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__gas_fraction__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_gas_fraction(1, 4).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__gas_fraction__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__gas_fraction__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__gas_remaining_minus__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_gas_remaining_minus(GAS_RESERVE).for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__gas_remaining_minus__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__gas_remaining_minus__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__gas_remaining_minus__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__gas_remaining_minus__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__gas_remaining_minus__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__all_remaining_gas__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_all_remaining_gas().for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__all_remaining_gas__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_all_remaining_gas().for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__all_remaining_gas__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_all_remaining_gas().for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__all_remaining_gas__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_all_remaining_gas().for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__all_remaining_gas__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__all_remaining_gas__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__gas_from_budget__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__gas_from_budget__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__gas_from_budget__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__no_value__gas_from_budget__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__gas_from_budget__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__gas_from_budget__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__no_value__reservation__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_gas_from_reservation(reservation_id()).for_reply().execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(0), ..call("send_from_reservation_for_reply", encodable()) });
+case!(send__no_value__reservation__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_gas_from_reservation(reservation_id()).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_from_reservation_for_reply", encodable()) });
+case!(send__no_value__reservation__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_gas_from_reservation(reservation_id()).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_from_reservation_for_reply", encodable()) });
+case!(send__no_value__reservation__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_gas_from_reservation(reservation_id()).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(0), ..call("send_from_reservation_for_reply_as", encodable()) });
+case!(send__no_value__reservation__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_gas_from_reservation(reservation_id()).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_from_reservation_for_reply_as", encodable()) });
+case!(send__no_value__reservation__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_gas_from_reservation(reservation_id()).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_from_reservation_for_reply_as", encodable()) });
+case!(send__value__no_gas__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).for_reply().execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(0), ..call("send_for_reply", encodable()) });
+case!(send__value__no_gas__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT), ..call("send_for_reply", encodable()) });
+case!(send__value__no_gas__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_for_reply", encodable()) });
+case!(send__value__no_gas__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(0), ..call("send_for_reply_as", encodable()) });
+case!(send__value__no_gas__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT), ..call("send_for_reply_as", encodable()) });
+case!(send__value__no_gas__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_for_reply_as", encodable()) });
+case!(send__value__gas_limit__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__gas_limit__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__gas_limit__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__gas_limit__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__gas_limit__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__gas_limit__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__gas_fraction__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_fraction(1, 4).for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__gas_fraction__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_fraction(1, 4).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__gas_fraction__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_fraction(1, 4).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__gas_fraction__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_fraction(1, 4).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__gas_fraction__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__gas_fraction__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__gas_remaining_minus__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__gas_remaining_minus__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__gas_remaining_minus__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__gas_remaining_minus__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__gas_remaining_minus__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__gas_remaining_minus__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__all_remaining_gas__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_all_remaining_gas().for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__all_remaining_gas__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_all_remaining_gas().for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__all_remaining_gas__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).with_all_remaining_gas().for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__all_remaining_gas__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_all_remaining_gas().for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__all_remaining_gas__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__all_remaining_gas__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__gas_from_budget__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__gas_from_budget__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__gas_from_budget__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_with_gas_for_reply", encodable()) });
+case!(send__value__gas_from_budget__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__gas_from_budget__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__gas_from_budget__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_with_gas_for_reply_as", encodable()) });
+case!(send__value__reservation__for_reply__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_reservation(reservation_id()).for_reply().execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), reply_deposit: Some(0), ..call("send_from_reservation_for_reply", encodable()) });
+case!(send__value__reservation__for_reply__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_reservation(reservation_id()).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_from_reservation_for_reply", encodable()) });
+case!(send__value__reservation__for_reply__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_reservation(reservation_id()).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_from_reservation_for_reply", encodable()) });
+case!(send__value__reservation__for_reply_as__no_reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_reservation(reservation_id()).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), reply_deposit: Some(0), ..call("send_from_reservation_for_reply_as", encodable()) });
+case!(send__value__reservation__for_reply_as__reply_deposit, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_reservation(reservation_id()).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_from_reservation_for_reply_as", encodable()) });
+case!(send__value__reservation__for_reply_as__reply_deposit_auto, builder::send(program(), ENCODABLE).with_value(VALUE).with_gas_from_reservation(reservation_id()).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_from_reservation_for_reply_as", encodable()) });
+case!(send_input__no_value__no_gas__for_reply__no_reply_deposit, builder::send_input(program(), INPUT).for_reply().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(0), ..call("send_input_for_reply", input()) });
+case!(send_input__no_value__no_gas__for_reply__reply_deposit, builder::send_input(program(), INPUT).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_for_reply", input()) });
+case!(send_input__no_value__no_gas__for_reply__reply_deposit_auto, builder::send_input(program(), INPUT).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_input_for_reply", input()) });
+case!(send_input__no_value__no_gas__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(0), ..call("send_input_for_reply_as", input()) });
+case!(send_input__no_value__no_gas__for_reply_as__reply_deposit, builder::send_input(program(), INPUT).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_for_reply_as", input()) });
+case!(send_input__no_value__no_gas__for_reply_as__reply_deposit_auto, builder::send_input(program(), INPUT).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_input_for_reply_as", input()) });
+case!(send_input__no_value__gas_limit__for_reply__no_reply_deposit, builder::send_input(program(), INPUT).with_gas_limit(GAS_LIMIT).for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__gas_limit__for_reply__reply_deposit, builder::send_input(program(), INPUT).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__gas_limit__for_reply__reply_deposit_auto, builder::send_input(program(), INPUT).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__gas_limit__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__gas_limit__for_reply_as__reply_deposit, builder::send_input(program(), INPUT).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__gas_limit__for_reply_as__reply_deposit_auto, builder::send_input(program(), INPUT).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__gas_fraction__for_reply__no_reply_deposit, builder::send_input(program(), INPUT).with_gas_fraction(1, 4).for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__gas_fraction__for_reply__reply_deposit, builder::send_input(program(), INPUT).with_gas_fraction(1, 4).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__gas_fraction__for_reply__reply_deposit_auto, builder::send_input(program(), INPUT).with_gas_fraction(1, 4).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__gas_fraction__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).with_gas_fraction(1, 4).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__gas_fraction__for_reply_as__reply_deposit, builder::send_input(program(), INPUT).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__gas_fraction__for_reply_as__reply_deposit_auto, builder::send_input(program(), INPUT).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__gas_remaining_minus__for_reply__no_reply_deposit, builder::send_input(program(), INPUT).with_gas_remaining_minus(GAS_RESERVE).for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__gas_remaining_minus__for_reply__reply_deposit, builder::send_input(program(), INPUT).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__gas_remaining_minus__for_reply__reply_deposit_auto, builder::send_input(program(), INPUT).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__gas_remaining_minus__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__gas_remaining_minus__for_reply_as__reply_deposit, builder::send_input(program(), INPUT).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__gas_remaining_minus__for_reply_as__reply_deposit_auto, builder::send_input(program(), INPUT).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__all_remaining_gas__for_reply__no_reply_deposit, builder::send_input(program(), INPUT).with_all_remaining_gas().for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__all_remaining_gas__for_reply__reply_deposit, builder::send_input(program(), INPUT).with_all_remaining_gas().for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__all_remaining_gas__for_reply__reply_deposit_auto, builder::send_input(program(), INPUT).with_all_remaining_gas().for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__all_remaining_gas__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).with_all_remaining_gas().for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__all_remaining_gas__for_reply_as__reply_deposit, builder::send_input(program(), INPUT).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__all_remaining_gas__for_reply_as__reply_deposit_auto, builder::send_input(program(), INPUT).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__gas_from_budget__for_reply__no_reply_deposit, builder::send_input(program(), INPUT).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__gas_from_budget__for_reply__reply_deposit, builder::send_input(program(), INPUT).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__gas_from_budget__for_reply__reply_deposit_auto, builder::send_input(program(), INPUT).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__no_value__gas_from_budget__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__gas_from_budget__for_reply_as__reply_deposit, builder::send_input(program(), INPUT).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__no_value__gas_from_budget__for_reply_as__reply_deposit_auto, builder::send_input(program(), INPUT).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__no_gas__for_reply__no_reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).for_reply().execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(0), ..call("send_input_for_reply", input()) });
+case!(send_input__value__no_gas__for_reply__reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_for_reply", input()) });
+case!(send_input__value__no_gas__for_reply__reply_deposit_auto, builder::send_input(program(), INPUT).with_value(VALUE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_input_for_reply", input()) });
+case!(send_input__value__no_gas__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(0), ..call("send_input_for_reply_as", input()) });
+case!(send_input__value__no_gas__for_reply_as__reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_for_reply_as", input()) });
+case!(send_input__value__no_gas__for_reply_as__reply_deposit_auto, builder::send_input(program(), INPUT).with_value(VALUE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_input_for_reply_as", input()) });
+case!(send_input__value__gas_limit__for_reply__no_reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__gas_limit__for_reply__reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__gas_limit__for_reply__reply_deposit_auto, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__gas_limit__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__gas_limit__for_reply_as__reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__gas_limit__for_reply_as__reply_deposit_auto, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__gas_fraction__for_reply__no_reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_fraction(1, 4).for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__gas_fraction__for_reply__reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_fraction(1, 4).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__gas_fraction__for_reply__reply_deposit_auto, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_fraction(1, 4).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__gas_fraction__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_fraction(1, 4).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__gas_fraction__for_reply_as__reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__gas_fraction__for_reply_as__reply_deposit_auto, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__gas_remaining_minus__for_reply__no_reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__gas_remaining_minus__for_reply__reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__gas_remaining_minus__for_reply__reply_deposit_auto, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__gas_remaining_minus__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__gas_remaining_minus__for_reply_as__reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__gas_remaining_minus__for_reply_as__reply_deposit_auto, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__all_remaining_gas__for_reply__no_reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_all_remaining_gas().for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__all_remaining_gas__for_reply__reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_all_remaining_gas().for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__all_remaining_gas__for_reply__reply_deposit_auto, builder::send_input(program(), INPUT).with_value(VALUE).with_all_remaining_gas().for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__all_remaining_gas__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_all_remaining_gas().for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__all_remaining_gas__for_reply_as__reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__all_remaining_gas__for_reply_as__reply_deposit_auto, builder::send_input(program(), INPUT).with_value(VALUE).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__gas_from_budget__for_reply__no_reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__gas_from_budget__for_reply__reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__gas_from_budget__for_reply__reply_deposit_auto, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("send_input_with_gas_for_reply", input()) });
+case!(send_input__value__gas_from_budget__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__gas_from_budget__for_reply_as__reply_deposit, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_input_with_gas_for_reply_as", input()) });
+case!(send_input__value__gas_from_budget__for_reply_as__reply_deposit_auto, builder::send_input(program(), INPUT).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("send_input_with_gas_for_reply_as", input()) });
+
+// ReplyBuilder
+case!(reply_bytes__no_value__no_gas, builder::reply_bytes(BYTES).execute(), Call { value: 0, ..call("reply_bytes", bytes()) });
+case!(reply_bytes__no_value__gas_limit, builder::reply_bytes(BYTES).with_gas_limit(GAS_LIMIT).execute(), Call { value: 0, gas_limit: Some(GAS_LIMIT), ..call("reply_bytes_with_gas", bytes()) });
+case!(reply_bytes__no_value__gas_fraction, builder::reply_bytes(BYTES).with_gas_fraction(1, 4).execute(), Call { value: 0, gas_limit: Some(GAS_AVAILABLE / 4), ..call("reply_bytes_with_gas", bytes()) });
+case!(reply_bytes__no_value__gas_remaining_minus, builder::reply_bytes(BYTES).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("reply_bytes_with_gas", bytes()) });
+case!(reply_bytes__no_value__all_remaining_gas, builder::reply_bytes(BYTES).with_all_remaining_gas().execute(), Call { value: 0, gas_limit: Some(GAS_AVAILABLE), ..call("reply_bytes_with_gas", bytes()) });
+case!(reply_bytes__no_value__gas_from_budget, builder::reply_bytes(BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { value: 0, gas_limit: Some(GAS_BUDGET / 2), ..call("reply_bytes_with_gas", bytes()) });
+case!(reply_bytes__no_value__reservation, builder::reply_bytes(BYTES).with_gas_from_reservation(reservation_id()).execute(), Call { value: 0, reservation_id: Some(reservation_id()), ..call("reply_bytes_from_reservation", bytes()) });
+case!(reply_bytes__value__no_gas, builder::reply_bytes(BYTES).with_value(VALUE).execute(), Call { value: VALUE, ..call("reply_bytes", bytes()) });
+case!(reply_bytes__value__gas_limit, builder::reply_bytes(BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).execute(), Call { value: VALUE, gas_limit: Some(GAS_LIMIT), ..call("reply_bytes_with_gas", bytes()) });
+case!(reply_bytes__value__gas_fraction, builder::reply_bytes(BYTES).with_value(VALUE).with_gas_fraction(1, 4).execute(), Call { value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), ..call("reply_bytes_with_gas", bytes()) });
+case!(reply_bytes__value__gas_remaining_minus, builder::reply_bytes(BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("reply_bytes_with_gas", bytes()) });
+case!(reply_bytes__value__all_remaining_gas, builder::reply_bytes(BYTES).with_value(VALUE).with_all_remaining_gas().execute(), Call { value: VALUE, gas_limit: Some(GAS_AVAILABLE), ..call("reply_bytes_with_gas", bytes()) });
+case!(reply_bytes__value__gas_from_budget, builder::reply_bytes(BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { value: VALUE, gas_limit: Some(GAS_BUDGET / 2), ..call("reply_bytes_with_gas", bytes()) });
+case!(reply_bytes__value__reservation, builder::reply_bytes(BYTES).with_value(VALUE).with_gas_from_reservation(reservation_id()).execute(), Call { value: VALUE, reservation_id: Some(reservation_id()), ..call("reply_bytes_from_reservation", bytes()) });
+case!(reply__no_value__no_gas, builder::reply(ENCODABLE).execute(), Call { value: 0, ..call("reply", encodable()) });
+case!(reply__no_value__gas_limit, builder::reply(ENCODABLE).with_gas_limit(GAS_LIMIT).execute(), Call { value: 0, gas_limit: Some(GAS_LIMIT), ..call("reply_with_gas", encodable()) });
+case!(reply__no_value__gas_fraction, builder::reply(ENCODABLE).with_gas_fraction(1, 4).execute(), Call { value: 0, gas_limit: Some(GAS_AVAILABLE / 4), ..call("reply_with_gas", encodable()) });
+case!(reply__no_value__gas_remaining_minus, builder::reply(ENCODABLE).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("reply_with_gas", encodable()) });
+case!(reply__no_value__all_remaining_gas, builder::reply(ENCODABLE).with_all_remaining_gas().execute(), Call { value: 0, gas_limit: Some(GAS_AVAILABLE), ..call("reply_with_gas", encodable()) });
+case!(reply__no_value__gas_from_budget, builder::reply(ENCODABLE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { value: 0, gas_limit: Some(GAS_BUDGET / 2), ..call("reply_with_gas", encodable()) });
+case!(reply__no_value__reservation, builder::reply(ENCODABLE).with_gas_from_reservation(reservation_id()).execute(), Call { value: 0, reservation_id: Some(reservation_id()), ..call("reply_from_reservation", encodable()) });
+case!(reply__value__no_gas, builder::reply(ENCODABLE).with_value(VALUE).execute(), Call { value: VALUE, ..call("reply", encodable()) });
+case!(reply__value__gas_limit, builder::reply(ENCODABLE).with_value(VALUE).with_gas_limit(GAS_LIMIT).execute(), Call { value: VALUE, gas_limit: Some(GAS_LIMIT), ..call("reply_with_gas", encodable()) });
+case!(reply__value__gas_fraction, builder::reply(ENCODABLE).with_value(VALUE).with_gas_fraction(1, 4).execute(), Call { value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), ..call("reply_with_gas", encodable()) });
+case!(reply__value__gas_remaining_minus, builder::reply(ENCODABLE).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("reply_with_gas", encodable()) });
+case!(reply__value__all_remaining_gas, builder::reply(ENCODABLE).with_value(VALUE).with_all_remaining_gas().execute(), Call { value: VALUE, gas_limit: Some(GAS_AVAILABLE), ..call("reply_with_gas", encodable()) });
+case!(reply__value__gas_from_budget, builder::reply(ENCODABLE).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { value: VALUE, gas_limit: Some(GAS_BUDGET / 2), ..call("reply_with_gas", encodable()) });
+case!(reply__value__reservation, builder::reply(ENCODABLE).with_value(VALUE).with_gas_from_reservation(reservation_id()).execute(), Call { value: VALUE, reservation_id: Some(reservation_id()), ..call("reply_from_reservation", encodable()) });
+case!(reply_input__no_value__no_gas, builder::reply_input(INPUT).execute(), Call { value: 0, ..call("reply_input", input()) });
+case!(reply_input__no_value__gas_limit, builder::reply_input(INPUT).with_gas_limit(GAS_LIMIT).execute(), Call { value: 0, gas_limit: Some(GAS_LIMIT), ..call("reply_input_with_gas", input()) });
+case!(reply_input__no_value__gas_fraction, builder::reply_input(INPUT).with_gas_fraction(1, 4).execute(), Call { value: 0, gas_limit: Some(GAS_AVAILABLE / 4), ..call("reply_input_with_gas", input()) });
+case!(reply_input__no_value__gas_remaining_minus, builder::reply_input(INPUT).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("reply_input_with_gas", input()) });
+case!(reply_input__no_value__all_remaining_gas, builder::reply_input(INPUT).with_all_remaining_gas().execute(), Call { value: 0, gas_limit: Some(GAS_AVAILABLE), ..call("reply_input_with_gas", input()) });
+case!(reply_input__no_value__gas_from_budget, builder::reply_input(INPUT).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { value: 0, gas_limit: Some(GAS_BUDGET / 2), ..call("reply_input_with_gas", input()) });
+case!(reply_input__value__no_gas, builder::reply_input(INPUT).with_value(VALUE).execute(), Call { value: VALUE, ..call("reply_input", input()) });
+case!(reply_input__value__gas_limit, builder::reply_input(INPUT).with_value(VALUE).with_gas_limit(GAS_LIMIT).execute(), Call { value: VALUE, gas_limit: Some(GAS_LIMIT), ..call("reply_input_with_gas", input()) });
+case!(reply_input__value__gas_fraction, builder::reply_input(INPUT).with_value(VALUE).with_gas_fraction(1, 4).execute(), Call { value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), ..call("reply_input_with_gas", input()) });
+case!(reply_input__value__gas_remaining_minus, builder::reply_input(INPUT).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("reply_input_with_gas", input()) });
+case!(reply_input__value__all_remaining_gas, builder::reply_input(INPUT).with_value(VALUE).with_all_remaining_gas().execute(), Call { value: VALUE, gas_limit: Some(GAS_AVAILABLE), ..call("reply_input_with_gas", input()) });
+case!(reply_input__value__gas_from_budget, builder::reply_input(INPUT).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { value: VALUE, gas_limit: Some(GAS_BUDGET / 2), ..call("reply_input_with_gas", input()) });
+
+// CreateProgramBuilder
+case!(create_program__no_value__no_delay__no_gas, builder::create_program(code_id(), BYTES).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, ..call("create_program", bytes()) });
+case!(create_program__no_value__no_delay__gas_limit, builder::create_program(code_id(), BYTES).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_LIMIT), ..call("create_program_with_gas", bytes()) });
+case!(create_program__no_value__no_delay__gas_fraction, builder::create_program(code_id(), BYTES).with_gas_fraction(1, 4).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), ..call("create_program_with_gas", bytes()) });
+case!(create_program__no_value__no_delay__gas_remaining_minus, builder::create_program(code_id(), BYTES).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("create_program_with_gas", bytes()) });
+case!(create_program__no_value__no_delay__all_remaining_gas, builder::create_program(code_id(), BYTES).with_all_remaining_gas().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE), ..call("create_program_with_gas", bytes()) });
+case!(create_program__no_value__no_delay__gas_from_budget, builder::create_program(code_id(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_BUDGET / 2), ..call("create_program_with_gas", bytes()) });
+case!(create_program__no_value__delay__no_gas, builder::create_program(code_id(), BYTES).with_delay(DELAY).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, delay: Some(DELAY), ..call("create_program_delayed", bytes()) });
+case!(create_program__no_value__delay__gas_limit, builder::create_program(code_id(), BYTES).with_delay(DELAY).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_LIMIT), delay: Some(DELAY), ..call("create_program_with_gas_delayed", bytes()) });
+case!(create_program__no_value__delay__gas_fraction, builder::create_program(code_id(), BYTES).with_delay(DELAY).with_gas_fraction(1, 4).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), delay: Some(DELAY), ..call("create_program_with_gas_delayed", bytes()) });
+case!(create_program__no_value__delay__gas_remaining_minus, builder::create_program(code_id(), BYTES).with_delay(DELAY).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), delay: Some(DELAY), ..call("create_program_with_gas_delayed", bytes()) });
+case!(create_program__no_value__delay__all_remaining_gas, builder::create_program(code_id(), BYTES).with_delay(DELAY).with_all_remaining_gas().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE), delay: Some(DELAY), ..call("create_program_with_gas_delayed", bytes()) });
+case!(create_program__no_value__delay__gas_from_budget, builder::create_program(code_id(), BYTES).with_delay(DELAY).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_BUDGET / 2), delay: Some(DELAY), ..call("create_program_with_gas_delayed", bytes()) });
+case!(create_program__value__no_delay__no_gas, builder::create_program(code_id(), BYTES).with_value(VALUE).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, ..call("create_program", bytes()) });
+case!(create_program__value__no_delay__gas_limit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_LIMIT), ..call("create_program_with_gas", bytes()) });
+case!(create_program__value__no_delay__gas_fraction, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), ..call("create_program_with_gas", bytes()) });
+case!(create_program__value__no_delay__gas_remaining_minus, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), ..call("create_program_with_gas", bytes()) });
+case!(create_program__value__no_delay__all_remaining_gas, builder::create_program(code_id(), BYTES).with_value(VALUE).with_all_remaining_gas().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), ..call("create_program_with_gas", bytes()) });
+case!(create_program__value__no_delay__gas_from_budget, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), ..call("create_program_with_gas", bytes()) });
+case!(create_program__value__delay__no_gas, builder::create_program(code_id(), BYTES).with_value(VALUE).with_delay(DELAY).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, delay: Some(DELAY), ..call("create_program_delayed", bytes()) });
+case!(create_program__value__delay__gas_limit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_delay(DELAY).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_LIMIT), delay: Some(DELAY), ..call("create_program_with_gas_delayed", bytes()) });
+case!(create_program__value__delay__gas_fraction, builder::create_program(code_id(), BYTES).with_value(VALUE).with_delay(DELAY).with_gas_fraction(1, 4).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), delay: Some(DELAY), ..call("create_program_with_gas_delayed", bytes()) });
+case!(create_program__value__delay__gas_remaining_minus, builder::create_program(code_id(), BYTES).with_value(VALUE).with_delay(DELAY).with_gas_remaining_minus(GAS_RESERVE).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), delay: Some(DELAY), ..call("create_program_with_gas_delayed", bytes()) });
+case!(create_program__value__delay__all_remaining_gas, builder::create_program(code_id(), BYTES).with_value(VALUE).with_delay(DELAY).with_all_remaining_gas().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), delay: Some(DELAY), ..call("create_program_with_gas_delayed", bytes()) });
+case!(create_program__value__delay__gas_from_budget, builder::create_program(code_id(), BYTES).with_value(VALUE).with_delay(DELAY).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), delay: Some(DELAY), ..call("create_program_with_gas_delayed", bytes()) });
+
+// CreateProgramBuilderForReply
+case!(create_program__no_value__no_gas__for_reply__no_reply_deposit, builder::create_program(code_id(), BYTES).for_reply().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, reply_deposit: Some(0), ..call("create_program_for_reply", bytes()) });
+case!(create_program__no_value__no_gas__for_reply__reply_deposit, builder::create_program(code_id(), BYTES).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_for_reply", bytes()) });
+case!(create_program__no_value__no_gas__for_reply__reply_deposit_auto, builder::create_program(code_id(), BYTES).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("create_program_for_reply", bytes()) });
+case!(create_program__no_value__no_gas__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, reply_deposit: Some(0), ..call("create_program_for_reply_as", bytes()) });
+case!(create_program__no_value__no_gas__for_reply_as__reply_deposit, builder::create_program(code_id(), BYTES).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_for_reply_as", bytes()) });
+case!(create_program__no_value__no_gas__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_for_reply_as", bytes()) });
+case!(create_program__no_value__gas_limit__for_reply__no_reply_deposit, builder::create_program(code_id(), BYTES).with_gas_limit(GAS_LIMIT).for_reply().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__gas_limit__for_reply__reply_deposit, builder::create_program(code_id(), BYTES).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__gas_limit__for_reply__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__gas_limit__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__gas_limit__for_reply_as__reply_deposit, builder::create_program(code_id(), BYTES).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__gas_limit__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__gas_fraction__for_reply__no_reply_deposit, builder::create_program(code_id(), BYTES).with_gas_fraction(1, 4).for_reply().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__gas_fraction__for_reply__reply_deposit, builder::create_program(code_id(), BYTES).with_gas_fraction(1, 4).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__gas_fraction__for_reply__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_gas_fraction(1, 4).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__gas_fraction__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).with_gas_fraction(1, 4).for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__gas_fraction__for_reply_as__reply_deposit, builder::create_program(code_id(), BYTES).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__gas_fraction__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__gas_remaining_minus__for_reply__no_reply_deposit, builder::create_program(code_id(), BYTES).with_gas_remaining_minus(GAS_RESERVE).for_reply().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__gas_remaining_minus__for_reply__reply_deposit, builder::create_program(code_id(), BYTES).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__gas_remaining_minus__for_reply__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__gas_remaining_minus__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__gas_remaining_minus__for_reply_as__reply_deposit, builder::create_program(code_id(), BYTES).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__gas_remaining_minus__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__all_remaining_gas__for_reply__no_reply_deposit, builder::create_program(code_id(), BYTES).with_all_remaining_gas().for_reply().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__all_remaining_gas__for_reply__reply_deposit, builder::create_program(code_id(), BYTES).with_all_remaining_gas().for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__all_remaining_gas__for_reply__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_all_remaining_gas().for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__all_remaining_gas__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).with_all_remaining_gas().for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__all_remaining_gas__for_reply_as__reply_deposit, builder::create_program(code_id(), BYTES).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__all_remaining_gas__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__gas_from_budget__for_reply__no_reply_deposit, builder::create_program(code_id(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__gas_from_budget__for_reply__reply_deposit, builder::create_program(code_id(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__gas_from_budget__for_reply__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__no_value__gas_from_budget__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__gas_from_budget__for_reply_as__reply_deposit, builder::create_program(code_id(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__no_value__gas_from_budget__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__no_gas__for_reply__no_reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).for_reply().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, reply_deposit: Some(0), ..call("create_program_for_reply", bytes()) });
+case!(create_program__value__no_gas__for_reply__reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_for_reply", bytes()) });
+case!(create_program__value__no_gas__for_reply__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_value(VALUE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("create_program_for_reply", bytes()) });
+case!(create_program__value__no_gas__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, reply_deposit: Some(0), ..call("create_program_for_reply_as", bytes()) });
+case!(create_program__value__no_gas__for_reply_as__reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_for_reply_as", bytes()) });
+case!(create_program__value__no_gas__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_value(VALUE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_for_reply_as", bytes()) });
+case!(create_program__value__gas_limit__for_reply__no_reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__gas_limit__for_reply__reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__gas_limit__for_reply__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__gas_limit__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__gas_limit__for_reply_as__reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__gas_limit__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__gas_fraction__for_reply__no_reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).for_reply().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__gas_fraction__for_reply__reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__gas_fraction__for_reply__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__gas_fraction__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__gas_fraction__for_reply_as__reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__gas_fraction__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_fraction(1, 4).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE / 4), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__gas_remaining_minus__for_reply__no_reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__gas_remaining_minus__for_reply__reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__gas_remaining_minus__for_reply__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__gas_remaining_minus__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__gas_remaining_minus__for_reply_as__reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__gas_remaining_minus__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_remaining_minus(GAS_RESERVE).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE - GAS_RESERVE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__all_remaining_gas__for_reply__no_reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_all_remaining_gas().for_reply().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__all_remaining_gas__for_reply__reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_all_remaining_gas().for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__all_remaining_gas__for_reply__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_value(VALUE).with_all_remaining_gas().for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__all_remaining_gas__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_all_remaining_gas().for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__all_remaining_gas__for_reply_as__reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__all_remaining_gas__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_value(VALUE).with_all_remaining_gas().for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_AVAILABLE), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__gas_from_budget__for_reply__no_reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__gas_from_budget__for_reply__reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__gas_from_budget__for_reply__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE), ..call("create_program_with_gas_for_reply", bytes()) });
+case!(create_program__value__gas_from_budget__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(0), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__gas_from_budget__for_reply_as__reply_deposit, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT), ..call("create_program_with_gas_for_reply_as", bytes()) });
+case!(create_program__value__gas_from_budget__for_reply_as__reply_deposit_auto, builder::create_program(code_id(), BYTES).with_value(VALUE).with_gas_from_budget(&mut GasBudget::from_gas(GAS_BUDGET, 2), 1).for_reply_as::<u64>().with_reply_deposit_auto().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: VALUE, gas_limit: Some(GAS_BUDGET / 2), reply_deposit: Some(REPLY_DEPOSIT_BASE + 8 * REPLY_DEPOSIT_PER_BYTE), ..call("create_program_with_gas_for_reply_as", bytes()) });
+
+// `.with_codec::<PayloadCodec>()`, checked with `Scale` which encodes like the plain builders
+#[cfg(feature = "scale")] case!(send_with__no_value__no_delay__no_gas, builder::send_with::<Scale, _>(program(), ENCODABLE).execute(), Call { destination: Some(program()), value: 0, ..call("send_bytes", encodable()) });
+#[cfg(feature = "scale")] case!(send_with__value__delay__reservation, builder::send_with::<Scale, _>(program(), ENCODABLE).with_value(VALUE).with_delay(DELAY).with_gas_from_reservation(reservation_id()).execute(), Call { destination: Some(program()), value: VALUE, delay: Some(DELAY), reservation_id: Some(reservation_id()), ..call("send_bytes_delayed_from_reservation", encodable()) });
+#[cfg(feature = "scale")] case!(send_with__no_value__gas_limit__for_reply_as__reply_deposit, builder::send_with::<Scale, _>(program(), ENCODABLE).with_gas_limit(GAS_LIMIT).for_reply_as::<u64>().with_reply_deposit(REPLY_DEPOSIT).execute(), Call { destination: Some(program()), value: 0, gas_limit: Some(GAS_LIMIT), reply_deposit: Some(REPLY_DEPOSIT), ..call("send_bytes_with_gas_for_reply", encodable()) });
+#[cfg(feature = "scale")] case!(send_bytes__with_codec__delay__no_gas, builder::send_bytes(program(), BYTES).with_codec::<Scale>().with_delay(DELAY).execute(), Call { destination: Some(program()), value: 0, delay: Some(DELAY), ..call("send_bytes_delayed", bytes()) });
+#[cfg(feature = "scale")] case!(send_input__with_codec__for_reply_as__no_reply_deposit, builder::send_input(program(), INPUT).with_codec::<Scale>().for_reply_as::<u64>().execute(), Call { destination: Some(program()), value: 0, reply_deposit: Some(0), ..call("send_input_for_reply", input()) });
+#[cfg(feature = "scale")] case!(reply_with__value__gas_limit, builder::reply_with::<Scale, _>(ENCODABLE).with_value(VALUE).with_gas_limit(GAS_LIMIT).execute(), Call { value: VALUE, gas_limit: Some(GAS_LIMIT), ..call("reply_bytes_with_gas", encodable()) });
+#[cfg(feature = "scale")] case!(create_program__with_codec__for_reply_as__no_reply_deposit, builder::create_program(code_id(), BYTES).with_codec::<Scale>().for_reply_as::<u64>().execute(), Call { destination: Some(created_program()), code_id: Some(code_id()), value: 0, reply_deposit: Some(0), ..call("create_program_for_reply", bytes()) });
+
+/// The names of the cases of the reachable states, built from the same rules as the type states:
+/// `*_input` builders and `create_program` can't use a reservation, replies can't be delayed or wait
+/// for a reply, and a message that waits for a reply can't be delayed.
+fn reachable_states() -> Vec<String> {
+    let values = ["no_value", "value"];
+    let delays = ["no_delay", "delay"];
+    let gas = ["no_gas", "gas_limit", "gas_fraction", "gas_remaining_minus", "all_remaining_gas", "gas_from_budget", "reservation"];
+    let for_replies = ["for_reply", "for_reply_as"];
+    let reply_deposits = ["no_reply_deposit", "reply_deposit", "reply_deposit_auto"];
+
+    let mut states = Vec::new();
+    for (builder, gas) in [("send_bytes", &gas[..]), ("send", &gas[..]), ("send_input", &gas[..6]), ("create_program", &gas[..6])] {
+        for value in values {
+            for delay in delays {
+                states.extend(gas.iter().map(|gas| format!("{builder}__{value}__{delay}__{gas}")));
+            }
+            for gas in gas {
+                for for_reply in for_replies {
+                    states.extend(reply_deposits.iter().map(|reply_deposit| format!("{builder}__{value}__{gas}__{for_reply}__{reply_deposit}")));
+                }
+            }
+        }
+    }
+    for (builder, gas) in [("reply_bytes", &gas[..]), ("reply", &gas[..]), ("reply_input", &gas[..6])] {
+        for value in values {
+            states.extend(gas.iter().map(|gas| format!("{builder}__{value}__{gas}")));
+        }
+    }
+
+    states
+}
+
+#[test]
+fn mapping__reachable_states() {
+    // The `.with_codec::<PayloadCodec>()` cases are samples on top of the matrix.
+    let mut cases = include_str!("mapping.rs")
+        .lines()
+        .filter_map(|line| line.trim_start_matches("#[cfg(feature = \"scale\")] ").strip_prefix("case!("))
+        .filter_map(|line| line.split(',').next())
+        .filter(|name| !name.contains("with_codec") && !name.starts_with("send_with") && !name.starts_with("reply_with"))
+        .collect::<Vec<_>>();
+    let mut states = reachable_states();
+    cases.sort_unstable();
+    states.sort_unstable();
+    assert_eq!(cases, states);
+}
@@ -0,0 +1,32 @@
+//! Checks that oversized payloads are rejected before they are sent, and `.encoded_size()`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+
+mod common;
+
+use gstd::Encode;
+use gstd_fluent::{
+    self as builder,
+    backend::mock,
+    config::MAX_PAYLOAD_SIZE,
+    errors::Error,
+};
+
+use common::*;
+
+#[test]
+fn payload_too_large() {
+    setup();
+    let payload = vec![0; MAX_PAYLOAD_SIZE + 1];
+    assert!(matches!(builder::send_bytes(program(), &payload).execute(), Err(Error::PayloadTooLarge { len, max: MAX_PAYLOAD_SIZE }) if len == MAX_PAYLOAD_SIZE + 1));
+    assert!(matches!(builder::reply(&payload).execute(), Err(Error::PayloadTooLarge { len, max: MAX_PAYLOAD_SIZE }) if len == payload.encoded_size()));
+    assert!(matches!(builder::send_input(program(), 0..=MAX_PAYLOAD_SIZE).execute(), Err(Error::PayloadTooLarge { len, max: MAX_PAYLOAD_SIZE }) if len == MAX_PAYLOAD_SIZE + 1));
+    assert!(mock::take_calls().is_empty());
+}
+
+#[test]
+fn encoded_size() {
+    assert_eq!(builder::send(program(), ENCODABLE).with_value(VALUE).encoded_size(), ENCODABLE.encoded_size());
+    assert_eq!(builder::send(program(), ENCODABLE).for_reply().encoded_size(), ENCODABLE.encoded_size());
+    assert_eq!(builder::reply(ENCODABLE).with_gas_limit(GAS_LIMIT).encoded_size(), ENCODABLE.encoded_size());
+}
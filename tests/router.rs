@@ -0,0 +1,89 @@
+//! Checks `builder::router()` with sync and async handlers.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::{ActorId, Decode, Encode};
+use gstd_fluent::{
+    self as builder,
+    backend::mock::{self, Call, Payload},
+    error_reply::ErrorReply,
+    route,
+    router::{DECODE_CODE, UNHANDLED_CODE},
+};
+
+use common::*;
+
+#[derive(Encode, Decode)]
+#[codec(crate = gstd::codec)]
+enum Action {
+    Add(u64, u64),
+    Fetch(ActorId),
+    Ping,
+}
+
+fn router_reply(reply: Result<u64, ErrorReply>) -> Payload {
+    Payload::Bytes(reply.encode())
+}
+
+#[test]
+fn router() {
+    let handle = || {
+        builder::router::<Action, _, _>()
+            .on(route!(Action::Add(a, b) => (a, b)), |(a, b)| a.checked_add(b).ok_or_else(|| String::from("overflow")))
+            .on(route!(Action::Ping => ()), |()| Ok(0))
+            .with_reply_value(VALUE)
+            .handle()
+    };
+
+    setup();
+    set_incoming(Action::Add(2, 3).encode());
+    handle().expect("handle() failed");
+    assert_eq!(recorded_call(), Call { value: VALUE, ..call("reply", router_reply(Ok(5))) });
+
+    set_incoming(Action::Add(u64::MAX, 1).encode());
+    handle().expect("handle() failed");
+    assert_eq!(recorded_call(), Call { value: VALUE, ..call("reply", router_reply(Err("overflow".into()))) });
+
+    set_incoming(Action::Fetch(program()).encode());
+    handle().expect("handle() failed");
+    assert_eq!(recorded_call(), Call { value: VALUE, ..call("reply", router_reply(Err(ErrorReply::new(UNHANDLED_CODE, "No route for the action")))) });
+
+    set_incoming(vec![u8::MAX]);
+    handle().expect("handle() failed");
+    assert_eq!(recorded_call(), Call { value: VALUE, ..call("reply", router_reply(Err(ErrorReply::new(DECODE_CODE, "Unable to decode the action")))) });
+}
+
+#[test]
+fn router__async() {
+    setup();
+    mock::push_reply_encoded(7u64);
+    set_incoming(Action::Fetch(program()).encode());
+    mock::block_on(
+        builder::router::<Action, u64, String>()
+            .on(route!(Action::Ping => ()), |()| Ok(0))
+            .on_async(route!(Action::Fetch(program) => program), |program| async move {
+                builder::send(program, ENCODABLE).for_reply_as::<u64>().execute().map_err(|err| err.to_string())?.await.map_err(|err| format!("{err:?}"))
+            })
+            .with_reply_gas_limit(GAS_LIMIT)
+            .handle_async(),
+    )
+    .expect("handle_async() failed");
+
+    let functions = mock::take_calls().into_iter().map(|call| (call.function, call.payload)).collect::<Vec<_>>();
+    assert_eq!(functions, [("send_for_reply_as", encodable()), ("reply_with_gas", router_reply(Ok(7)))]);
+}
+
+#[test]
+fn router__for_reply_result() {
+    setup();
+    set_incoming(vec![u8::MAX]);
+    builder::router::<Action, u64, String>().on(route!(Action::Ping => ()), |()| Ok(0)).handle().expect("handle() failed");
+    let Payload::Bytes(reply) = recorded_call().payload else { panic!("the reply is not bytes") };
+
+    mock::push_reply(reply);
+    let result = mock::block_on(builder::send(program(), ENCODABLE).for_reply_result::<u64, ErrorReply>().execute().expect("execute() failed"));
+    assert_eq!(result.unwrap(), Err(ErrorReply::new(DECODE_CODE, "Unable to decode the action")));
+}
@@ -0,0 +1,147 @@
+//! Checks `Scheduler`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::{Decode, Encode, ReservationId};
+use gstd_fluent::{
+    backend::mock::{self, Call, IncomingMessage},
+    config::{self, Config},
+    errors::Error,
+    scheduler::{GasSource, Run, Scheduler, MAX_STALE_RUNS},
+};
+
+use common::*;
+
+/// Makes the pending run of the job the message being processed.
+fn set_run(scheduler: &Scheduler, job_id: u64) {
+    let id = scheduler.job(job_id).unwrap().pending().unwrap();
+    mock::set_message(IncomingMessage { id, source: program(), ..Default::default() });
+}
+
+#[test]
+fn scheduler() {
+    let mut scheduler = Scheduler::new();
+
+    setup();
+    mock::set_program_id(program());
+    let job_id = scheduler.schedule(ENCODABLE, DELAY).with_gas_limit(GAS_LIMIT).execute().unwrap();
+    assert_eq!(recorded_call(), Call { destination: Some(program()), gas_limit: Some(GAS_LIMIT), delay: Some(DELAY), ..call("send_bytes_with_gas_delayed", encodable()) });
+    // The job is identified by its payload, the interval and gas of the registered one are kept.
+    assert_eq!(scheduler.schedule(ENCODABLE, DELAY * 2).execute().unwrap(), job_id);
+    assert!(mock::take_calls().is_empty());
+    assert_eq!((scheduler.job(job_id).unwrap().interval(), scheduler.job(job_id).unwrap().gas()), (DELAY, &GasSource::Limit(GAS_LIMIT)));
+
+    assert_eq!(scheduler.run().unwrap(), Run::NotScheduled);
+    set_run(&scheduler, job_id);
+    assert_eq!(scheduler.run().unwrap(), Run::Job(job_id));
+    assert_eq!(recorded_call(), Call { destination: Some(program()), gas_limit: Some(GAS_LIMIT), delay: Some(DELAY), ..call("send_bytes_with_gas_delayed", encodable()) });
+    assert_eq!(scheduler.run().unwrap(), Run::NotScheduled);
+
+    let other_job_id = scheduler.schedule("PONG", DELAY).execute().unwrap();
+    assert_ne!(other_job_id, job_id);
+    assert_eq!(scheduler.job(other_job_id).unwrap().gas(), &GasSource::Unspecified);
+    assert_eq!(recorded_call().function, "send_bytes_delayed");
+
+    set_run(&scheduler, job_id);
+    assert!(scheduler.cancel(job_id));
+    assert!(!scheduler.cancel(job_id));
+    assert_eq!(scheduler.run().unwrap(), Run::Stale);
+    assert_eq!(scheduler.run().unwrap(), Run::NotScheduled);
+    assert!(mock::take_calls().is_empty());
+    assert_eq!(scheduler.jobs().map(|(job_id, _)| job_id).collect::<Vec<_>>(), [other_job_id]);
+
+    let decoded = Scheduler::decode(&mut scheduler.encode().as_slice()).unwrap();
+    assert_eq!(decoded, scheduler);
+}
+
+#[test]
+fn scheduler__reservations() {
+    let second_reservation_id = ReservationId::from([4; 32]);
+    let mut scheduler = Scheduler::new();
+
+    setup();
+    mock::set_program_id(program());
+    let job_id = scheduler.schedule(ENCODABLE, DELAY).with_gas_from_reservations([reservation_id(), second_reservation_id]).execute().unwrap();
+    assert_eq!(recorded_call(), Call { destination: Some(program()), reservation_id: Some(reservation_id()), delay: Some(DELAY), ..call("send_bytes_delayed_from_reservation", encodable()) });
+
+    set_run(&scheduler, job_id);
+    assert_eq!(scheduler.run().unwrap(), Run::Job(job_id));
+    assert_eq!(recorded_call().reservation_id, Some(second_reservation_id));
+
+    // The run sent with the last reservation is still a run of the job.
+    set_run(&scheduler, job_id);
+    assert_eq!(scheduler.run().unwrap(), Run::Last(job_id));
+    assert!(mock::take_calls().is_empty());
+    assert!(scheduler.is_empty());
+    assert_eq!(scheduler.run().unwrap(), Run::NotScheduled);
+    assert!(matches!(scheduler.schedule(ENCODABLE, DELAY).with_gas_from_reservations([]).execute(), Err(Error::ReservationsExhausted { job_id: 1 })));
+    assert!(scheduler.is_empty());
+}
+
+#[test]
+fn scheduler__one_reservation() {
+    let mut scheduler = Scheduler::new();
+
+    setup();
+    mock::set_program_id(program());
+    let job_id = scheduler.schedule(ENCODABLE, DELAY).with_gas_from_reservations([reservation_id()]).execute().unwrap();
+    assert_eq!(recorded_call().reservation_id, Some(reservation_id()));
+    assert_eq!(scheduler.job(job_id).unwrap().gas(), &GasSource::Reservations(Default::default()));
+
+    set_run(&scheduler, job_id);
+    assert_eq!(scheduler.run().unwrap(), Run::Last(job_id));
+    assert!(mock::take_calls().is_empty());
+    assert_eq!(scheduler.job(job_id), None);
+}
+
+#[test]
+fn scheduler__failed_run() {
+    let second_reservation_id = ReservationId::from([4; 32]);
+    let mut scheduler = Scheduler::new();
+
+    setup();
+    mock::set_program_id(program());
+    let job_id = scheduler.schedule(ENCODABLE, DELAY).with_gas_from_reservations([reservation_id(), second_reservation_id]).execute().unwrap();
+    mock::take_calls();
+
+    // The reservation is kept if the run can't be sent.
+    set_run(&scheduler, job_id);
+    config::set(Config::new().with_max_payload_size(0));
+    assert!(matches!(scheduler.run(), Err(Error::PayloadTooLarge { .. })));
+    let job = scheduler.job(job_id).unwrap();
+    assert_eq!((job.pending(), job.gas()), (None, &GasSource::Reservations([second_reservation_id].into())));
+    assert_eq!(scheduler.run().unwrap(), Run::NotScheduled);
+    assert!(mock::take_calls().is_empty());
+
+    config::set(Config::new());
+    assert!(scheduler.resume(job_id).unwrap());
+    assert_eq!(recorded_call().reservation_id, Some(second_reservation_id));
+    assert!(!scheduler.resume(job_id).unwrap());
+    assert!(!scheduler.resume(job_id + 1).unwrap());
+    set_run(&scheduler, job_id);
+    assert_eq!(scheduler.run().unwrap(), Run::Last(job_id));
+}
+
+#[test]
+fn scheduler__stale_runs() {
+    let mut scheduler = Scheduler::new();
+
+    setup();
+    mock::set_program_id(program());
+    let job_ids = (0..=MAX_STALE_RUNS as u32).map(|payload| scheduler.schedule(payload, DELAY).execute().unwrap()).collect::<Vec<_>>();
+    let runs = job_ids.iter().map(|&job_id| scheduler.job(job_id).unwrap().pending().unwrap()).collect::<Vec<_>>();
+    for &job_id in &job_ids {
+        assert!(scheduler.cancel(job_id));
+    }
+
+    // Only the last `MAX_STALE_RUNS` cancelled runs are remembered.
+    mock::set_message(IncomingMessage { id: runs[0], source: program(), ..Default::default() });
+    assert_eq!(scheduler.run().unwrap(), Run::NotScheduled);
+    mock::set_message(IncomingMessage { id: runs[MAX_STALE_RUNS], source: program(), ..Default::default() });
+    assert_eq!(scheduler.run().unwrap(), Run::Stale);
+    assert_eq!(scheduler.run().unwrap(), Run::NotScheduled);
+    assert_eq!(scheduler.encode().len(), Scheduler::new().encode().len() + (MAX_STALE_RUNS - 1) * 32);
+}
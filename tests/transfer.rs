@@ -0,0 +1,34 @@
+//! Checks `builder::transfer(...)` and `.keep_alive()`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd_fluent::{
+    self as builder,
+    backend::mock::{self, Call},
+    errors::Error,
+};
+
+use common::*;
+
+case!(transfer__no_delay__no_gas, builder::transfer(program(), VALUE).execute(), Call { destination: Some(program()), value: VALUE, ..call("send_bytes", empty()) });
+case!(transfer__no_delay__gas_limit, builder::transfer(program(), VALUE).with_gas_limit(GAS_LIMIT).execute(), Call { destination: Some(program()), value: VALUE, gas_limit: Some(GAS_LIMIT), ..call("send_bytes_with_gas", empty()) });
+case!(transfer__no_delay__reservation, builder::transfer(program(), VALUE).with_gas_from_reservation(reservation_id()).execute(), Call { destination: Some(program()), value: VALUE, reservation_id: Some(reservation_id()), ..call("send_bytes_from_reservation", empty()) });
+case!(transfer__delay__no_gas, builder::transfer(program(), VALUE).with_delay(DELAY).execute(), Call { destination: Some(program()), value: VALUE, delay: Some(DELAY), ..call("send_bytes_delayed", empty()) });
+case!(transfer__delay__gas_limit, builder::transfer(program(), VALUE).with_gas_limit(GAS_LIMIT).with_delay(DELAY).execute(), Call { destination: Some(program()), value: VALUE, delay: Some(DELAY), gas_limit: Some(GAS_LIMIT), ..call("send_bytes_with_gas_delayed", empty()) });
+case!(transfer__delay__reservation, builder::transfer(program(), VALUE).with_delay(DELAY).with_gas_from_reservation(reservation_id()).execute(), Call { destination: Some(program()), value: VALUE, delay: Some(DELAY), reservation_id: Some(reservation_id()), ..call("send_bytes_delayed_from_reservation", empty()) });
+
+#[test]
+fn transfer__keep_alive() {
+    setup();
+    assert!(matches!(builder::transfer(program(), 0).execute(), Err(Error::ZeroTransfer)));
+
+    mock::set_value_available(VALUE);
+    assert!(matches!(builder::transfer(program(), VALUE + 1).keep_alive().execute(), Err(Error::KeepAliveViolated { amount, value_available: VALUE, existential_deposit: 0 }) if amount == VALUE + 1));
+    assert!(mock::take_calls().is_empty());
+
+    builder::transfer(program(), VALUE).keep_alive().execute().expect("execute() failed");
+    assert_eq!(recorded_call(), Call { destination: Some(program()), value: VALUE, ..call("send_bytes", empty()) });
+}
@@ -0,0 +1,7 @@
+use gstd_fluent::{self as builder, gstd::CodeId};
+
+fn main() {
+    builder::create_program(CodeId::default(), b"INIT")
+        .with_gas_limit(1)
+        .with_gas_limit(2);
+}
@@ -0,0 +1,9 @@
+use gstd_fluent::{
+    self as builder,
+    gstd::{CodeId, ReservationId},
+};
+
+fn main() {
+    builder::create_program(CodeId::default(), b"INIT")
+        .with_gas_from_reservation(ReservationId::from([0; 32]));
+}
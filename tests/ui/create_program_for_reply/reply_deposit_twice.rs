@@ -0,0 +1,8 @@
+use gstd_fluent::{self as builder, gstd::CodeId};
+
+fn main() {
+    builder::create_program(CodeId::default(), b"INIT")
+        .for_reply()
+        .with_reply_deposit(1)
+        .with_reply_deposit(2);
+}
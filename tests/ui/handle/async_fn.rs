@@ -0,0 +1,6 @@
+#[gstd_fluent::handle]
+async fn handle() -> Result<u64, gstd_fluent::error_reply::ErrorReply> {
+    Ok(42)
+}
+
+fn main() {}
@@ -0,0 +1,4 @@
+#[gstd_fluent::handle]
+fn handle() {}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use gstd_fluent as builder;
+
+fn main() {
+    builder::reply_bytes(b"PONG")
+        .with_gas_limit(1)
+        .with_gas_limit(2);
+}
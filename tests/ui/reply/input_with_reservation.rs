@@ -0,0 +1,5 @@
+use gstd_fluent::{self as builder, gstd::ReservationId};
+
+fn main() {
+    builder::reply_input(..).with_gas_from_reservation(ReservationId::from([0; 32]));
+}
@@ -0,0 +1,7 @@
+use gstd_fluent::{self as builder, gstd::ReservationId};
+
+fn main() {
+    builder::reply_bytes(b"PONG")
+        .with_gas_limit(1)
+        .with_gas_from_reservation(ReservationId::from([0; 32]));
+}
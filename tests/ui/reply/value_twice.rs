@@ -0,0 +1,5 @@
+use gstd_fluent as builder;
+
+fn main() {
+    builder::reply_bytes(b"PONG").with_value(1).with_value(2);
+}
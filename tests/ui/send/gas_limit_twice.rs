@@ -0,0 +1,7 @@
+use gstd_fluent::{self as builder, gstd::ActorId};
+
+fn main() {
+    builder::send_bytes(ActorId::zero(), b"PING")
+        .with_gas_limit(1)
+        .with_gas_limit(2);
+}
@@ -0,0 +1,9 @@
+use gstd_fluent::{
+    self as builder,
+    gstd::{ActorId, ReservationId},
+};
+
+fn main() {
+    builder::send_input(ActorId::zero(), ..)
+        .with_gas_from_reservation(ReservationId::from([0; 32]));
+}
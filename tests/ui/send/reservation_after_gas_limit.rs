@@ -0,0 +1,10 @@
+use gstd_fluent::{
+    self as builder,
+    gstd::{ActorId, ReservationId},
+};
+
+fn main() {
+    builder::send_bytes(ActorId::zero(), b"PING")
+        .with_gas_limit(1)
+        .with_gas_from_reservation(ReservationId::from([0; 32]));
+}
@@ -0,0 +1,8 @@
+use gstd_fluent::{self as builder, gstd::ActorId};
+
+fn main() {
+    builder::send_bytes(ActorId::zero(), b"PING")
+        .for_reply()
+        .with_reply_deposit(1)
+        .with_reply_deposit_auto();
+}
@@ -0,0 +1,7 @@
+use gstd_fluent::{self as builder, gstd::ActorId};
+
+fn main() {
+    builder::send_bytes(ActorId::zero(), b"PING")
+        .for_reply_as::<Vec<u8>>()
+        .with_reply_deposit_auto();
+}
@@ -0,0 +1,5 @@
+use gstd_fluent::{self as builder, gstd::ActorId};
+
+fn main() {
+    builder::transfer(ActorId::zero(), 1).with_delay(1).with_delay(2);
+}
@@ -0,0 +1,61 @@
+//! Checks `builder::wait()`, `builder::wake(...)` and `Waitlist`.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(non_snake_case)]
+
+mod common;
+
+use gstd::MessageId;
+use gstd_fluent::{
+    self as builder,
+    backend::mock::{self, Call, IncomingMessage},
+    wait::Waitlist,
+};
+
+use common::*;
+
+/// Runs the builder and checks that it reached `exec::wait*()`.
+fn waited(wait: impl FnOnce() + std::panic::UnwindSafe) -> Call {
+    let panic = std::panic::catch_unwind(wait).expect_err("exec::wait*() was not called");
+    assert_eq!(panic.downcast_ref::<String>().map(String::as_str), Some(mock::WAIT_MESSAGE));
+    recorded_call()
+}
+
+#[test]
+fn wait() {
+    setup();
+    assert_eq!(waited(|| builder::wait().execute()), call("wait", empty()));
+    assert_eq!(waited(|| builder::wait().for_blocks(DELAY).execute()), Call { delay: Some(DELAY), ..call("wait_for", empty()) });
+    assert_eq!(waited(|| builder::wait().up_to(DELAY).execute()), Call { delay: Some(DELAY), ..call("wait_up_to", empty()) });
+}
+
+case!(wake__no_delay, builder::wake(MessageId::from([4; 32])).execute(), Call { woken: Some(MessageId::from([4; 32])), ..call("wake", empty()) });
+case!(wake__delay, builder::wake(MessageId::from([4; 32])).with_delay(DELAY).execute(), Call { woken: Some(MessageId::from([4; 32])), delay: Some(DELAY), ..call("wake_delayed", empty()) });
+
+#[test]
+fn waitlist() {
+    let first = MessageId::from([4; 32]);
+    let second = MessageId::from([5; 32]);
+    let mut waitlist = Waitlist::new();
+
+    setup();
+    mock::set_message(IncomingMessage { id: first, ..Default::default() });
+    assert_eq!(waited(std::panic::AssertUnwindSafe(|| waitlist.wait("filled").up_to(DELAY).execute())), Call { delay: Some(DELAY), ..call("wait_up_to", empty()) });
+    waitlist.register("filled", second);
+    waitlist.register("filled", second);
+    waitlist.register("cancelled", second);
+    assert_eq!(waitlist.waiting(&"filled"), [first, second]);
+
+    assert_eq!(waitlist.notify_one(&"filled").unwrap(), Some(first));
+    assert_eq!(recorded_call().woken, Some(first));
+    assert!(waitlist.remove(second));
+    assert!(!waitlist.remove(second));
+    assert!(waitlist.is_empty());
+    assert_eq!(waitlist.notify_one(&"filled").unwrap(), None);
+
+    waitlist.register("filled", first);
+    waitlist.register("filled", second);
+    assert_eq!(waitlist.notify_all(&"filled").unwrap(), 2);
+    assert_eq!(mock::take_calls().into_iter().map(|call| call.woken).collect::<Vec<_>>(), [Some(first), Some(second)]);
+    assert!(waitlist.is_empty());
+}